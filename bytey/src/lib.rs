@@ -330,6 +330,9 @@ pub fn read_slice<R>(mut read: R, len: usize) -> Result<Box<[u8]>>
 where
     R: Read,
 {
+    if len == 0 {
+        return Ok(Box::new([]));
+    }
     // TODO: replace with `Box::new_uninit_slice` once stable
     let mut buf = unsafe {
         mem::transmute::<&[u8], Box<[u8]>>(slice::from_raw_parts(
@@ -1,6 +1,10 @@
 use log::info;
+use modinfo::schema;
 use modinfo::settings::cracks::Cracks;
 use modinfo::settings::cracksanity::Cracksanity;
+use modinfo::settings::entrance_shuffle::EntranceShuffle;
+use modinfo::settings::goal::GoalSetting;
+use modinfo::settings::keysanity::Keysanity;
 use modinfo::settings::keysy::Keysy;
 use modinfo::settings::nice_items::NiceItems;
 use modinfo::settings::ravios_shop::RaviosShop;
@@ -8,14 +12,27 @@ use modinfo::settings::trials_door::TrialsDoor;
 use modinfo::settings::weather_vanes::WeatherVanes;
 use modinfo::settings::{logic::LogicMode, pedestal::PedestalSetting, Settings};
 use std::{
-    io::{stdin, stdout, Read, Write},
+    io::{stdin, stdout, IsTerminal, Read, Write},
     str::FromStr,
+    sync::atomic::{AtomicBool, Ordering},
 };
 
-/// Pauses program execution
+static NO_PAUSE: AtomicBool = AtomicBool::new(false);
+
+/// Turns [`pause`] into a no-op for the rest of the program, e.g. for a `--no-pause` flag.
+pub fn disable_pause() {
+    NO_PAUSE.store(true, Ordering::Relaxed);
+}
+
+/// Pauses program execution, so that a console window opened just for the randomizer stays open to be read.
+///
+/// Never pauses when stdout isn't a terminal, so that scripts and CI can't hang waiting for input.
 pub fn pause() {
+    if NO_PAUSE.load(Ordering::Relaxed) || !stdout().is_terminal() {
+        return;
+    }
     println!("Press Enter to continue...");
-    stdin().read_exact(&mut [0]).unwrap();
+    let _ = stdin().read(&mut [0]);
 }
 
 /// Prompt the user for Seed Settings on the CLI
@@ -30,17 +47,58 @@ pub fn get_seed_settings() -> Result<Settings, String> {
         "This shuffles all Sage Portraits, Pendants, and the Charm among themselves.",
     );
 
-    let maiamai_limit =
-        prompt_u8_in_range("Maiamai Limit", "Choose the maximum number of Maiamai you're willing to collect:", 0, 100)
-            as usize;
+    let (min, max) = setting_range("maiamai_limit");
+    let maiamai_limit = prompt_u8_in_range(
+        "Maiamai Limit",
+        "Choose the maximum number of Maiamai you're willing to collect:",
+        min,
+        max,
+    ) as usize;
+
+    let goal = GoalSetting::try_from(prompt_u8_in_range(
+        "Goal",
+        "Choose what must be done to beat the game:\n\
+        [0] Yuga Ganon    - Defeat Yuga Ganon in the Throne Room.\n\
+        [1] Triforce Hunt - Collect enough of the Triforce Pieces shuffled into the item pool.\n\
+        [2] All Dungeons  - Collect every Pendant and Portrait, then defeat Yuga Ganon.\n\
+        [3] Pedestal      - Pull the Master Sword from its Pedestal.",
+        0,
+        3,
+    ))?;
+
+    let (triforce_pieces, triforce_pieces_required) = if goal == GoalSetting::TriforceHunt {
+        let (min, max) = setting_range("triforce_pieces");
+        let triforce_pieces = prompt_u8_in_range(
+            "Triforce Pieces",
+            "Choose how many Triforce Pieces are shuffled into the item pool:",
+            min,
+            max,
+        );
+        let triforce_pieces_required = prompt_u8_in_range(
+            "Triforce Pieces Required",
+            "Choose how many Triforce Pieces are needed to beat the game:",
+            1,
+            triforce_pieces,
+        );
+        (triforce_pieces, triforce_pieces_required)
+    } else {
+        (20, 15)
+    };
 
+    let (min, max) = setting_range("lc_requirement");
     let lc_requirement = prompt_u8_in_range(
         "Lorule Castle Requirement",
-        "Choose how many Portraits are needed to enter Lorule Castle and fight Yuganon:",
-        0,
-        7,
+        "Choose how many Portraits are needed to enter Lorule Castle:",
+        min,
+        max,
+    );
+    let (min, max) = setting_range("yuganon_requirement");
+    let yuganon_requirement = prompt_u8_in_range(
+        "Yuga Ganon Requirement",
+        "Choose how many Portraits are needed to fight Yuga Ganon:",
+        min,
+        max,
     );
-    //let yuganon_requirement = prompt_u8_in_range("Choose how many Portraits are needed to fight Yuganon:", 0, 7);
 
     let ped_requirement = PedestalSetting::try_from(prompt_u8_in_range(
         "Pedestal Requirement",
@@ -123,9 +181,21 @@ pub fn get_seed_settings() -> Result<Settings, String> {
         [1] Cross World Pairs          - Cracks are shuffled, but remain in Hyrule/Lorule pairs.\n\
         [2] Any World Pairs            - Cracks are shuffled freely, and can lead to the same or opposite world.\n\
         [3] Mirrored Cross World Pairs - Same as Cross World Pairs, but each pair's vanilla counterparts will be in a matching pair.\n\
-        [4] Mirrored Any World Pairs   - Same as Any World Pairs, but each pair's vanilla counterparts will be in a matching pair.",
+        [4] Mirrored Any World Pairs   - Same as Any World Pairs, but each pair's vanilla counterparts will be in a matching pair.\n\
+        [5] Decoupled Cross World      - Cracks lead between Hyrule and Lorule, but going back through one won't return you to where you started.\n\
+        [6] Decoupled Any World        - Cracks lead anywhere, and going back through one won't return you to where you started.",
         0,
-        4,
+        6,
+    ))?;
+
+    let entrance_shuffle = EntranceShuffle::try_from(prompt_u8_in_range(
+        "Entrance Shuffle",
+        "Choose how to shuffle dungeon, cave, and house entrances:\n\
+        [0] Off       - Entrances are not shuffled.\n\
+        [1] Coupled   - Entrances are shuffled amongst their own kind, and exiting leads back out the same door.\n\
+        [2] Decoupled - Same as Coupled, but exiting leads out a different, randomly chosen door.",
+        0,
+        2,
     ))?;
 
     let minigames_excluded = prompt_bool(
@@ -208,11 +278,12 @@ pub fn get_seed_settings() -> Result<Settings, String> {
         Note: Some large chests will have a reduced hitbox to prevent negative gameplay interference.",
     );
 
+    let (min, max) = setting_range("treacherous_tower_floors");
     let treacherous_tower_floors = prompt_u16_in_range(
         "Treacherous Tower Floors",
-        "How many floors should Treacherous Tower have? (2-66)\nRecommended: 5",
-        2,
-        66,
+        "How many floors should Treacherous Tower have?\nRecommended: 5",
+        min,
+        max,
     ) as usize;
 
     let purple_potion_bottles =
@@ -229,16 +300,24 @@ pub fn get_seed_settings() -> Result<Settings, String> {
         3,
     ))?;
 
+    let small_keys = prompt_keysanity("Small Keys")?;
+    let big_keys = prompt_keysanity("Big Keys")?;
+    let compasses = prompt_keysanity("Compasses")?;
+
     println!();
     info!("Starting seed generation...\n");
 
     Ok(Settings {
         dev_mode: false,
         user_exclusions: Default::default(),
+        goal,
+        triforce_pieces,
+        triforce_pieces_required,
         lc_requirement,
-        yuganon_requirement: lc_requirement,
+        yuganon_requirement,
         ped_requirement,
         logic_mode,
+        logic_file: None,
         dark_rooms_lampless,
         dungeon_prize_shuffle,
         maiamai_limit,
@@ -248,12 +327,16 @@ pub fn get_seed_settings() -> Result<Settings, String> {
         lamp_and_net_as_weapons,
         cracks,
         cracksanity,
+        entrance_shuffle,
         trials_door,
         weather_vanes,
         ravios_shop: RaviosShop::Open,
         bow_of_light_in_castle,
         no_progression_enemies,
         keysy,
+        small_keys,
+        big_keys,
+        compasses,
         progressive_bow_of_light: false,
         swordless_mode,
         start_with_merge,
@@ -268,9 +351,26 @@ pub fn get_seed_settings() -> Result<Settings, String> {
         treacherous_tower_floors,
         purple_potion_bottles,
         night_mode: false,
+        hint_distribution: Default::default(),
     })
 }
 
+pub fn prompt_keysanity(items: &str) -> Result<Keysanity, String> {
+    Keysanity::try_from(prompt_u8_in_range(
+        items,
+        &format!(
+            "Choose where {} may be placed:\n\
+            [0] Own Dungeon - Within their own dungeon.\n\
+            [1] Own World   - Anywhere in the same world as their dungeon, including its other dungeons.\n\
+            [2] Any Dungeon - Within any dungeon.\n\
+            [3] Anywhere    - Anywhere at all.",
+            items
+        ),
+        0,
+        3,
+    ))
+}
+
 #[rustfmt::skip]
 pub fn prompt_logic_mode() -> LogicMode {
     println!("\n[Logic Mode]");
@@ -304,6 +404,15 @@ pub fn prompt_logic_mode() -> LogicMode {
     }
 }
 
+/// Gets the range of an integer setting from the settings schema, to prompt within it.
+fn setting_range<T: TryFrom<u64>>(name: &str) -> (T, T) {
+    let (min, max) = schema::range(name).unwrap_or_else(|| panic!("{} is not an integer setting", name));
+    match (T::try_from(min), T::try_from(max)) {
+        (Ok(min), Ok(max)) => (min, max),
+        _ => panic!("The range of {} is too large to prompt for", name),
+    }
+}
+
 pub fn prompt_u8_in_range(title: &str, description: &str, range_start: u8, range_end: u8) -> u8 {
     print!("\n[{}]\n{}", title, description);
     loop {
@@ -1,7 +1,7 @@
 use serde::Serialize;
-use strum::EnumIter;
+use strum::{EnumIter, EnumString};
 
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, EnumIter, Serialize)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, EnumIter, EnumString, Serialize)]
 pub enum HintGhost {
     // Hyrule Overworld Hint Ghosts (36) -----------------------------------------------------------
     LostWoodsMaze1,
@@ -2,10 +2,9 @@ use {
     crate::{IntoBytes, JackFile, Pathed},
     byteorder::{BigEndian, LittleEndian, ReadBytesExt},
     log::info,
-    macros::fail,
     std::{
         collections::BTreeMap,
-        io::{BufRead, BufReader, Cursor, Error, Seek, SeekFrom},
+        io::{BufRead, BufReader, Cursor, Error, ErrorKind, Seek, SeekFrom},
         str::from_utf8,
     },
 };
@@ -22,15 +21,19 @@ impl Sarc {
     /// Adds a new file to this [`Sarc`] Archive
     /// The `named` field determines whether the file's actual name will be stored in the archive's SFNT Filename Table.
     /// This can usually be set to false safely, but a small number of files do need this to deal with Hash collisions.
-    pub fn create(&mut self, filename: &str, data: Vec<u8>, named: bool) {
+    pub fn create(&mut self, filename: &str, data: Vec<u8>, named: bool) -> Result<(), Error> {
         if self.read(filename).is_some() {
-            fail!("File '{}' with matching Hash already exists in SZS Archive: '{}'", filename, self.path);
+            return Err(Error::new(
+                ErrorKind::AlreadyExists,
+                format!("File '{}' with matching Hash already exists in SZS Archive: '{}'", filename, self.path),
+            ));
         }
 
         self.files.insert(
             self.calculate_hash(filename),
             vec![SarcInnerFile { filename: if named { Some(filename.to_owned()) } else { None }, data }],
         );
+        Ok(())
     }
 
     /// Gets a file with the given `filename` from within this [`Sarc`] Archive. Panics if the file does not exist.
@@ -62,7 +65,7 @@ impl Sarc {
     }
 
     /// Updates a file within this [`Sarc`] Archive
-    pub fn update(&mut self, filename: &str, data: Vec<u8>) {
+    pub fn update(&mut self, filename: &str, data: Vec<u8>) -> Result<(), Error> {
         if let Some(files) = self.files.get_mut(&self.calculate_hash(filename)) {
             if files.len() == 1 {
                 files.get_mut(0).unwrap().data = data;
@@ -79,24 +82,31 @@ impl Sarc {
                     .unwrap_or_else(|| panic!("File with hash collision did not have matching filename: {}", filename))
                     .data = data;
             }
+            Ok(())
         } else {
-            fail!("Could not update file '{}' in SARC archive '{}': File doesn't exist.", filename, self.path);
+            Err(Error::new(
+                ErrorKind::NotFound,
+                format!("Could not update file '{}' in SARC archive '{}': File doesn't exist.", filename, self.path),
+            ))
         }
     }
 
-    /// Deletes a file with the given `filename` from within this [`Sarc`] Archive. Panics if the file does not exist.
-    pub fn delete(&mut self, filename: &str) {
+    /// Deletes a file with the given `filename` from within this [`Sarc`] Archive.
+    pub fn delete(&mut self, filename: &str) -> Result<(), Error> {
         let filename_hash = self.calculate_hash(filename);
         if let Some(files) = self.files.get_mut(&filename_hash) {
             if files.len() == 1 {
                 self.files.remove(&filename_hash);
-                return; // success
+                return Ok(());
             } else if let Some(index) = files.iter().position(|file| file.filename.as_deref() == Some(filename)) {
                 files.remove(index);
-                return; // success
+                return Ok(());
             }
         }
-        fail!("Could not delete file '{}' in SARC Archive '{}': File doesn't exist", filename, self.path);
+        Err(Error::new(
+            ErrorKind::NotFound,
+            format!("Could not delete file '{}' in SARC Archive '{}': File doesn't exist", filename, self.path),
+        ))
     }
 
     /// Creates a representation of a [`Sarc`] Archive from the given file `path` and array of `bytes`.
@@ -1,7 +1,9 @@
 use {
     crate::{IntoBytes, JackFile, Pathed},
-    macros::fail,
-    std::{io::Cursor, marker::PhantomData},
+    std::{
+        io::{self, Cursor},
+        marker::PhantomData,
+    },
     yaz0::{CompressionLevel, Yaz0Archive, Yaz0Writer},
 };
 
@@ -40,19 +42,13 @@ impl<State> IntoBytes for Yaz0File<State> {
 
 impl Yaz0File<Compressed> {
     /// Perform the decompression
-    pub(crate) fn decompress(self) -> Yaz0File<Decompressed> {
+    pub(crate) fn decompress(self) -> io::Result<Yaz0File<Decompressed>> {
         let path = self.path.clone();
-        let mut yaz0 = match Yaz0Archive::new(Cursor::new(self.into_bytes())) {
-            Ok(yaz0) => yaz0,
-            Err(err) => fail!("{}", err),
-        };
+        let invalid = |err: yaz0::Error| io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", path, err));
+        let mut yaz0 = Yaz0Archive::new(Cursor::new(self.into_bytes())).map_err(invalid)?;
+        let decompressed = yaz0.decompress().map_err(invalid)?;
 
-        let decompressed = match yaz0.decompress() {
-            Ok(decompressed) => decompressed,
-            Err(err) => fail!("{}", err),
-        };
-
-        Yaz0File { path, data: decompressed.into(), state: PhantomData::<Decompressed> }
+        Ok(Yaz0File { path, data: decompressed.into(), state: PhantomData::<Decompressed> })
     }
 }
 
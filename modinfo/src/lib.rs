@@ -1,2 +1,4 @@
 pub use settings::Settings;
+pub mod schema;
 pub mod settings;
+pub mod settings_string;
@@ -0,0 +1,599 @@
+//! A machine-readable description of every setting, for GUIs, wrappers, and Preset validators to share.
+//!
+//! [`json_schema`] describes a whole Preset as a [JSON Schema](https://json-schema.org/): each setting's type, its
+//! allowed values along with their display names, its range, its default, and which settings can't be combined.
+//! The randomizer validates [`Settings`] against the same table with [`validate`].
+
+use crate::settings::{
+    Cracks, Cracksanity, EntranceShuffle, GoalSetting, HintDistribution, Keysanity, Keysy, LogicMode, NiceItems,
+    PedestalSetting, TrialsDoor, WeatherVanes,
+};
+use crate::Settings;
+use pyo3::pyfunction;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use std::fmt::Display;
+
+/// One setting, as it's named in a Preset.
+struct Setting {
+    name: &'static str,
+    title: &'static str,
+    description: &'static str,
+    kind: Kind,
+}
+
+enum Kind {
+    Bool,
+    /// A whole number within an inclusive range, with no upper bound if `max` is `None`.
+    Integer {
+        min: u64,
+        max: Option<u64>,
+    },
+    /// One of the variants of a setting enum, given by [`variants`].
+    Enum(fn() -> Vec<Value>),
+    /// Text, such as the path to a file.
+    Text,
+    /// A set of check names, as they appear in the Spoiler Log.
+    CheckNames,
+    /// A group of settings with its own default.
+    Group {
+        settings: &'static [Setting],
+        default: fn() -> Value,
+    },
+}
+
+/// Pairs of `bool` settings that can't both be enabled.
+const CONFLICTS: [(&str, &str); 4] = [
+    ("progressive_bow_of_light", "bow_of_light_in_castle"),
+    ("sword_in_shop", "swordless_mode"),
+    ("assured_weapon", "sword_in_shop"),
+    ("assured_weapon", "boots_in_shop"),
+];
+
+/// Integer settings that can't be more than another, e.g. `triforce_pieces_required` can't be more than
+/// `triforce_pieces`, but only while another setting has the given value.
+const LIMITS: [(&str, &str, (&str, &str)); 1] =
+    [("triforce_pieces_required", "triforce_pieces", ("goal", "TriforceHunt"))];
+
+const SETTINGS: &[Setting] = &[
+    Setting {
+        name: "dev_mode",
+        title: "Developer Mode",
+        description: "Leaves in developer conveniences when patching. Not meant for playing seeds.",
+        kind: Kind::Bool,
+    },
+    Setting {
+        name: "goal",
+        title: "Goal",
+        description: "What must be done to beat the game. Only YugaGanon can be patched for now.",
+        kind: Kind::Enum(variants::<GoalSetting>),
+    },
+    Setting {
+        name: "triforce_pieces",
+        title: "Triforce Pieces",
+        description: "The number of Triforce Pieces shuffled into the item pool for the Triforce Hunt goal.",
+        kind: Kind::Integer { min: 1, max: Some(30) },
+    },
+    Setting {
+        name: "triforce_pieces_required",
+        title: "Triforce Pieces Required",
+        description: "The number of Triforce Pieces needed to complete the Triforce Hunt goal. Must not be more than \
+        triforce_pieces.",
+        kind: Kind::Integer { min: 1, max: Some(30) },
+    },
+    Setting {
+        name: "lc_requirement",
+        title: "Lorule Castle Requirement",
+        description: "The number of Portraits needed to trigger the Hilda cutscene to open Lorule Castle.",
+        kind: Kind::Integer { min: 0, max: Some(7) },
+    },
+    Setting {
+        name: "yuganon_requirement",
+        title: "Yuga Ganon Requirement",
+        description: "The number of Portraits needed to enter the Throne Room and fight Yuga Ganon. Seeds where this \
+        differs from lc_requirement can't be patched yet.",
+        kind: Kind::Integer { min: 0, max: Some(7) },
+    },
+    Setting {
+        name: "ped_requirement",
+        title: "Pedestal Requirement",
+        description: "Which Pendants are required to reach the Master Sword Pedestal.",
+        kind: Kind::Enum(variants::<PedestalSetting>),
+    },
+    Setting {
+        name: "logic_mode",
+        title: "Logic Mode",
+        description: "The logic to use for item placement.",
+        kind: Kind::Enum(variants::<LogicMode>),
+    },
+    Setting {
+        name: "logic_file",
+        title: "Logic File",
+        description: "Custom logic file to build the World Graph from, instead of the built-in logic.",
+        kind: Kind::Text,
+    },
+    Setting {
+        name: "dark_rooms_lampless",
+        title: "Dark Room Crossing",
+        description: "If enabled, the logic may expect players to cross Dark Rooms without the Lamp.",
+        kind: Kind::Bool,
+    },
+    Setting {
+        name: "dungeon_prize_shuffle",
+        title: "Dungeon Prizes",
+        description: "Randomizes the Pendants and Portraits between Hyrule and Lorule dungeons.",
+        kind: Kind::Bool,
+    },
+    Setting {
+        name: "maiamai_limit",
+        title: "Maiamai Limit",
+        description: "The most Maiamai the seed may require collecting, assuming none are wasted on junk.",
+        kind: Kind::Integer { min: 0, max: Some(100) },
+    },
+    Setting {
+        name: "maiamai_madness",
+        title: "Maiamai Madness",
+        description: "Shuffles Maiamai into the item pool, adding 100 more locations.",
+        kind: Kind::Bool,
+    },
+    Setting {
+        name: "nice_items",
+        title: "Nice Items",
+        description: "How Nice Items and Mother Maiamai's rewards are handled.",
+        kind: Kind::Enum(variants::<NiceItems>),
+    },
+    Setting {
+        name: "super_items",
+        title: "Super Items",
+        description: "Shuffles a second progressive copy of the Lamp and Net into the item pool.",
+        kind: Kind::Bool,
+    },
+    Setting {
+        name: "lamp_and_net_as_weapons",
+        title: "Lamp & Net as Weapons",
+        description: "Treats the base Lamp and Net as logical weapons.",
+        kind: Kind::Bool,
+    },
+    Setting {
+        name: "cracks",
+        title: "Cracks",
+        description: "Whether Cracks start open, or stay closed until Quake is found.",
+        kind: Kind::Enum(variants::<Cracks>),
+    },
+    Setting {
+        name: "cracksanity",
+        title: "Cracksanity",
+        description: "Shuffles the Crack destinations amongst each other.",
+        kind: Kind::Enum(variants::<Cracksanity>),
+    },
+    Setting {
+        name: "entrance_shuffle",
+        title: "Entrance Shuffle",
+        description: "Shuffles Hyrule's dungeon, cave, and house entrances amongst their own kind.",
+        kind: Kind::Enum(variants::<EntranceShuffle>),
+    },
+    Setting {
+        name: "weather_vanes",
+        title: "Weather Vanes",
+        description: "Which Weather Vanes are active at the start of the game, or how they're shuffled.",
+        kind: Kind::Enum(variants::<WeatherVanes>),
+    },
+    Setting {
+        name: "bow_of_light_in_castle",
+        title: "Bow of Light in Castle",
+        description: "Guarantees the Bow of Light will be placed in Lorule Castle.",
+        kind: Kind::Bool,
+    },
+    Setting {
+        name: "no_progression_enemies",
+        title: "No Progression Enemies",
+        description: "Removes Enemies from dungeons that are themselves Progression (e.g.: Bawbs, the bomb enemy).",
+        kind: Kind::Bool,
+    },
+    Setting {
+        name: "keysy",
+        title: "Keysy",
+        description: "Removes locked doors and their keys from dungeons.",
+        kind: Kind::Enum(variants::<Keysy>),
+    },
+    Setting {
+        name: "small_keys",
+        title: "Small Keys",
+        description: "Where Small Keys may be placed. Only OwnDungeon can be patched for now.",
+        kind: Kind::Enum(variants::<Keysanity>),
+    },
+    Setting {
+        name: "big_keys",
+        title: "Big Keys",
+        description: "Where Big Keys may be placed. Only OwnDungeon can be patched for now.",
+        kind: Kind::Enum(variants::<Keysanity>),
+    },
+    Setting {
+        name: "compasses",
+        title: "Compasses",
+        description: "Where Compasses may be placed. Only OwnDungeon can be patched for now.",
+        kind: Kind::Enum(variants::<Keysanity>),
+    },
+    Setting {
+        name: "progressive_bow_of_light",
+        title: "Progressive Bow of Light",
+        description: "Makes the Bow of Light the third upgrade for the Bow.",
+        kind: Kind::Bool,
+    },
+    Setting {
+        name: "swordless_mode",
+        title: "Swordless Mode",
+        description: "Removes all Swords from the game.",
+        kind: Kind::Bool,
+    },
+    Setting {
+        name: "start_with_merge",
+        title: "Start with Merge",
+        description: "Start with the ability to Merge into walls, without Ravio's Bracelet.",
+        kind: Kind::Bool,
+    },
+    Setting {
+        name: "start_with_pouch",
+        title: "Start with Pouch",
+        description: "Start with the Pouch and a usable X Button.",
+        kind: Kind::Bool,
+    },
+    Setting {
+        name: "bell_in_shop",
+        title: "Bell in Shop",
+        description: "Places the Bell in Ravio's Shop.",
+        kind: Kind::Bool,
+    },
+    Setting {
+        name: "sword_in_shop",
+        title: "Sword in Shop",
+        description: "Places a Sword in Ravio's Shop.",
+        kind: Kind::Bool,
+    },
+    Setting {
+        name: "boots_in_shop",
+        title: "Boots in Shop",
+        description: "Places the Pegasus Boots in Ravio's Shop.",
+        kind: Kind::Bool,
+    },
+    Setting {
+        name: "assured_weapon",
+        title: "Assured Weapon",
+        description: "Guarantees a Weapon is placed in Ravio's Shop.",
+        kind: Kind::Bool,
+    },
+    Setting {
+        name: "chest_size_matches_contents",
+        title: "Chest Size Matches Contents",
+        description: "Makes chests holding progression or unique items large, and all others small.",
+        kind: Kind::Bool,
+    },
+    Setting {
+        name: "minigames_excluded",
+        title: "Minigames Excluded",
+        description: "Excludes Cucco Ranch, both Rupee Rushes, Treacherous Tower, Octoball Derby, and Hyrule Hotfoot.",
+        kind: Kind::Bool,
+    },
+    Setting {
+        name: "skip_big_bomb_flower",
+        title: "Skip Big Bomb Flower",
+        description: "Skips the Big Bomb Flower by removing the 5 Big Rocks in Lorule Field.",
+        kind: Kind::Bool,
+    },
+    Setting {
+        name: "trials_door",
+        title: "Trials Door",
+        description: "How the Trials Door in Lorule Castle is opened.",
+        kind: Kind::Enum(variants::<TrialsDoor>),
+    },
+    Setting {
+        name: "treacherous_tower_floors",
+        title: "Treacherous Tower Floors",
+        description: "The number of floors in Treacherous Tower.",
+        kind: Kind::Integer { min: 2, max: Some(66) },
+    },
+    Setting {
+        name: "purple_potion_bottles",
+        title: "Purple Potion Bottles",
+        description: "Fills all Empty Bottles with a free Purple Potion.",
+        kind: Kind::Bool,
+    },
+    Setting {
+        name: "night_mode",
+        title: "Night Mode",
+        description: "Experimental: Changes Hyrule to the nighttime color scheme (until visiting Lorule).",
+        kind: Kind::Bool,
+    },
+    Setting {
+        name: "user_exclusions",
+        title: "Excluded Checks",
+        description: "Checks that will only hold junk items.",
+        kind: Kind::CheckNames,
+    },
+    Setting {
+        name: "hint_distribution",
+        title: "Hint Distribution",
+        description: "How many of each kind of hint the Hint Ghosts give out.",
+        kind: Kind::Group { settings: HINT_DISTRIBUTION, default: || json!(HintDistribution::default()) },
+    },
+];
+
+const HINT_DISTRIBUTION: &[Setting] = &[
+    Setting {
+        name: "name",
+        title: "Name",
+        description: "Name of the distribution, shown in the settings log.",
+        kind: Kind::Text,
+    },
+    Setting {
+        name: "total",
+        title: "Total Hints",
+        description: "Total number of unique hints. Sometimes Hints make up whatever isn't counted by the others.",
+        kind: Kind::Integer { min: 0, max: None },
+    },
+    Setting {
+        name: "always_checks",
+        title: "Always Checks",
+        description: "Checks to give Always Hints for on top of the built-in ones.",
+        kind: Kind::CheckNames,
+    },
+    Setting {
+        name: "path",
+        title: "Path Hints",
+        description: "Number of Path Hints.",
+        kind: Kind::Integer { min: 0, max: None },
+    },
+    Setting {
+        name: "maiamai",
+        title: "Mother Maiamai Hints",
+        description: "Number of Mother Maiamai Hints, when Nice Items are shuffled.",
+        kind: Kind::Integer { min: 0, max: None },
+    },
+    Setting {
+        name: "hero",
+        title: "Way of the Hero Hints",
+        description: "Number of Way of the Hero Hints.",
+        kind: Kind::Integer { min: 0, max: None },
+    },
+    Setting {
+        name: "crack",
+        title: "Crack Hints",
+        description: "Number of Crack Hints, when Cracksanity is on.",
+        kind: Kind::Integer { min: 0, max: None },
+    },
+    Setting {
+        name: "barren",
+        title: "Barren Hints",
+        description: "Most Barren Hints to give.",
+        kind: Kind::Integer { min: 0, max: None },
+    },
+    Setting {
+        name: "duplicates",
+        title: "Duplicate Hints",
+        description: "Number of extra Hint Ghosts giving out each hint.",
+        kind: Kind::Integer { min: 0, max: None },
+    },
+];
+
+/// Lists every variant of a setting enum, in the order of its `TryFrom<u8>` indices, paired with its display name.
+fn variants<T>() -> Vec<Value>
+where
+    T: TryFrom<u8> + Serialize + Display,
+{
+    (0..=u8::MAX)
+        .filter_map(|index| T::try_from(index).ok())
+        .map(|variant| json!({ "const": variant, "title": variant.to_string() }))
+        .collect()
+}
+
+/// Describes a whole Preset, with its `seed`, `version`, and `settings`, as a JSON Schema.
+pub fn json_schema() -> Value {
+    // The defaults used for a setting that's left out of a Preset
+    let defaults = serde_json::from_value::<Settings>(json!({})).and_then(serde_json::to_value);
+    let defaults = defaults.expect("Default Settings could not be serialized.");
+
+    let conflicts = CONFLICTS
+        .iter()
+        .map(|(a, b)| {
+            json!({
+                "title": format!("{} and {} cannot both be enabled", a, b),
+                "not": {
+                    "required": [a, b],
+                    "properties": { *a: { "const": true }, *b: { "const": true } },
+                },
+            })
+        })
+        .collect::<Vec<_>>();
+
+    // JSON Schema can't compare two values, so every value the limiting setting can have gets its own maximum
+    let limits = LIMITS.iter().flat_map(|(name, limit, (when, is))| {
+        let (min, max) = range(limit).expect("Limits should be integer settings");
+        let default = defaults.get(limit).and_then(Value::as_u64);
+        (min..=max).map(move |value| {
+            // A Preset that leaves out the limiting setting uses its default
+            let required = if Some(value) == default { vec![*when] } else { vec![*when, *limit] };
+            json!({
+                "title": format!("{} cannot be more than {}", name, limit),
+                "if": {
+                    "required": required,
+                    "properties": { *when: { "const": is }, *limit: { "const": value } },
+                },
+                "then": { "properties": { *name: { "maximum": value } } },
+            })
+        })
+    });
+
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "ALBWR Preset",
+        "type": "object",
+        "properties": {
+            "seed": {
+                "description": "The seed number to generate, or 0 for a random one.",
+                "type": "integer",
+                "minimum": 0,
+                "maximum": u32::MAX,
+                "default": 0,
+            },
+            "version": {
+                "description": "The version of the randomizer the Preset was made for.",
+                "type": "string",
+            },
+            "settings": {
+                "type": "object",
+                "additionalProperties": false,
+                "properties": properties(SETTINGS, &defaults),
+                "allOf": conflicts.into_iter().chain(limits).collect::<Vec<_>>(),
+            },
+        },
+    })
+}
+
+fn properties(settings: &[Setting], defaults: &Value) -> Map<String, Value> {
+    settings
+        .iter()
+        .map(|setting| {
+            let mut property = json!({ "title": setting.title, "description": setting.description });
+            let property_map = property.as_object_mut().unwrap();
+            let default = defaults.get(setting.name).cloned();
+
+            let (schema, default) = match &setting.kind {
+                Kind::Bool => (json!({ "type": "boolean" }), default.or(Some(false.into()))),
+                Kind::Integer { min, max: Some(max) } => {
+                    (json!({ "type": "integer", "minimum": min, "maximum": max }), default)
+                },
+                Kind::Integer { min, max: None } => (json!({ "type": "integer", "minimum": min }), default),
+                Kind::Enum(variants) => (json!({ "type": "string", "oneOf": variants() }), default),
+                Kind::Text => (json!({ "type": "string" }), default),
+                Kind::CheckNames => {
+                    (json!({ "type": "array", "items": { "type": "string" }, "uniqueItems": true }), default)
+                },
+                Kind::Group { settings, default } => {
+                    let default = default();
+                    let schema = json!({
+                        "type": "object",
+                        "additionalProperties": false,
+                        "properties": properties(settings, &default),
+                    });
+                    (schema, Some(default))
+                },
+            };
+
+            property_map.extend(schema.as_object().unwrap().clone());
+            if let Some(default) = default {
+                property_map.insert("default".to_owned(), default);
+            }
+            (setting.name.to_owned(), property)
+        })
+        .collect()
+}
+
+/// Checks the [`Settings`] against the ranges and conflicts of the schema.
+pub fn validate(settings: &Settings) -> Result<(), String> {
+    let values = serde_json::to_value(settings).map_err(|err| err.to_string())?;
+
+    for setting in SETTINGS {
+        if let Kind::Integer { min, max } = setting.kind {
+            let value = values.get(setting.name).and_then(Value::as_u64).unwrap_or_default();
+            if value < min || max.is_some_and(|max| value > max) {
+                return Err(format!(
+                    "Invalid {}: \"{}\" was not between {}-{}, inclusive.",
+                    setting.title,
+                    value,
+                    min,
+                    max.map_or("".to_owned(), |max| max.to_string())
+                ));
+            }
+        }
+    }
+
+    for (a, b) in CONFLICTS {
+        let enabled = |name| values.get(name).and_then(Value::as_bool).unwrap_or_default();
+        if enabled(a) && enabled(b) {
+            return Err(format!("The {} and {} settings cannot both be enabled.", a, b));
+        }
+    }
+
+    for (name, limit, (when, is)) in LIMITS {
+        let value = |name| values.get(name).and_then(Value::as_u64).unwrap_or_default();
+        if values.get(when).and_then(Value::as_str) == Some(is) && value(name) > value(limit) {
+            return Err(format!(
+                "Invalid {}: \"{}\" was more than the {} setting: \"{}\".",
+                title(name),
+                value(name),
+                title(limit),
+                value(limit)
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Gets the range of an integer setting from the schema, e.g. for a prompt to ask within it.
+pub fn range(name: &str) -> Option<(u64, u64)> {
+    SETTINGS.iter().find(|setting| setting.name == name).and_then(|setting| match setting.kind {
+        Kind::Integer { min, max } => Some((min, max.unwrap_or(u64::MAX))),
+        _ => None,
+    })
+}
+
+fn title(name: &str) -> &str {
+    SETTINGS.iter().find(|setting| setting.name == name).map_or(name, |setting| setting.title)
+}
+
+/// Gets [`json_schema`] as a JSON string.
+#[pyfunction]
+pub fn settings_schema() -> String {
+    serde_json::to_string_pretty(&json_schema()).expect("Settings schema could not be serialized.")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{json_schema, validate, SETTINGS};
+    use crate::settings::GoalSetting;
+    use crate::Settings;
+    use serde_json::json;
+
+    #[test]
+    fn it_describes_every_setting() {
+        let schema = json_schema();
+        let properties = schema["properties"]["settings"]["properties"].as_object().unwrap();
+        let serialized = serde_json::to_value(Settings { dev_mode: true, ..Default::default() }).unwrap();
+        for name in serialized.as_object().unwrap().keys() {
+            assert!(properties.contains_key(name), "{} is missing from the schema", name);
+        }
+        assert_eq!(properties.len(), SETTINGS.len());
+        assert_eq!(properties["maiamai_limit"]["default"], 50);
+        assert_eq!(properties["logic_mode"]["oneOf"][3], json!({ "const": "AdvGlitched", "title": "Adv. Glitched" }));
+        assert_eq!(properties["hint_distribution"]["properties"]["total"]["default"], 29);
+    }
+
+    #[test]
+    fn it_validates_ranges_and_conflicts() {
+        let defaults: Settings = serde_json::from_value(json!({})).unwrap();
+        assert!(validate(&defaults).is_ok());
+        assert!(validate(&Settings { treacherous_tower_floors: 67, ..defaults.clone() }).is_err());
+        assert!(validate(&Settings { sword_in_shop: true, swordless_mode: true, ..defaults }).is_err());
+    }
+
+    #[test]
+    fn it_limits_triforce_pieces_required() {
+        let hunt = Settings { goal: GoalSetting::TriforceHunt, triforce_pieces: 10, ..Default::default() };
+        assert!(validate(&Settings { triforce_pieces_required: 10, ..hunt.clone() }).is_ok());
+        assert!(validate(&Settings { triforce_pieces_required: 11, ..hunt.clone() }).is_err());
+        assert!(validate(&Settings { goal: GoalSetting::YugaGanon, triforce_pieces_required: 11, ..hunt }).is_ok());
+
+        let limits = &json_schema()["properties"]["settings"]["allOf"];
+        let limit =
+            limits.as_array().unwrap().iter().find(|limit| limit["if"]["properties"]["triforce_pieces"]["const"] == 10);
+        assert_eq!(limit.unwrap()["then"]["properties"]["triforce_pieces_required"]["maximum"], 10);
+    }
+
+    #[test]
+    fn it_validates_default_settings() {
+        let settings = Settings::default();
+        assert!(validate(&settings).is_ok());
+        assert_eq!(settings.goal, GoalSetting::YugaGanon);
+    }
+}
@@ -17,6 +17,10 @@ pub enum Cracksanity {
     MirroredCrossWorldPairs,
     /// Same as AnyWorldPairs, but each pair's vanilla counterparts will be in a matching pair
     MirroredAnyWorldPairs,
+    /// Cracks are shuffled between Hyrule and Lorule, but going through a Crack and back won't return to where it began
+    DecoupledCrossWorld,
+    /// Cracks are shuffled freely, and going through a Crack and back won't return to where it began
+    DecoupledAnyWorld,
 }
 
 impl TryFrom<u8> for Cracksanity {
@@ -29,6 +33,8 @@ impl TryFrom<u8> for Cracksanity {
             2 => Ok(Self::AnyWorldPairs),
             3 => Ok(Self::MirroredCrossWorldPairs),
             4 => Ok(Self::MirroredAnyWorldPairs),
+            5 => Ok(Self::DecoupledCrossWorld),
+            6 => Ok(Self::DecoupledAnyWorld),
             _ => Err("Invalid CrackShuffle index: {}".to_owned()),
         }
     }
@@ -45,6 +51,8 @@ impl Display for Cracksanity {
                 Self::AnyWorldPairs => "Any World Pairs",
                 Self::MirroredCrossWorldPairs => "Mirrored Cross World Pairs",
                 Self::MirroredAnyWorldPairs => "Mirrored Any World Pairs",
+                Self::DecoupledCrossWorld => "Decoupled Cross World",
+                Self::DecoupledAnyWorld => "Decoupled Any World",
             }
         )
     }
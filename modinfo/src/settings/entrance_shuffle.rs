@@ -0,0 +1,43 @@
+use pyo3::pyclass;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Entrance Shuffle
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Deserialize, Serialize)]
+#[pyclass]
+pub enum EntranceShuffle {
+    /// Entrances are not shuffled
+    #[default]
+    Off,
+    /// Dungeon, cave, and house entrances are shuffled amongst their own kind, and exiting leads back out the same door
+    Coupled,
+    /// Same as Coupled, but exiting leads out a different, randomly chosen door of the same kind
+    Decoupled,
+}
+
+impl TryFrom<u8> for EntranceShuffle {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Off),
+            1 => Ok(Self::Coupled),
+            2 => Ok(Self::Decoupled),
+            _ => Err(format!("Invalid EntranceShuffle index: {}", value)),
+        }
+    }
+}
+
+impl Display for EntranceShuffle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Off => "Off",
+                Self::Coupled => "Coupled",
+                Self::Decoupled => "Decoupled",
+            }
+        )
+    }
+}
@@ -0,0 +1,47 @@
+use pyo3::pyclass;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// The win condition: what the player must do to roll the credits.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Deserialize, Serialize)]
+#[pyclass]
+pub enum GoalSetting {
+    /// Defeat Yuga Ganon in the Throne Room, once enough Portraits have been collected to enter it.
+    #[default]
+    YugaGanon,
+    /// Collect the required number of Triforce Pieces shuffled into the item pool.
+    TriforceHunt,
+    /// Collect every Pendant and Portrait, then defeat Yuga Ganon.
+    AllDungeons,
+    /// Pull the Master Sword from its Pedestal.
+    Pedestal,
+}
+
+impl TryFrom<u8> for GoalSetting {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::YugaGanon),
+            1 => Ok(Self::TriforceHunt),
+            2 => Ok(Self::AllDungeons),
+            3 => Ok(Self::Pedestal),
+            _ => Err(format!("Invalid Goal index: {}", value)),
+        }
+    }
+}
+
+impl Display for GoalSetting {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::YugaGanon => "Yuga Ganon",
+                Self::TriforceHunt => "Triforce Hunt",
+                Self::AllDungeons => "All Dungeons",
+                Self::Pedestal => "Pedestal",
+            }
+        )
+    }
+}
@@ -0,0 +1,84 @@
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::fmt::{Display, Formatter};
+
+/// How many of each kind of hint the Hint Ghosts give out.
+///
+/// Sometimes Hints aren't counted here, they make up whatever is left of the `total`.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+#[pyclass]
+pub struct HintDistribution {
+    /// Name of the distribution, shown in the settings log
+    #[pyo3(get, set)]
+    pub name: String,
+
+    /// Total number of unique hints
+    #[pyo3(get, set)]
+    pub total: usize,
+
+    /// Checks to give Always Hints for on top of the built-in ones
+    #[pyo3(get, set)]
+    pub always_checks: BTreeSet<String>,
+
+    /// Number of Path Hints. One is given per Boss guarding a Sage Portrait, with any extra going to random Bosses.
+    #[pyo3(get, set)]
+    pub path: usize,
+
+    /// Number of Mother Maiamai Hints, when Nice Items are shuffled
+    #[pyo3(get, set)]
+    pub maiamai: usize,
+
+    /// Number of Way of the Hero Hints, telling how many items needed to beat the game are in a region
+    #[pyo3(get, set)]
+    pub hero: usize,
+
+    /// Number of Crack Hints, when Cracksanity is on
+    #[pyo3(get, set)]
+    pub crack: usize,
+
+    /// Most Barren Hints to give, as a seed may not have that many barren regions
+    #[pyo3(get, set)]
+    pub barren: usize,
+
+    /// Number of extra Hint Ghosts giving out each hint, chosen at random
+    #[pyo3(get, set)]
+    pub duplicates: usize,
+}
+
+#[pymethods]
+impl HintDistribution {
+    #[new]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl HintDistribution {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl Default for HintDistribution {
+    fn default() -> Self {
+        Self {
+            name: "Default".to_owned(),
+            total: 29,
+            always_checks: BTreeSet::new(),
+            path: 7,
+            maiamai: 5,
+            hero: 0,
+            crack: 3,
+            barren: 0,
+            duplicates: 1,
+        }
+    }
+}
+
+impl Display for HintDistribution {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
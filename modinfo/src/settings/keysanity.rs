@@ -0,0 +1,47 @@
+use pyo3::pyclass;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Keysanity controls where a class of dungeon items (Small Keys, Big Keys, or Compasses) may be placed.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Deserialize, Serialize)]
+#[pyclass]
+pub enum Keysanity {
+    /// Dungeon items are placed within their own dungeon.
+    #[default]
+    OwnDungeon,
+    /// Dungeon items are placed anywhere in the same world as their dungeon, including its other dungeons.
+    OwnWorld,
+    /// Dungeon items are placed within any dungeon.
+    AnyDungeon,
+    /// Dungeon items are placed anywhere.
+    Anywhere,
+}
+
+impl TryFrom<u8> for Keysanity {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::OwnDungeon),
+            1 => Ok(Self::OwnWorld),
+            2 => Ok(Self::AnyDungeon),
+            3 => Ok(Self::Anywhere),
+            _ => Err(format!("Invalid Keysanity index: {}", value)),
+        }
+    }
+}
+
+impl Display for Keysanity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::OwnDungeon => "Own Dungeon",
+                Self::OwnWorld => "Own World",
+                Self::AnyDungeon => "Any Dungeon",
+                Self::Anywhere => "Anywhere",
+            }
+        )
+    }
+}
@@ -1,5 +1,6 @@
 use pyo3::pyclass;
 use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
 
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, Deserialize, Serialize)]
 #[pyclass]
@@ -12,3 +13,36 @@ pub enum LogicMode {
     Hell,
     NoLogic,
 }
+
+impl TryFrom<u8> for LogicMode {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Normal),
+            1 => Ok(Self::Hard),
+            2 => Ok(Self::Glitched),
+            3 => Ok(Self::AdvGlitched),
+            4 => Ok(Self::Hell),
+            5 => Ok(Self::NoLogic),
+            _ => Err(format!("Invalid LogicMode index: {}", value)),
+        }
+    }
+}
+
+impl Display for LogicMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Normal => "Normal",
+                Self::Hard => "Hard",
+                Self::Glitched => "Glitched",
+                Self::AdvGlitched => "Adv. Glitched",
+                Self::Hell => "Hell",
+                Self::NoLogic => "No Logic",
+            }
+        )
+    }
+}
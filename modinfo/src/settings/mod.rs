@@ -1,5 +1,9 @@
 pub use crate::settings::cracks::Cracks;
 pub use crate::settings::cracksanity::Cracksanity;
+pub use crate::settings::entrance_shuffle::EntranceShuffle;
+pub use crate::settings::goal::GoalSetting;
+pub use crate::settings::hint_distribution::HintDistribution;
+pub use crate::settings::keysanity::Keysanity;
 pub use crate::settings::keysy::Keysy;
 pub use crate::settings::logic::LogicMode;
 pub use crate::settings::nice_items::NiceItems;
@@ -16,6 +20,10 @@ use std::hash::Hash;
 
 pub mod cracks;
 pub mod cracksanity;
+pub mod entrance_shuffle;
+pub mod goal;
+pub mod hint_distribution;
+pub mod keysanity;
 pub mod keysy;
 pub mod logic;
 pub mod nice_items;
@@ -25,21 +33,36 @@ pub mod trials_door;
 pub mod weather_vanes;
 
 /// Logic and behavior settings.
-#[derive(Clone, Debug, Default, Deserialize, Hash, Serialize)]
-#[serde(default, deny_unknown_fields)]
+#[derive(Clone, Debug, Deserialize, Hash, Serialize)]
+#[serde(deny_unknown_fields)]
 #[pyclass]
 pub struct Settings {
-    #[serde(skip_serializing_if = "is_false")]
+    #[serde(default, skip_serializing_if = "is_false")]
     #[pyo3(get, set)]
     pub dev_mode: bool,
 
+    /// The win condition: defeating Yuga Ganon, collecting Triforce Pieces, clearing every dungeon, or the Pedestal
+    #[serde(default)]
+    #[pyo3(get, set)]
+    pub goal: GoalSetting,
+
+    /// The number of Triforce Pieces shuffled into the item pool for the Triforce Hunt goal
+    #[serde(default = "twenty")]
+    #[pyo3(get, set)]
+    pub triforce_pieces: u8,
+
+    /// The number of Triforce Pieces needed to complete the Triforce Hunt goal
+    #[serde(default = "fifteen")]
+    #[pyo3(get, set)]
+    pub triforce_pieces_required: u8,
+
     /// The number of Portraits needed to trigger the Hilda cutscene to open Lorule Castle
     #[serde(default = "seven")]
     #[pyo3(get, set)]
     pub lc_requirement: u8,
 
-    /// The number of Portraits needed to fight Yuga Ganon
-    #[serde(default = "seven", skip_serializing)]
+    /// The number of Portraits needed to enter the Throne Room and fight Yuga Ganon
+    #[serde(default = "seven")]
     #[pyo3(get, set)]
     pub yuganon_requirement: u8,
 
@@ -53,6 +76,11 @@ pub struct Settings {
     #[pyo3(get, set)]
     pub logic_mode: LogicMode,
 
+    /// Custom logic file to build the World Graph from, instead of the built-in logic
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[pyo3(get, set)]
+    pub logic_file: Option<String>,
+
     /// Dark Room Lamp Requirement. If enabled, the player may have to cross dark rooms without Lamp
     #[serde(default)]
     #[pyo3(get, set)]
@@ -89,6 +117,7 @@ pub struct Settings {
     pub lamp_and_net_as_weapons: bool,
 
     /// Cracks Open/Closed Setting
+    #[serde(default)]
     #[pyo3(get, set)]
     pub cracks: Cracks,
 
@@ -97,6 +126,11 @@ pub struct Settings {
     #[pyo3(get, set)]
     pub cracksanity: Cracksanity,
 
+    /// Shuffles dungeon, cave, and house entrances amongst their own kind
+    #[serde(default)]
+    #[pyo3(get, set)]
+    pub entrance_shuffle: EntranceShuffle,
+
     /// Weather Vanes behavior and activation setting.
     #[serde(default)]
     #[pyo3(get, set)]
@@ -123,6 +157,21 @@ pub struct Settings {
     #[pyo3(get, set)]
     pub keysy: Keysy,
 
+    /// Where Small Keys may be placed
+    #[serde(default)]
+    #[pyo3(get, set)]
+    pub small_keys: Keysanity,
+
+    /// Where Big Keys may be placed
+    #[serde(default)]
+    #[pyo3(get, set)]
+    pub big_keys: Keysanity,
+
+    /// Where Compasses may be placed
+    #[serde(default)]
+    #[pyo3(get, set)]
+    pub compasses: Keysanity,
+
     /// Makes the Bow of Light the third upgrade for the Bow
     #[serde(default, skip_serializing_if = "is_false")]
     #[pyo3(get, set)]
@@ -166,6 +215,7 @@ pub struct Settings {
     pub assured_weapon: bool,
 
     /// Alters treasure chest sizes depending on their contents: Large for Progression items, Small for everything else.
+    #[serde(default)]
     #[pyo3(get, set)]
     pub chest_size_matches_contents: bool,
 
@@ -190,16 +240,31 @@ pub struct Settings {
     pub treacherous_tower_floors: usize,
 
     /// Purple Potion Bottles
+    #[serde(default)]
     #[pyo3(get, set)]
     pub purple_potion_bottles: bool,
 
     /// Experimental: Change Hyrule to the nighttime color scheme (until visiting Lorule)
+    #[serde(default)]
     #[pyo3(get, set)]
     pub night_mode: bool,
 
     /// Set of user-provided locations to be excluded from having progression.
+    #[serde(default)]
     #[pyo3(get, set)]
     pub user_exclusions: BTreeSet<String>,
+
+    /// How many of each kind of hint to generate
+    #[serde(default, skip_serializing_if = "HintDistribution::is_default")]
+    #[pyo3(get, set)]
+    pub hint_distribution: HintDistribution,
+}
+
+/// The same defaults as a Preset that leaves these settings out, so that both make the same seed.
+impl Default for Settings {
+    fn default() -> Self {
+        serde_json::from_value(serde_json::json!({})).expect("Every setting should have a default")
+    }
 }
 
 #[pymethods]
@@ -223,10 +288,17 @@ impl Settings {
                 NoLogic => "No Logic",
             }
         );
+        if let Some(logic_file) = &self.logic_file {
+            info!("Logic File:                     {}", logic_file);
+        }
         info!(
             "Dungeon Prizes:                 {}",
             if self.dungeon_prize_shuffle { "Randomized" } else { "Not Randomized" }
         );
+        info!("Goal:                           {}", self.goal);
+        if self.goal == GoalSetting::TriforceHunt {
+            info!("Triforce Pieces:                {} of {}", self.triforce_pieces_required, self.triforce_pieces);
+        }
         info!("Lorule Castle Requirement:      {} Portraits", self.lc_requirement);
         info!("Yuga Ganon Requirement:         {} Portraits", self.yuganon_requirement);
         info!("Pedestal Requirement:           {}", self.ped_requirement);
@@ -267,7 +339,12 @@ impl Settings {
             "Chest Size:                     {}",
             if self.chest_size_matches_contents { "Matches Contents" } else { "Normal" }
         );
-        info!("Cracksanity:                   {}", self.cracksanity)
+        info!("Cracksanity:                   {}", self.cracksanity);
+        info!("Entrance Shuffle:               {}", self.entrance_shuffle);
+        info!("Small Keys:                     {}", self.small_keys);
+        info!("Big Keys:                       {}", self.big_keys);
+        info!("Compasses:                      {}", self.compasses);
+        info!("Hint Distribution:              {}", self.hint_distribution);
     }
 }
 
@@ -287,6 +364,14 @@ const fn seven() -> u8 {
     7
 }
 
+const fn fifteen() -> u8 {
+    15
+}
+
+const fn twenty() -> u8 {
+    20
+}
+
 const fn fifty() -> usize {
     50
 }
@@ -294,3 +379,22 @@ const fn fifty() -> usize {
 const fn r#true() -> bool {
     true
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Settings;
+    use serde_json::json;
+
+    #[test]
+    fn it_defaults_like_an_empty_preset() {
+        let settings = Settings::default();
+        assert_eq!((settings.triforce_pieces, settings.triforce_pieces_required), (20, 15));
+        assert_eq!((settings.lc_requirement, settings.yuganon_requirement), (7, 7));
+        assert_eq!((settings.maiamai_limit, settings.treacherous_tower_floors), (50, 5));
+        assert!(settings.dungeon_prize_shuffle);
+        assert_eq!(
+            serde_json::to_value(&settings).unwrap(),
+            serde_json::to_value(serde_json::from_value::<Settings>(json!({})).unwrap()).unwrap()
+        );
+    }
+}
@@ -0,0 +1,431 @@
+//! Compact Settings Strings, for sharing a seed and its [`Settings`] as one short line of text.
+//!
+//! A Settings String is URL-safe base64 over a bit-packed layout of every [`Settings`] field plus the seed number,
+//! led by [`SETTINGS_STRING_VERSION`]. Check names, such as the user's exclusions, are written as indexes into a fixed
+//! list of checks rather than spelled out. Decoding one gives back identical [`Settings`], so the seed it makes has the
+//! same hash when generated with the same build of the randomizer.
+
+use crate::settings::{HintDistribution, Settings};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use checks::CHECKS;
+use std::collections::BTreeSet;
+
+mod checks;
+
+/// Version of the bit-packed layout, bumped whenever a setting is added, removed, or re-encoded.
+pub const SETTINGS_STRING_VERSION: u8 = 2;
+
+/// Bits used for each index into [`CHECKS`], leaving room for the list to grow without changing the layout.
+const CHECK_INDEX_BITS: usize = 10;
+
+/// Encodes the `seed` and its `settings` as a Settings String.
+pub fn encode(seed: u32, settings: &Settings) -> String {
+    let mut writer = BitWriter::default();
+    writer.write(SETTINGS_STRING_VERSION as u64, 8);
+    writer.write(seed as u64, 32);
+
+    writer.write_bool(settings.dev_mode);
+    writer.write_enum(settings.goal, 2);
+    writer.write(settings.triforce_pieces as u64, 8);
+    writer.write(settings.triforce_pieces_required as u64, 8);
+    writer.write(settings.lc_requirement as u64, 8);
+    writer.write(settings.yuganon_requirement as u64, 8);
+    writer.write_enum(settings.ped_requirement, 2);
+    writer.write_enum(settings.logic_mode, 3);
+    writer.write_option(settings.logic_file.as_deref());
+    writer.write_bool(settings.dark_rooms_lampless);
+    writer.write_bool(settings.dungeon_prize_shuffle);
+    writer.write_number(settings.maiamai_limit);
+    writer.write_bool(settings.maiamai_madness);
+    writer.write_enum(settings.nice_items, 2);
+    writer.write_bool(settings.super_items);
+    writer.write_bool(settings.lamp_and_net_as_weapons);
+    writer.write_enum(settings.cracks, 1);
+    writer.write_enum(settings.cracksanity, 3);
+    writer.write_enum(settings.entrance_shuffle, 2);
+    writer.write_enum(settings.weather_vanes, 3);
+    writer.write_enum(settings.ravios_shop, 1);
+    writer.write_bool(settings.bow_of_light_in_castle);
+    writer.write_bool(settings.no_progression_enemies);
+    writer.write_enum(settings.keysy, 2);
+    writer.write_enum(settings.small_keys, 2);
+    writer.write_enum(settings.big_keys, 2);
+    writer.write_enum(settings.compasses, 2);
+    writer.write_bool(settings.progressive_bow_of_light);
+    writer.write_bool(settings.swordless_mode);
+    writer.write_bool(settings.start_with_merge);
+    writer.write_bool(settings.start_with_pouch);
+    writer.write_bool(settings.bell_in_shop);
+    writer.write_bool(settings.sword_in_shop);
+    writer.write_bool(settings.boots_in_shop);
+    writer.write_bool(settings.assured_weapon);
+    writer.write_bool(settings.chest_size_matches_contents);
+    writer.write_bool(settings.minigames_excluded);
+    writer.write_bool(settings.skip_big_bomb_flower);
+    writer.write_enum(settings.trials_door, 3);
+    writer.write_number(settings.treacherous_tower_floors);
+    writer.write_bool(settings.purple_potion_bottles);
+    writer.write_bool(settings.night_mode);
+    writer.write_checks(&settings.user_exclusions);
+
+    // Most seeds use the default Hint Distribution, so it only costs a single bit for them
+    let distribution = &settings.hint_distribution;
+    writer.write_bool(distribution.is_default());
+    if !distribution.is_default() {
+        writer.write_str(&distribution.name);
+        writer.write_number(distribution.total);
+        writer.write_checks(&distribution.always_checks);
+        writer.write_number(distribution.path);
+        writer.write_number(distribution.maiamai);
+        writer.write_number(distribution.hero);
+        writer.write_number(distribution.crack);
+        writer.write_number(distribution.barren);
+        writer.write_number(distribution.duplicates);
+    }
+
+    URL_SAFE_NO_PAD.encode(writer.bytes)
+}
+
+/// Decodes a Settings String back into the seed and [`Settings`] it was encoded from.
+pub fn decode(settings_string: &str) -> Result<(u32, Settings), String> {
+    let bytes =
+        URL_SAFE_NO_PAD.decode(settings_string.trim()).map_err(|err| format!("Invalid Settings String: {}", err))?;
+    let mut reader = BitReader { bytes: &bytes, position: 0 };
+
+    let version = reader.read(8)? as u8;
+    if version != SETTINGS_STRING_VERSION {
+        return Err(format!(
+            "Settings String version {} is not supported by this randomizer, which reads version {}",
+            version, SETTINGS_STRING_VERSION
+        ));
+    }
+    let seed = reader.read(32)? as u32;
+
+    let mut settings = Settings {
+        dev_mode: reader.read_bool()?,
+        goal: reader.read_enum(2)?,
+        triforce_pieces: reader.read(8)? as u8,
+        triforce_pieces_required: reader.read(8)? as u8,
+        lc_requirement: reader.read(8)? as u8,
+        yuganon_requirement: reader.read(8)? as u8,
+        ped_requirement: reader.read_enum(2)?,
+        logic_mode: reader.read_enum(3)?,
+        logic_file: reader.read_option()?,
+        dark_rooms_lampless: reader.read_bool()?,
+        dungeon_prize_shuffle: reader.read_bool()?,
+        maiamai_limit: reader.read_number()?,
+        maiamai_madness: reader.read_bool()?,
+        nice_items: reader.read_enum(2)?,
+        super_items: reader.read_bool()?,
+        lamp_and_net_as_weapons: reader.read_bool()?,
+        cracks: reader.read_enum(1)?,
+        cracksanity: reader.read_enum(3)?,
+        entrance_shuffle: reader.read_enum(2)?,
+        weather_vanes: reader.read_enum(3)?,
+        ravios_shop: reader.read_enum(1)?,
+        bow_of_light_in_castle: reader.read_bool()?,
+        no_progression_enemies: reader.read_bool()?,
+        keysy: reader.read_enum(2)?,
+        small_keys: reader.read_enum(2)?,
+        big_keys: reader.read_enum(2)?,
+        compasses: reader.read_enum(2)?,
+        progressive_bow_of_light: reader.read_bool()?,
+        swordless_mode: reader.read_bool()?,
+        start_with_merge: reader.read_bool()?,
+        start_with_pouch: reader.read_bool()?,
+        bell_in_shop: reader.read_bool()?,
+        sword_in_shop: reader.read_bool()?,
+        boots_in_shop: reader.read_bool()?,
+        assured_weapon: reader.read_bool()?,
+        chest_size_matches_contents: reader.read_bool()?,
+        minigames_excluded: reader.read_bool()?,
+        skip_big_bomb_flower: reader.read_bool()?,
+        trials_door: reader.read_enum(3)?,
+        treacherous_tower_floors: reader.read_number()?,
+        purple_potion_bottles: reader.read_bool()?,
+        night_mode: reader.read_bool()?,
+        user_exclusions: reader.read_checks()?,
+        hint_distribution: HintDistribution::default(),
+    };
+
+    if !reader.read_bool()? {
+        settings.hint_distribution = HintDistribution {
+            name: reader.read_str()?,
+            total: reader.read_number()?,
+            always_checks: reader.read_checks()?,
+            path: reader.read_number()?,
+            maiamai: reader.read_number()?,
+            hero: reader.read_number()?,
+            crack: reader.read_number()?,
+            barren: reader.read_number()?,
+            duplicates: reader.read_number()?,
+        };
+    }
+
+    // Only the zero bits padding out the last byte may be left over
+    if reader.position + 8 <= reader.bytes.len() * 8 || reader.read(reader.bytes.len() * 8 - reader.position)? != 0 {
+        return Err("Invalid Settings String: unexpected data after the last setting".to_owned());
+    }
+
+    Ok((seed, settings))
+}
+
+/// Writes values most significant bit first, packed with no padding between them.
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    position: usize,
+}
+
+impl BitWriter {
+    fn write(&mut self, value: u64, bits: usize) {
+        for bit in (0..bits).rev() {
+            if self.position.is_multiple_of(8) {
+                self.bytes.push(0);
+            }
+            if (value >> bit) & 1 == 1 {
+                *self.bytes.last_mut().unwrap() |= 0x80 >> (self.position % 8);
+            }
+            self.position += 1;
+        }
+    }
+
+    fn write_bool(&mut self, value: bool) {
+        self.write(value as u64, 1);
+    }
+
+    /// Writes an enum setting as the same index its `TryFrom<u8>` reads, so the two can't disagree.
+    fn write_enum<T>(&mut self, value: T, bits: usize)
+    where
+        T: TryFrom<u8> + PartialEq,
+    {
+        let index = (0..=u8::MAX)
+            .find(|&index| T::try_from(index).is_ok_and(|variant| variant == value))
+            .expect("Every setting variant should have an index");
+        self.write(index as u64, bits);
+    }
+
+    /// Writes a number of any size, 7 bits at a time, each group led by a bit telling whether another one follows.
+    fn write_number(&mut self, mut value: usize) {
+        loop {
+            let group = value & 0x7F;
+            value >>= 7;
+            self.write_bool(value != 0);
+            self.write(group as u64, 7);
+            if value == 0 {
+                break;
+            }
+        }
+    }
+
+    fn write_str(&mut self, value: &str) {
+        self.write_number(value.len());
+        for byte in value.bytes() {
+            self.write(byte as u64, 8);
+        }
+    }
+
+    fn write_option(&mut self, value: Option<&str>) {
+        self.write_bool(value.is_some());
+        if let Some(value) = value {
+            self.write_str(value);
+        }
+    }
+
+    /// Writes check names as indexes into [`CHECKS`], or as a bitset over it when that's shorter. Names missing from
+    /// the list, e.g. those of a custom logic file, are written out in full after them.
+    fn write_checks(&mut self, values: &BTreeSet<String>) {
+        let mut indexes = vec![];
+        let mut others = vec![];
+        for value in values {
+            match CHECKS.iter().position(|check| check == value) {
+                Some(index) => indexes.push(index),
+                None => others.push(value),
+            }
+        }
+
+        let bitset_len = indexes.iter().max().map_or(0, |max| max + 1);
+        let bitset = bitset_len < indexes.len() * CHECK_INDEX_BITS;
+        self.write_bool(bitset);
+        if bitset {
+            self.write_number(bitset_len);
+            for index in 0..bitset_len {
+                self.write_bool(indexes.contains(&index));
+            }
+        } else {
+            self.write_number(indexes.len());
+            for index in indexes {
+                self.write(index as u64, CHECK_INDEX_BITS);
+            }
+        }
+
+        self.write_number(others.len());
+        for other in others {
+            self.write_str(other);
+        }
+    }
+}
+
+/// Reads back the values written by a [`BitWriter`], in the same order.
+struct BitReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl BitReader<'_> {
+    fn read(&mut self, bits: usize) -> Result<u64, String> {
+        let mut value = 0;
+        for _ in 0..bits {
+            let byte = self.bytes.get(self.position / 8).ok_or("Invalid Settings String: too short")?;
+            value = (value << 1) | ((byte >> (7 - self.position % 8)) & 1) as u64;
+            self.position += 1;
+        }
+        Ok(value)
+    }
+
+    fn read_bool(&mut self) -> Result<bool, String> {
+        Ok(self.read(1)? == 1)
+    }
+
+    fn read_enum<T>(&mut self, bits: usize) -> Result<T, String>
+    where
+        T: TryFrom<u8, Error = String>,
+    {
+        T::try_from(self.read(bits)? as u8)
+    }
+
+    fn read_number(&mut self) -> Result<usize, String> {
+        let mut value = 0;
+        let mut shift = 0;
+        loop {
+            let more = self.read_bool()?;
+            let group = self.read(7)? as usize;
+            if shift >= usize::BITS {
+                return Err("Invalid Settings String: number too large".to_owned());
+            }
+            value |= group << shift;
+            shift += 7;
+            if !more {
+                return Ok(value);
+            }
+        }
+    }
+
+    fn read_str(&mut self) -> Result<String, String> {
+        let len = self.read_number()?;
+        let bytes = (0..len).map(|_| Ok(self.read(8)? as u8)).collect::<Result<Vec<_>, String>>()?;
+        String::from_utf8(bytes).map_err(|err| format!("Invalid Settings String: {}", err))
+    }
+
+    fn read_option(&mut self) -> Result<Option<String>, String> {
+        Ok(if self.read_bool()? { Some(self.read_str()?) } else { None })
+    }
+
+    fn read_checks(&mut self) -> Result<BTreeSet<String>, String> {
+        let indexes = if self.read_bool()? {
+            let len = self.read_number()?;
+            if len > CHECKS.len() {
+                return Err("Invalid Settings String: too many checks".to_owned());
+            }
+            let bits = (0..len).map(|_| self.read_bool()).collect::<Result<Vec<_>, String>>()?;
+            (0..len).filter(|&index| bits[index]).collect::<Vec<_>>()
+        } else {
+            let len = self.read_number()?;
+            (0..len).map(|_| Ok(self.read(CHECK_INDEX_BITS)? as usize)).collect::<Result<Vec<_>, String>>()?
+        };
+
+        let mut checks = indexes
+            .into_iter()
+            .map(|index| match CHECKS.get(index) {
+                Some(check) => Ok(check.to_string()),
+                None => Err(format!("Invalid Settings String: unknown check {}", index)),
+            })
+            .collect::<Result<BTreeSet<_>, String>>()?;
+        for _ in 0..self.read_number()? {
+            checks.insert(self.read_str()?);
+        }
+        Ok(checks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode, encode, CHECKS, CHECK_INDEX_BITS};
+    use crate::settings::{GoalSetting, HintDistribution, LogicMode, PedestalSetting, RaviosShop, TrialsDoor};
+    use crate::Settings;
+
+    #[test]
+    fn it_round_trips_default_settings() {
+        let settings = Settings::default();
+        let (seed, decoded) = decode(&encode(1234567890, &settings)).unwrap();
+        assert_eq!(seed, 1234567890);
+        assert_eq!(serde_json::to_value(decoded).unwrap(), serde_json::to_value(settings).unwrap());
+    }
+
+    #[test]
+    fn it_round_trips_every_setting() {
+        let settings = Settings {
+            goal: GoalSetting::TriforceHunt,
+            triforce_pieces: 30,
+            triforce_pieces_required: 25,
+            lc_requirement: 3,
+            ped_requirement: PedestalSetting::Vanilla,
+            logic_mode: LogicMode::NoLogic,
+            logic_file: Some("logic/custom.json".to_owned()),
+            maiamai_limit: 100,
+            ravios_shop: RaviosShop::Closed,
+            trials_door: TrialsDoor::OpenFromBothSides,
+            treacherous_tower_floors: 66,
+            user_exclusions: ["Ravio's Gift", "Treacherous Tower Advanced (2)"].map(str::to_owned).into(),
+            hint_distribution: HintDistribution {
+                name: "Tournament".to_owned(),
+                always_checks: ["Master Sword Pedestal".to_owned()].into(),
+                path: 300,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let (_, decoded) = decode(&encode(0, &settings)).unwrap();
+        assert_eq!(decoded.ravios_shop, RaviosShop::Closed);
+        assert_eq!(decoded.hint_distribution, settings.hint_distribution);
+        assert_eq!(serde_json::to_value(decoded).unwrap(), serde_json::to_value(settings).unwrap());
+    }
+
+    #[test]
+    fn it_encodes_checks_by_index() {
+        let round_trip = |user_exclusions: &[&str]| {
+            let user_exclusions = user_exclusions.iter().map(|&check| check.to_owned()).collect();
+            let settings = Settings { user_exclusions, ..Default::default() };
+            let settings_string = encode(0, &settings);
+            assert_eq!(decode(&settings_string).unwrap().1.user_exclusions, settings.user_exclusions);
+            settings_string.len()
+        };
+
+        let empty = round_trip(&[]);
+        assert!(round_trip(&["Treacherous Tower"]) <= empty + 2);
+        assert!(round_trip(&["Ravio's Gift", "Custom Logic Check"]) > empty + "Custom Logic Check".len());
+        assert!(round_trip(CHECKS) <= empty + CHECKS.len() / 6 + 2);
+    }
+
+    #[test]
+    fn it_lists_every_check_of_the_default_logic() {
+        let logic = std::fs::read_to_string("../logic/Default.json").unwrap();
+        for check in logic.split("\"check\": \"").skip(1).map(|rest| &rest[..rest.find('"').unwrap()]) {
+            assert!(CHECKS.contains(&check), "{} is missing from the Settings String checks", check);
+        }
+
+        assert!(CHECKS.len() <= 1 << CHECK_INDEX_BITS);
+        for (index, check) in CHECKS.iter().enumerate() {
+            assert!(!CHECKS[..index].contains(check), "{} is listed twice", check);
+        }
+    }
+
+    #[test]
+    fn it_rejects_bad_strings() {
+        let settings_string = encode(42, &Settings::default());
+        assert!(decode(&settings_string[..settings_string.len() - 2]).is_err());
+        assert!(decode(&format!("{}AAAA", settings_string)).is_err());
+        assert!(decode("not base64!").is_err());
+        assert!(decode(&format!("B{}", &settings_string[1..])).is_err());
+    }
+}
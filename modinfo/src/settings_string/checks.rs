@@ -0,0 +1,425 @@
+//! The checks a Settings String refers to by index, e.g. those excluded by the user.
+//!
+//! A check's index is its position in [`CHECKS`], so entries must never be reordered, renamed, or removed without
+//! bumping [`SETTINGS_STRING_VERSION`](super::SETTINGS_STRING_VERSION). New checks are only ever appended.
+
+/// Every check in the default logic, in the order they were first listed there.
+pub(super) const CHECKS: &[&str] = &[
+    "Ravio's Gift",
+    "Ravio's Shop (1)",
+    "Ravio's Shop (2)",
+    "Ravio's Shop (3)",
+    "Ravio's Shop (4)",
+    "Ravio's Shop (5)",
+    "Ravio's Shop (6)",
+    "Ravio's Shop (7)",
+    "Ravio's Shop (8)",
+    "Ravio's Shop (9)",
+    "Your House Weather Vane",
+    "Kakariko Village Weather Vane",
+    "Sanctuary Weather Vane",
+    "Witch's House Weather Vane",
+    "Dampe",
+    "Irene",
+    "Sanctuary Pegs",
+    "Blacksmith Ledge",
+    "Hyrule Castle Rocks",
+    "Haunted Grove Stump",
+    "Southern Ruins Ledge",
+    "Lake Hylia Ledge Chest",
+    "Lake Hylia Eastern Shore",
+    "Hyrule Hotfoot 75s",
+    "Hyrule Hotfoot 65s",
+    "Bird Lover",
+    "Street Merchant (Left)",
+    "Street Merchant (Right)",
+    "Shady Guy",
+    "Dodge the Cuccos",
+    "Rupee Rush (Hyrule)",
+    "[Mai] Kakariko Bush",
+    "[Mai] Lost Woods Path Rock",
+    "[Mai] Fortune-Teller Tent",
+    "[Mai] Woman's Roof",
+    "Eastern Ruins Peg Circle",
+    "[Mai] Rosso Wall",
+    "[Mai] Small Pond",
+    "[Mai] Sanctuary Wall",
+    "[Mai] Blacksmith Tree",
+    "[Mai] Lost Woods Tree",
+    "[Mai] Hyrule Castle Tree",
+    "[Mai] Hyrule Castle Tiles",
+    "[Mai] Wooden Bridge",
+    "[Mai] Eastern Ruins Wall",
+    "[Mai] Eastern Ruins Yellow Tree",
+    "[Mai] Eastern Ruins Green Tree",
+    "[Mai] Eastern Ruins Rock",
+    "[Mai] Blacksmith Tiles",
+    "[Mai] Eastern Ruins Bonk Rocks",
+    "[Mai] Hyrule Rupee Rush Wall",
+    "[Mai] Cucco Ranch Tree",
+    "[Mai] Haunted Grove Tree",
+    "[Mai] Your House Tree",
+    "[Mai] Behind Your House",
+    "[Mai] Eastern Ruins River",
+    "[Mai] Southern Ruins Pillars",
+    "[Mai] Outside Flippers Mini-Dungeon",
+    "[Mai] Outside Maiamai Cave",
+    "[Mai] Lake Hylia East River",
+    "[Mai] Hyrule Hotfoot Rock",
+    "[Mai] Southern Ruins Big Rock",
+    "[Mai] Lake Hylia Shallow Ring",
+    "Maiamai Bow Upgrade",
+    "Maiamai Boomerang Upgrade",
+    "Maiamai Hookshot Upgrade",
+    "Maiamai Hammer Upgrade",
+    "Maiamai Bombs Upgrade",
+    "Maiamai Fire Rod Upgrade",
+    "Maiamai Ice Rod Upgrade",
+    "Maiamai Tornado Rod Upgrade",
+    "Maiamai Sand Rod Upgrade",
+    "100 Maiamai",
+    "Woman",
+    "[Mai] Kakariko Sand",
+    "[Mai] Waterfall Ledge",
+    "[Mai] Outside Cucco Mini-Dungeon",
+    "Cucco Mini-Dungeon",
+    "[Mai] Witch's House",
+    "Eastern Palace Weather Vane",
+    "Eastern Ruins Armos Chest",
+    "Eastern Ruins Hookshot Chest",
+    "Eastern Ruins Merge Chest",
+    "Queen Oren",
+    "Zora's Domain Ledge",
+    "[Mai] Zora's Domain",
+    "[Mai] South of Zora's Domain",
+    "Waterfall Cave",
+    "Merge Mini-Dungeon",
+    "Eastern Ruins Cave",
+    "House of Gales Weather Vane",
+    "[Mai] Lake Hylia Island Tile",
+    "Rosso (1)",
+    "Rosso (2)",
+    "Rosso Cave",
+    "River Mini-Dungeon",
+    "[Mai] Hyrule Graveyard Wall",
+    "Graveyard Ledge Cave",
+    "Blacksmith Table",
+    "Blacksmith",
+    "Blacksmith Cave",
+    "[HC] Throne",
+    "[HC] West Wing",
+    "[HC] Battlement",
+    "Lost Woods Alcove",
+    "Lost Woods Chest",
+    "[Mai] Lost Woods Bush",
+    "[Mai] Lost Woods Rock",
+    "Master Sword Pedestal",
+    "Fortune-Teller",
+    "Kakariko Jail",
+    "Kakariko Well (Top)",
+    "Kakariko Well (Bottom)",
+    "Stylish Woman",
+    "Bee Guy (1)",
+    "Bee Guy (2)",
+    "Kakariko Item Shop (1)",
+    "Kakariko Item Shop (2)",
+    "Kakariko Item Shop (3)",
+    "Lakeside Item Shop (1)",
+    "Lakeside Item Shop (2)",
+    "Lakeside Item Shop (3)",
+    "Runaway Item Seller",
+    "Flippers Mini-Dungeon",
+    "[Mai] Southern Ruins Bomb Cave",
+    "Southern Ruins Pillar Cave",
+    "Lake Hylia Dark Cave",
+    "Ice Rod Cave",
+    "[HS] Entrance",
+    "[HS] Lower Chest",
+    "[HS] Upper Chest",
+    "[HS] Ledge",
+    "[Mai] Moldorm Ledge",
+    "Death Mountain (Hyrule) Weather Vane",
+    "[Mai] Death Mountain Base Rock",
+    "Death Mountain Blocked Cave",
+    "Death Mountain Open Cave",
+    "Death Mountain Fairy Cave",
+    "Donkey Cave",
+    "Donkey Cave Ledge",
+    "[Mai] Death Mountain West Ledge",
+    "Death Mountain West Highest Cave",
+    "Spectacle Rock",
+    "Tower of Hera Weather Vane",
+    "[Mai] Outside Hookshot Mini-Dungeon",
+    "Hookshot Mini-Dungeon",
+    "Fire Cave Pillar",
+    "[Mai] Fire Cave Ledge",
+    "Bouldering Guy",
+    "[Mai] Rosso's Ore Mine",
+    "Floating Island",
+    "Vacant House Weather Vane",
+    "Blacksmith Weather Vane",
+    "Lorule Castle Weather Vane",
+    "Thieves' Town Weather Vane",
+    "Rupee Rush (Lorule)",
+    "Octoball Derby",
+    "Fortune's Choice",
+    "[Mai] Lorule Castle Wall",
+    "[Mai] Lorule Castle Tree",
+    "[Mai] Thieves' Town Wall",
+    "[Mai] Lorule Fortune-Teller Rock",
+    "[Mai] Lorule Blacksmith Wall",
+    "[Mai] Lorule Rupee Rush Wall",
+    "[Mai] Octoball Derby Skull",
+    "[Mai] Vacant House Rock",
+    "[Mai] Behind Vacant House",
+    "[Mai] Lorule S Ruins Pillars",
+    "[Mai] Lorule S Ruins Wall",
+    "[Mai] Lorule S Ruins Water",
+    "[Mai] Thieves' Town Tree",
+    "Thieves' Town Item Shop (1)",
+    "Thieves' Town Item Shop (2)",
+    "Thieves' Town Item Shop (3)",
+    "Thieves' Town Item Shop (4)",
+    "[Mai] Big Bomb Flower Grass",
+    "Graveyard Weather Vane",
+    "Graveyard Peninsula",
+    "[Mai] Lorule Graveyard Big Rock",
+    "[Mai] Lorule Graveyard Wall",
+    "[Mai] Lorule Graveyard Tree",
+    "[LS] Entrance Chest",
+    "[LS] Lower Chest",
+    "[LS] Upper Chest",
+    "[LS] Ledge",
+    "Philosopher's Cave",
+    "Great Rupee Fairy",
+    "Blacksmith (Lorule)",
+    "Pegasus Boots Pyramid",
+    "Vacant House",
+    "Thief Girl",
+    "Swamp Cave (Left)",
+    "Swamp Cave (Middle)",
+    "Swamp Cave (Right)",
+    "Big Bomb Flower Cave",
+    "Lorule Field Hookshot Chest",
+    "[Mai] Lorule Haunted Grove Wall",
+    "[Mai] Buried in the Desert",
+    "Desert Palace Weather Vane",
+    "[Mai] Buried near Desert Palace",
+    "Misery Mire Weather Vane",
+    "[Mai] Misery Mire Wall",
+    "[Mai] Misery Mire Water",
+    "[Mai] Misery Mire Rock",
+    "Sand Mini-Dungeon",
+    "Misery Mire Ledge",
+    "[Mai] Lorule Lake SE Wall",
+    "[Mai] Lorule Lake Skull",
+    "Lorule Lake Chest",
+    "[Mai] Lorule Lake West Wall",
+    "[Mai] Lorule Lake Rock",
+    "Lorule Lakeside Item Shop (1)",
+    "Lorule Lakeside Item Shop (2)",
+    "Lorule Lakeside Item Shop (3)",
+    "Lorule Lakeside Item Shop (4)",
+    "[Mai] Lorule Lake Water",
+    "Turtle Rock Weather Vane",
+    "Dark/Turtle Chest",
+    "[Mai] Dark Ruins Waterfall",
+    "[Mai] Dark Maze Entrance Wall",
+    "[Mai] Dark Ruins Bonk Rocks",
+    "[Mai] Dark Ruins West Tree",
+    "[Mai] Dark Ruins East Tree",
+    "[Mai] Dark Ruins South Wall",
+    "Dark Maze Chest",
+    "Dark Maze Ledge",
+    "[Mai] Dark Maze Center Wall",
+    "Dark Palace Weather Vane",
+    "[Mai] Ku's Domain Grass",
+    "Ku's Domain Fight",
+    "[Mai] Ku's Domain Water",
+    "[Mai] Outside Hinox Cave",
+    "Hinox (1)",
+    "Hinox (2)",
+    "Hinox (3)",
+    "Hinox (4)",
+    "Hinox (5)",
+    "Hinox (6)",
+    "Skull Woods Weather Vane",
+    "n-Shaped House",
+    "Destroyed House",
+    "[Mai] Skull Woods Grass",
+    "[Mai] Skull Woods Skull",
+    "[Mai] Destroyed House Tree",
+    "[Mai] Skull Woods Bush",
+    "[Mai] Skull Woods Rock",
+    "[Mai] Skull Woods Entrance Wall",
+    "[Mai] Skull Woods Dry Pond",
+    "[Mai] n-Shaped House Wall",
+    "Mysterious Man",
+    "Treacherous Tower Weather Vane",
+    "Ice Gimos Fight",
+    "Lorule Mountain W Ledge",
+    "Treacherous Tower",
+    "[Mai] Lorule Mountain W Skull",
+    "[Mai] Lorule Mountain W Big Rock",
+    "[Mai] Lorule Mountain E Big Rock",
+    "Death Mountain (Lorule) Weather Vane",
+    "[Mai] Lorule Mountain E Wall",
+    "Ice Cave Ledge",
+    "[Mai] Ice Cave Ledge",
+    "Ice Ruins Weather Vane",
+    "Behind Ice Gimos",
+    "[Mai] Outside Ice Ruins",
+    "[EP] (1F) Merge Chest",
+    "[EP] (1F) Left Door Chest",
+    "[EP] (1F) Popo Room",
+    "[EP] (1F) Secret Room",
+    "[EP] (1F) Switch Room",
+    "[EP] (2F) Defeat Popos",
+    "[EP] (2F) Ball Room",
+    "[EP] (2F) Switch Room",
+    "[EP] (2F) Big Chest",
+    "[EP] Yuga (1)",
+    "[EP] Yuga (2)",
+    "[EP] Prize",
+    "[EP] (3F) Escape Chest",
+    "[EP] (1F) Escape Chest",
+    "[HG] (1F) Torches",
+    "[HG] (1F) Switch Room",
+    "[HG] (1F) Fire Bubbles",
+    "[HG] (1F) West Room",
+    "[HG] (1F) West Room Secret",
+    "[HG] (2F) Narrow Ledge",
+    "[HG] (2F) Big Chest",
+    "[HG] (2F) Fire Ring",
+    "[HG] (3F) Fire Bubbles",
+    "[HG] (3F) Rat Room",
+    "[HG] Margomill",
+    "[HG] Prize",
+    "[TH] (1F) Outside",
+    "[TH] (1F) Center",
+    "[TH] (3F) Platform",
+    "[TH] (5F) Red/Blue Switches",
+    "[TH] (6F) Right Mole",
+    "[TH] (6F) Left Mole",
+    "[TH] (7F) Outside (Ledge)",
+    "[TH] (8F) Fairy Room",
+    "[TH] (11F) Big Chest",
+    "[TH] Moldorm",
+    "[TH] Prize",
+    "[PD] (1F) Right Pit",
+    "[PD] (1F) Left Pit",
+    "[PD] (1F) Switch Puzzle",
+    "[PD] (1F) Hidden Room (Upper)",
+    "[PD] (1F) Hidden Room (Lower)",
+    "[PD] (B1) Fall From 1F",
+    "[PD] (B1) Helmasaur Room",
+    "[PD] (B1) Helmasaur Room (Fall)",
+    "[PD] (B1) Glow-in-the-Dark Maze",
+    "[PD] (1F) Fall From 2F",
+    "[PD] (2F) Big Chest (Hidden)",
+    "[PD] (2F) South Hidden Room",
+    "[PD] (2F) Alcove",
+    "[PD] (B1) Bomb Bowling",
+    "[PD] Gemesaur King",
+    "[PD] Prize",
+    "Swamp Palace Weather Vane",
+    "[SP] (B1) Center",
+    "[SP] (B1) Waterfall Room",
+    "[SP] (B1) Raft Room (Pillar)",
+    "[SP] (B1) Raft Room (Right)",
+    "[SP] (B1) Raft Room (Left)",
+    "[SP] (B1) Gyorm",
+    "[SP] (B1) Big Chest (Secret)",
+    "[SP] (1F) West Room",
+    "[SP] (1F) East Room",
+    "[SP] (1F) Water Puzzle",
+    "[SP] (1F) Big Chest (Fire)",
+    "[SP] Arrghus",
+    "[SP] Prize",
+    "[SW] (B1) South Chest",
+    "[SW] (B1) Gibdo Room (Lower)",
+    "[SW] (B1) Gibdo Room (Hole)",
+    "[SW] (B1) Grate Room",
+    "[SW] (B2) Moving Platform Room",
+    "[SW] Knucklemaster",
+    "[SW] Prize",
+    "[SW] (B1) Big Chest (Eyes)",
+    "[SW] (B1) Big Chest (Upper)",
+    "[SW] Outdoor Chest",
+    "[TT] (B1) Grate Chest",
+    "[TT] (B1) Jail Cell",
+    "[TT] (B2) Grate Chest (Fall)",
+    "[TT] (B2) Jail Cell",
+    "[TT] (B2) Switch Puzzle Room",
+    "[TT] (B2) Eyegores",
+    "[TT] (B3) Underwater",
+    "[TT] (B3) Big Chest (Hidden)",
+    "[TT] (B1) Behind Wall",
+    "[TT] (B1) Big Chest (Entrance)",
+    "[TT] Stalblind",
+    "[TT] Prize",
+    "[IR] (1F) Hidden Chest",
+    "[IR] (B2) Ice Pillar",
+    "[IR] (B3) Grate Chest (Left)",
+    "[IR] (B3) Grate Chest (Right)",
+    "[IR] (B5) Big Chest",
+    "[IR] (B1) Narrow Ledge",
+    "[IR] (B1) East Chest",
+    "[IR] (B1) Upper Chest",
+    "[IR] (B2) Long Merge Chest",
+    "[IR] (B3) Big Chest (Puzzle)",
+    "[IR] (B4) Switches",
+    "[IR] (B4) Southwest Chest (Fall)",
+    "[IR] (B4) Narrow Platform",
+    "[IR] (B4) Southeast Chest (Fall)",
+    "[IR] Dharkstare",
+    "[IR] Prize",
+    "[DP] (1F) Entrance",
+    "[DP] (1F) Sand Switch Room",
+    "[DP] (1F) Sand Room (North)",
+    "[DP] (1F) Sand Room (South)",
+    "[DP] (1F) Behind Rocks",
+    "[DP] (1F) Big Chest (Behind Wall)",
+    "[DP] (2F) Under Rock (Left)",
+    "[DP] (2F) Under Rock (Right)",
+    "[DP] (2F) Under Rock (Ball Room)",
+    "[DP] (2F) Beamos Room",
+    "[DP] (2F) Red/Blue Switches",
+    "[DP] (2F) Big Chest (Puzzle)",
+    "[DP] (2F) Leever Room",
+    "[DP] (3F) Behind Falling Sand",
+    "[DP] (3F) Armos Room",
+    "[DP] Zaganaga",
+    "[DP] Prize",
+    "[TR] (1F) Center",
+    "[TR] (1F) Northeast Ledge",
+    "[TR] (1F) Southeast Chest",
+    "[TR] (1F) Defeat Flamolas",
+    "[TR] (1F) Northwest Room",
+    "[TR] (1F) Grate Chest",
+    "[TR] (B1) Northeast Room",
+    "[TR] (B1) Grate Chest (Small)",
+    "[TR] (B1) Big Chest (Top)",
+    "[TR] (B1) Big Chest (Center)",
+    "[TR] (B1) Platform",
+    "[TR] (1F) Under Center",
+    "[TR] (B1) Under Center",
+    "[TR] Left Balcony",
+    "[TR] Grinexx",
+    "[TR] Prize",
+    "[LC] (1F) Ledge",
+    "[LC] (1F) Center",
+    "[LC] (2F) Near Torches",
+    "[LC] (2F) Hidden Path",
+    "[LC] (2F) Ledge",
+    "[LC] Bomb Trial (1)",
+    "[LC] Bomb Trial (2)",
+    "[LC] Tile Trial (1)",
+    "[LC] Tile Trial (2)",
+    "[LC] Lamp Trial",
+    "[LC] Hook Trial (2)",
+    "[LC] Hook Trial (1)",
+    "[LC] (4F) Center",
+    "[LC] (4F) Hidden Path",
+    "[LC] Zelda",
+];
@@ -3,3 +3,6 @@
 /// Build Version
 pub const VERSION: &str = "v0.4.0 - Beta Build 2024-08-15";
 pub const CONFIG_FILE_NAME: &str = "config.json";
+
+/// Spoiler Log format version, bumped whenever older Spoiler Logs can no longer be read back in full.
+pub const SPOILER_SCHEMA_VERSION: u32 = 2;
@@ -8,7 +8,7 @@ use {
 };
 
 /// A specific location containing a randomized item
-#[derive(Copy, Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize)]
 pub struct Check {
     name: &'static str,
     logic: Logic,
@@ -27,8 +27,8 @@ impl Check {
         self.name
     }
 
-    pub fn get_logic(&self) -> Logic {
-        self.logic
+    pub fn get_logic(&self) -> &Logic {
+        &self.logic
     }
 
     pub fn get_quest(&self) -> Option<Randomizable> {
@@ -12,13 +12,14 @@ use rand::rngs::StdRng;
 use rand::Rng;
 use rom::flag::Flag;
 use rom::scene::SpawnPoint;
-use serde::{Serialize, Serializer};
+use serde::{Deserialize, Serialize, Serializer};
 use std::cmp::Ordering;
+use strum::{EnumIter, EnumString};
 use std::fmt::{Display, Formatter};
 use std::hash::Hash;
 
 /// Crack item
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Deserialize, EnumIter, EnumString)]
 #[pyclass]
 pub enum Crack {
     // --- Hyrule --- //
@@ -558,7 +559,8 @@ impl Serialize for Crack {
 /// Builds out the CrackMap for use traversing the WorldGraph.
 /// Shuffles the crack destinations if CrackShuffle is enabled.
 ///
-/// The 6 pairs of "down-facing" Cracks are only shuffled between themselves, for technical reasons
+/// The 6 pairs of "down-facing" Cracks are only shuffled between themselves, for technical reasons.
+/// The Decoupled modes chain each pool of Cracks into a single one-way loop, so the CrackMap is no longer symmetric.
 #[allow(clippy::manual_while_let_some)]
 pub fn build_crack_map(settings: &Settings, rng: &mut StdRng) -> Result<CrackMap> {
     info!("Building Crack Map...");
@@ -732,6 +734,38 @@ pub fn build_crack_map(settings: &Settings, rng: &mut StdRng) -> Result<CrackMap
                 }
             }
         },
+        Cracksanity::DecoupledCrossWorld => {
+            // UP - Alternate between worlds in one big loop, so following the Cracks from anywhere leads everywhere
+            let hyrule_up_cracks = filler::util::shuffle(rng, hyrule_up_cracks);
+            let lorule_up_cracks = filler::util::shuffle(rng, lorule_up_cracks);
+            crack_map.extend(filler::util::chain(interleave(hyrule_up_cracks, lorule_up_cracks)));
+
+            // DOWN
+            let hyrule_down_cracks = filler::util::shuffle(rng, hyrule_down_cracks);
+            let lorule_down_cracks = filler::util::shuffle(rng, lorule_down_cracks);
+            crack_map.extend(filler::util::chain(interleave(hyrule_down_cracks, lorule_down_cracks)));
+        },
+        Cracksanity::DecoupledAnyWorld => {
+            // Force Hyrule Castle crack to always lead to a Lorule (Up) crack
+            let hc_match = lorule_up_cracks.remove(rng.gen_range(0..lorule_up_cracks.len()));
+            hyrule_up_cracks.retain(|&p| p != Crack::HyruleCastle);
+
+            // UP - One big loop starting from Hyrule Castle, so following the Cracks from anywhere leads everywhere
+            let mut up_cracks = Vec::new();
+            up_cracks.extend(hyrule_up_cracks);
+            up_cracks.extend(lorule_up_cracks);
+
+            let mut up_loop = vec![Crack::HyruleCastle, hc_match];
+            up_loop.extend(filler::util::shuffle(rng, up_cracks));
+            crack_map.extend(filler::util::chain(up_loop));
+
+            // DOWN
+            let mut down_cracks = Vec::new();
+            down_cracks.extend(hyrule_down_cracks);
+            down_cracks.extend(lorule_down_cracks);
+
+            crack_map.extend(filler::util::chain(filler::util::shuffle(rng, down_cracks)));
+        },
     }
 
     // info!("{:?}", crack_map);
@@ -760,3 +794,104 @@ where
         crack_map.insert(vec2[i], vec1[i]);
     }
 }
+
+/// Alternates between the entries of two equal length Vecs
+fn interleave<T>(vec1: Vec<T>, vec2: Vec<T>) -> Vec<T> {
+    vec1.into_iter().zip(vec2).flat_map(|(a, b)| [a, b]).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{build_crack_map, Crack};
+    use crate::filler::item_pools;
+    use crate::CrackMap;
+    use modinfo::settings::cracksanity::Cracksanity;
+    use modinfo::Settings;
+    use rand::{rngs::StdRng, SeedableRng};
+    use std::collections::BTreeSet;
+    use strum::IntoEnumIterator;
+
+    fn crack_maps(cracksanity: Cracksanity) -> impl Iterator<Item = CrackMap> {
+        let settings = Settings { cracksanity, ..Default::default() };
+        (0..20).map(move |seed| build_crack_map(&settings, &mut StdRng::seed_from_u64(seed)).unwrap())
+    }
+
+    fn all_cracksanity() -> impl Iterator<Item = Cracksanity> {
+        (0..).map_while(|index| Cracksanity::try_from(index).ok())
+    }
+
+    fn is_up(crack: Crack) -> bool {
+        item_pools::get_hyrule_up_cracks().contains(&crack) || item_pools::get_lorule_up_cracks().contains(&crack)
+    }
+
+    #[test]
+    fn every_mode_is_a_bijection() {
+        let all_cracks = Crack::iter().collect::<BTreeSet<_>>();
+        for cracksanity in all_cracksanity() {
+            for crack_map in crack_maps(cracksanity) {
+                assert_eq!(crack_map.keys().copied().collect::<BTreeSet<_>>(), all_cracks, "{}", cracksanity);
+                assert_eq!(crack_map.values().copied().collect::<BTreeSet<_>>(), all_cracks, "{}", cracksanity);
+                for (&from, &to) in &crack_map {
+                    assert_ne!(from, to, "{}: {} leads to itself", cracksanity, from);
+                    assert_eq!(is_up(from), is_up(to), "{}: {} leads to {}", cracksanity, from, to);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn paired_modes_lead_back() {
+        for cracksanity in all_cracksanity() {
+            if matches!(cracksanity, Cracksanity::DecoupledCrossWorld | Cracksanity::DecoupledAnyWorld) {
+                continue;
+            }
+            for crack_map in crack_maps(cracksanity) {
+                for (from, to) in &crack_map {
+                    assert_eq!(crack_map[to], *from, "{}: {} doesn't lead back from {}", cracksanity, to, from);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn decoupled_modes_are_one_way_chains() {
+        for cracksanity in [Cracksanity::DecoupledCrossWorld, Cracksanity::DecoupledAnyWorld] {
+            for crack_map in crack_maps(cracksanity) {
+                // The Desert Palace and Zaganaga Cracks stay vanilla, every other Crack is part of one loop per pool
+                assert_eq!(crack_map[&Crack::DesertPalace], Crack::Zaganaga);
+                assert_eq!(crack_map[&Crack::Zaganaga], Crack::DesertPalace);
+
+                for start in [Crack::HyruleCastle, Crack::YourHouse] {
+                    let mut chain = vec![start];
+                    let mut crack = crack_map[&start];
+                    while crack != start {
+                        chain.push(crack);
+                        crack = crack_map[&crack];
+                    }
+
+                    let pool = crack_map
+                        .keys()
+                        .filter(|&&crack| is_up(crack) == is_up(start))
+                        .filter(|&&crack| !matches!(crack, Crack::DesertPalace | Crack::Zaganaga))
+                        .count();
+                    assert_eq!(chain.len(), pool, "{}: loop from {} doesn't visit its whole pool", cracksanity, start);
+
+                    if cracksanity == Cracksanity::DecoupledCrossWorld {
+                        for (from, to) in chain.iter().zip(chain.iter().cycle().skip(1)) {
+                            assert!(from.get_world() != to.get_world(), "{} leads to {}", from, to);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn hyrule_castle_leads_to_lorule() {
+        for cracksanity in all_cracksanity() {
+            for crack_map in crack_maps(cracksanity) {
+                assert!(crack_map[&Crack::HyruleCastle].get_world() == game::World::Lorule, "{}", cracksanity);
+            }
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use crate::filler::loading_zone_pair::{LoadingZoneId, LoadingZones};
+use crate::filler::util::shuffle;
+use crate::Result;
+use log::info;
+use modinfo::settings::entrance_shuffle::EntranceShuffle;
+use modinfo::Settings;
+use rand::rngs::StdRng;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Map of each shuffled door to the interior it leads into, and of each shuffled interior to the door it leads back out
+/// of. Map is not bidirectional to allow for decoupled shuffle. Doors missing from the map keep their vanilla
+/// connections, so an empty map is the vanilla game.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct EntranceMap {
+    entrances: BTreeMap<LoadingZoneId, LoadingZoneId>,
+    exits: BTreeMap<LoadingZoneId, LoadingZoneId>,
+}
+
+impl EntranceMap {
+    pub(crate) fn new(
+        entrances: BTreeMap<LoadingZoneId, LoadingZoneId>, exits: BTreeMap<LoadingZoneId, LoadingZoneId>,
+    ) -> Self {
+        Self { entrances, exits }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entrances.is_empty() && self.exits.is_empty()
+    }
+
+    /// The door whose interior the player enters when going in through `door`.
+    pub fn get_entrance(&self, door: LoadingZoneId) -> LoadingZoneId {
+        self.entrances.get(&door).copied().unwrap_or(door)
+    }
+
+    /// The door the player comes out of when leaving the interior of `door`.
+    pub fn get_exit(&self, door: LoadingZoneId) -> LoadingZoneId {
+        self.exits.get(&door).copied().unwrap_or(door)
+    }
+
+    pub(crate) fn entrances(&self) -> impl Iterator<Item = (LoadingZoneId, LoadingZoneId)> + '_ {
+        self.entrances.iter().map(|(&door, &interior)| (door, interior))
+    }
+
+    pub(crate) fn exits(&self) -> impl Iterator<Item = (LoadingZoneId, LoadingZoneId)> + '_ {
+        self.exits.iter().map(|(&interior, &door)| (interior, door))
+    }
+}
+
+/// Shuffles the dungeon, cave, and house entrances amongst their own kind, if Entrance Shuffle is enabled.
+pub fn build_entrance_map(settings: &Settings, rng: &mut StdRng) -> Result<EntranceMap> {
+    info!("Building Entrance Map...");
+    let mut entrance_map = EntranceMap::default();
+
+    if settings.entrance_shuffle == EntranceShuffle::Off {
+        return Ok(entrance_map);
+    }
+
+    for doors in [LoadingZones::dungeons(), LoadingZones::caves(), LoadingZones::houses()] {
+        let interiors = shuffle(rng, doors.clone());
+
+        match settings.entrance_shuffle {
+            EntranceShuffle::Off => unreachable!(),
+            EntranceShuffle::Coupled => {
+                for (&door, &interior) in doors.iter().zip(&interiors) {
+                    entrance_map.entrances.insert(door, interior);
+                    entrance_map.exits.insert(interior, door);
+                }
+            },
+            EntranceShuffle::Decoupled => {
+                let exits = shuffle(rng, doors.clone());
+                entrance_map.entrances.extend(doors.iter().copied().zip(interiors));
+                entrance_map.exits.extend(doors.iter().copied().zip(exits));
+            },
+        }
+    }
+
+    Ok(entrance_map)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{build_entrance_map, EntranceMap};
+    use crate::filler::loading_zone_pair::LoadingZones;
+    use modinfo::settings::entrance_shuffle::EntranceShuffle;
+    use modinfo::Settings;
+    use rand::{rngs::StdRng, SeedableRng};
+    use std::collections::BTreeSet;
+
+    fn entrance_maps(entrance_shuffle: EntranceShuffle) -> impl Iterator<Item = EntranceMap> {
+        let settings = Settings { entrance_shuffle, ..Default::default() };
+        (0..20).map(move |seed| build_entrance_map(&settings, &mut StdRng::seed_from_u64(seed)).unwrap())
+    }
+
+    /// Checks that every door of each kind leads into exactly one interior of the same kind, and out of exactly one.
+    fn assert_shuffled_by_kind(entrance_map: &EntranceMap) {
+        for doors in [LoadingZones::dungeons(), LoadingZones::caves(), LoadingZones::houses()] {
+            let kind = doors.iter().copied().collect::<BTreeSet<_>>();
+            let interiors = doors.iter().map(|&door| entrance_map.get_entrance(door)).collect::<BTreeSet<_>>();
+            let exits = doors.iter().map(|&interior| entrance_map.get_exit(interior)).collect::<BTreeSet<_>>();
+            assert_eq!(interiors, kind);
+            assert_eq!(exits, kind);
+        }
+    }
+
+    #[test]
+    fn off_is_vanilla() {
+        for entrance_map in entrance_maps(EntranceShuffle::Off) {
+            assert!(entrance_map.is_empty());
+            for door in [LoadingZones::dungeons(), LoadingZones::caves(), LoadingZones::houses()].concat() {
+                assert_eq!(entrance_map.get_entrance(door), door);
+                assert_eq!(entrance_map.get_exit(door), door);
+            }
+        }
+    }
+
+    #[test]
+    fn coupled_leads_back_out_the_same_door() {
+        for entrance_map in entrance_maps(EntranceShuffle::Coupled) {
+            assert_shuffled_by_kind(&entrance_map);
+            for (door, interior) in entrance_map.entrances() {
+                assert_eq!(entrance_map.get_exit(interior), door);
+            }
+        }
+    }
+
+    #[test]
+    fn decoupled_shuffles_exits_separately() {
+        let mut any_decoupled = false;
+        for entrance_map in entrance_maps(EntranceShuffle::Decoupled) {
+            assert_shuffled_by_kind(&entrance_map);
+            any_decoupled |= entrance_map.entrances().any(|(door, interior)| entrance_map.get_exit(interior) != door);
+        }
+        assert!(any_decoupled);
+    }
+}
@@ -5,7 +5,8 @@ use crate::Result;
 use game::ghosts::HintGhost;
 use pyo3::prelude::*;
 use rom::flag::Flag;
-use serde::{Serialize, Serializer};
+use serde::{Deserialize, Serialize, Serializer};
+use strum::{EnumIter, EnumString, IntoStaticStr};
 use std::collections::hash_map::DefaultHasher;
 use std::fmt::Debug;
 use std::hash::{Hash, Hasher};
@@ -179,7 +180,37 @@ impl Randomizable {
                     | Item::ScootFruit01
                     | Item::ScootFruit02
                     | Item::GoldBee01
-                    | Item::TriforceOfCourage,
+                    | Item::TriforceOfCourage
+                    | Item::TriforcePiece01
+                    | Item::TriforcePiece02
+                    | Item::TriforcePiece03
+                    | Item::TriforcePiece04
+                    | Item::TriforcePiece05
+                    | Item::TriforcePiece06
+                    | Item::TriforcePiece07
+                    | Item::TriforcePiece08
+                    | Item::TriforcePiece09
+                    | Item::TriforcePiece10
+                    | Item::TriforcePiece11
+                    | Item::TriforcePiece12
+                    | Item::TriforcePiece13
+                    | Item::TriforcePiece14
+                    | Item::TriforcePiece15
+                    | Item::TriforcePiece16
+                    | Item::TriforcePiece17
+                    | Item::TriforcePiece18
+                    | Item::TriforcePiece19
+                    | Item::TriforcePiece20
+                    | Item::TriforcePiece21
+                    | Item::TriforcePiece22
+                    | Item::TriforcePiece23
+                    | Item::TriforcePiece24
+                    | Item::TriforcePiece25
+                    | Item::TriforcePiece26
+                    | Item::TriforcePiece27
+                    | Item::TriforcePiece28
+                    | Item::TriforcePiece29
+                    | Item::TriforcePiece30,
             ) | Self::Goal(
                 Goal::RavioShopOpen
                     | Goal::ShadyGuyTrigger
@@ -337,7 +368,7 @@ impl Randomizable {
             Item(SageRosso) => Some(MsbfKey::Ice),
             Item(SageImpa) => None, // Impa special
             Item(PendantOfPower) | Item(PendantOfWisdom) | Item(PendantOfCourage) => None,
-            _ => macros::fail!("Not an MSBF Key: {:?}", self),
+            _ => unreachable!("Not an MSBF Key: {:?}", self),
         }
     }
 
@@ -440,7 +471,7 @@ impl PyRandomizable {
     }
 }
 
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Ord, PartialOrd)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Ord, PartialOrd, Deserialize, EnumIter, EnumString, IntoStaticStr)]
 #[pyclass]
 pub enum Item {
     Empty,
@@ -850,6 +881,38 @@ pub enum Item {
 
     TriforceOfCourage,
 
+    // Triforce Hunt
+    TriforcePiece01,
+    TriforcePiece02,
+    TriforcePiece03,
+    TriforcePiece04,
+    TriforcePiece05,
+    TriforcePiece06,
+    TriforcePiece07,
+    TriforcePiece08,
+    TriforcePiece09,
+    TriforcePiece10,
+    TriforcePiece11,
+    TriforcePiece12,
+    TriforcePiece13,
+    TriforcePiece14,
+    TriforcePiece15,
+    TriforcePiece16,
+    TriforcePiece17,
+    TriforcePiece18,
+    TriforcePiece19,
+    TriforcePiece20,
+    TriforcePiece21,
+    TriforcePiece22,
+    TriforcePiece23,
+    TriforcePiece24,
+    TriforcePiece25,
+    TriforcePiece26,
+    TriforcePiece27,
+    TriforcePiece28,
+    TriforcePiece29,
+    TriforcePiece30,
+
     // Shop Items (treated as Quest Items) ---------------------------------------------------------
 
     // Kakariko
@@ -1044,6 +1107,11 @@ impl Item {
             Fairy01 | Fairy02 => game::Item::Fairy,
 
             TriforceOfCourage => game::Item::TriforceCourage,
+            TriforcePiece01 | TriforcePiece02 | TriforcePiece03 | TriforcePiece04 | TriforcePiece05 | TriforcePiece06
+            | TriforcePiece07 | TriforcePiece08 | TriforcePiece09 | TriforcePiece10 | TriforcePiece11 | TriforcePiece12
+            | TriforcePiece13 | TriforcePiece14 | TriforcePiece15 | TriforcePiece16 | TriforcePiece17 | TriforcePiece18
+            | TriforcePiece19 | TriforcePiece20 | TriforcePiece21 | TriforcePiece22 | TriforcePiece23 | TriforcePiece24
+            | TriforcePiece25 | TriforcePiece26 | TriforcePiece27 | TriforcePiece28 | TriforcePiece29 | TriforcePiece30 => game::Item::TriforceCourage,
         }
     }
 
@@ -1178,6 +1246,12 @@ impl Item {
 
             TriforceOfCourage => "the",
 
+            TriforcePiece01 | TriforcePiece02 | TriforcePiece03 | TriforcePiece04 | TriforcePiece05 | TriforcePiece06
+            | TriforcePiece07 | TriforcePiece08 | TriforcePiece09 | TriforcePiece10 | TriforcePiece11 | TriforcePiece12
+            | TriforcePiece13 | TriforcePiece14 | TriforcePiece15 | TriforcePiece16 | TriforcePiece17 | TriforcePiece18
+            | TriforcePiece19 | TriforcePiece20 | TriforcePiece21 | TriforcePiece22 | TriforcePiece23 | TriforcePiece24
+            | TriforcePiece25 | TriforcePiece26 | TriforcePiece27 | TriforcePiece28 | TriforcePiece29 | TriforcePiece30 => "a",
+
             Quake => "",
         }
     }
@@ -1315,6 +1389,11 @@ impl Item {
             Fairy01 | Fairy02 => "Fairy",
             Shield01 | Shield02 | Shield03 | Shield04 => "Shield",
             TriforceOfCourage => "Triforce of Courage",
+            TriforcePiece01 | TriforcePiece02 | TriforcePiece03 | TriforcePiece04 | TriforcePiece05 | TriforcePiece06
+            | TriforcePiece07 | TriforcePiece08 | TriforcePiece09 | TriforcePiece10 | TriforcePiece11 | TriforcePiece12
+            | TriforcePiece13 | TriforcePiece14 | TriforcePiece15 | TriforcePiece16 | TriforcePiece17 | TriforcePiece18
+            | TriforcePiece19 | TriforcePiece20 | TriforcePiece21 | TriforcePiece22 | TriforcePiece23 | TriforcePiece24
+            | TriforcePiece25 | TriforcePiece26 | TriforcePiece27 | TriforcePiece28 | TriforcePiece29 | TriforcePiece30 => "Triforce Piece",
             Quake => "Quake",
         }
     }
@@ -1342,7 +1421,7 @@ impl Serialize for Item {
 }
 
 // Quest Items ---------------------------------------------------------------------------------
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, EnumIter, EnumString)]
 #[pyclass]
 pub enum Goal {
     // Bosses -------
@@ -1458,7 +1537,7 @@ impl Serialize for Goal {
 }
 
 /// Weather Vane Item
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Ord, PartialOrd)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Ord, PartialOrd, Deserialize, EnumIter)]
 #[pyclass]
 pub enum Vane {
     BlacksmithWV,
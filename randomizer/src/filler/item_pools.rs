@@ -5,6 +5,7 @@ use crate::filler::filler_item::Vane;
 use crate::filler::util::shuffle;
 use crate::SeedInfo;
 use modinfo::settings::cracks::Cracks;
+use modinfo::settings::goal::GoalSetting;
 use modinfo::settings::keysy::Keysy;
 use modinfo::settings::logic::LogicMode;
 use modinfo::settings::nice_items::NiceItems;
@@ -86,6 +87,13 @@ pub(crate) fn get_item_pools(rng: &mut StdRng, SeedInfo { settings, .. }: &SeedI
         progression_items.extend_from_slice(&[Sword01, Sword02, Sword03, Sword04]);
     }
 
+    // Triforce Hunt. Replaces junk with the Triforce Pieces.
+    if settings.goal == GoalSetting::TriforceHunt {
+        let triforce_pieces = get_triforce_piece_pool().into_iter().take(settings.triforce_pieces as usize);
+        delta_junk_items -= triforce_pieces.len() as i32;
+        progression_items.extend(triforce_pieces);
+    }
+
     // Junk Pool. Add or remove elements from the junk pool based on chosen settings.
     let junk_pool = get_base_junk_pool(rng);
     let mut junk_pool = shuffle(rng, junk_pool);
@@ -311,11 +319,22 @@ fn get_base_junk_pool(rng: &mut StdRng) -> Vec<Item> {
     junk
 }
 
-fn add_random_junk_item(rng: &mut StdRng, junk_pool: &mut Vec<Item>) {
+pub(crate) fn add_random_junk_item(rng: &mut StdRng, junk_pool: &mut Vec<Item>) {
     const POSSIBLE_EXTRA_ITEMS: [Item; 3] = [MonsterTail, MonsterHorn, MonsterGuts];
     junk_pool.push(POSSIBLE_EXTRA_ITEMS[rng.gen_range(0..POSSIBLE_EXTRA_ITEMS.len())]);
 }
 
+/// All the Triforce Pieces that may be shuffled into the item pool for the Triforce Hunt goal.
+pub fn get_triforce_piece_pool() -> Vec<Item> {
+    vec![
+        TriforcePiece01, TriforcePiece02, TriforcePiece03, TriforcePiece04, TriforcePiece05, TriforcePiece06,
+        TriforcePiece07, TriforcePiece08, TriforcePiece09, TriforcePiece10, TriforcePiece11, TriforcePiece12,
+        TriforcePiece13, TriforcePiece14, TriforcePiece15, TriforcePiece16, TriforcePiece17, TriforcePiece18,
+        TriforcePiece19, TriforcePiece20, TriforcePiece21, TriforcePiece22, TriforcePiece23, TriforcePiece24,
+        TriforcePiece25, TriforcePiece26, TriforcePiece27, TriforcePiece28, TriforcePiece29, TriforcePiece30,
+    ]
+}
+
 pub fn get_maiamai_pool() -> Vec<Item> {
     vec![
         Maiamai001, Maiamai002, Maiamai003, Maiamai004, Maiamai005, Maiamai006, Maiamai007, Maiamai008, Maiamai009,
@@ -9,7 +9,7 @@ pub struct LocationNode {
 }
 
 impl LocationNode {
-    pub fn new<C, P>(_name: &'static str, checks: C, paths: P) -> Self
+    pub fn new<C, P>(_name: &str, checks: C, paths: P) -> Self
     where
         C: Into<Option<Vec<Check>>>,
         P: Into<Option<Vec<Path>>>,
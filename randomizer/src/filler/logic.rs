@@ -1,19 +1,20 @@
 use serde::{Serialize, Serializer};
 use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
 
 use modinfo::settings::logic::LogicMode::*;
 
 use crate::filler::progress::Progress;
+use crate::filler::requirement::Requirement;
 
-// TODO I'd eventually like to externalize the logic, both for organization purposes and to allow users to write custom logic. But this is fine for now.
-
-#[derive(Copy, Clone, Default)]
+/// The requirements to access a Check or Path under each Logic Mode, as read from the logic file.
+#[derive(Clone, Default)]
 pub struct Logic {
-    pub normal: Option<fn(&Progress) -> bool>,
-    pub hard: Option<fn(&Progress) -> bool>,
-    pub glitched: Option<fn(&Progress) -> bool>,
-    pub adv_glitched: Option<fn(&Progress) -> bool>,
-    pub hell: Option<fn(&Progress) -> bool>,
+    pub normal: Option<Arc<Requirement>>,
+    pub hard: Option<Arc<Requirement>>,
+    pub glitched: Option<Arc<Requirement>>,
+    pub adv_glitched: Option<Arc<Requirement>>,
+    pub hell: Option<Arc<Requirement>>,
 }
 
 impl Logic {
@@ -21,57 +22,28 @@ impl Logic {
         Self { normal: None, hard: None, glitched: None, adv_glitched: None, hell: None }
     }
 
-    pub fn config<L>(normal: L, hard: L, glitched: L, adv_glitched: L, hell: L) -> Self
-    where
-        L: Into<Option<fn(&Progress) -> bool>>,
-    {
-        Self {
-            normal: normal.into(),
-            hard: hard.into(),
-            glitched: glitched.into(),
-            adv_glitched: adv_glitched.into(),
-            hell: hell.into(),
-        }
-    }
-
-    pub fn normal(&mut self, logic: fn(&Progress) -> bool) -> &mut Self {
-        self.normal = logic.into();
-        self
-    }
-
-    pub fn hard(&mut self, logic: fn(&Progress) -> bool) -> &mut Self {
-        self.hard = logic.into();
-        self
+    /// Whether no logic has been defined for any mode, i.e. this is only ever accessible with No Logic.
+    pub fn is_empty(&self) -> bool {
+        self.normal.is_none()
+            && self.hard.is_none()
+            && self.glitched.is_none()
+            && self.adv_glitched.is_none()
+            && self.hell.is_none()
     }
 
-    pub fn glitched(&mut self, logic: fn(&Progress) -> bool) -> &mut Self {
-        self.glitched = logic.into();
-        self
-    }
-
-    pub fn adv_glitched(&mut self, logic: fn(&Progress) -> bool) -> &mut Self {
-        self.adv_glitched = logic.into();
-        self
-    }
-
-    pub fn hell(&mut self, logic: fn(&Progress) -> bool) -> &mut Self {
-        self.hell = logic.into();
-        self
-    }
-
-    pub fn can_access(self, progress: &Progress) -> bool {
+    pub fn can_access(&self, progress: &Progress) -> bool {
         // Progression is available if the current logic or a lower tiered logic passes
         for logic in match progress.get_settings().logic_mode {
-            Normal => Vec::from([self.normal]),
-            Hard => Vec::from([self.normal, self.hard]),
-            Glitched => Vec::from([self.normal, self.hard, self.glitched]),
-            AdvGlitched => Vec::from([self.normal, self.hard, self.glitched, self.adv_glitched]),
-            Hell => Vec::from([self.normal, self.hard, self.glitched, self.adv_glitched, self.hell]),
+            Normal => Vec::from([&self.normal]),
+            Hard => Vec::from([&self.normal, &self.hard]),
+            Glitched => Vec::from([&self.normal, &self.hard, &self.glitched]),
+            AdvGlitched => Vec::from([&self.normal, &self.hard, &self.glitched, &self.adv_glitched]),
+            Hell => Vec::from([&self.normal, &self.hard, &self.glitched, &self.adv_glitched, &self.hell]),
             NoLogic => {
                 return true;
             },
         } {
-            if logic.is_some() && (logic.unwrap())(progress) {
+            if logic.as_ref().is_some_and(|requirement| requirement.eval(progress)) {
                 return true;
             }
         }
@@ -80,12 +52,13 @@ impl Logic {
     }
 
     pub fn free() -> Self {
+        let accessible = Some(Arc::new(Requirement::Const(true)));
         Self {
-            normal: accessible(),
-            hard: accessible(),
-            glitched: accessible(),
-            adv_glitched: accessible(),
-            hell: accessible(),
+            normal: accessible.clone(),
+            hard: accessible.clone(),
+            glitched: accessible.clone(),
+            adv_glitched: accessible.clone(),
+            hell: accessible,
         }
     }
 }
@@ -104,7 +77,3 @@ impl Serialize for Logic {
         serializer.serialize_str("[logic]")
     }
 }
-
-pub fn accessible() -> Option<fn(&Progress) -> bool> {
-    Some(|_| true)
-}
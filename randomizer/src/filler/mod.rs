@@ -4,27 +4,31 @@ use crate::filler::filler_item::{Item, Randomizable};
 use crate::filler::item_pools::{get_maiamai_pool, Pool};
 use crate::filler::location::Location;
 use crate::filler::progress::Progress;
+use crate::regions::World;
 use crate::{world::WorldGraph, CheckMap, DashMap, SeedInfo};
-use log::{debug, error, info};
-use macros::fail;
+use log::{debug, info};
+use modinfo::settings::keysanity::Keysanity;
 use modinfo::settings::logic::LogicMode;
 use modinfo::settings::nice_items::NiceItems;
+use modinfo::Settings;
 use path::Path;
 use queue::Queue;
 use rand::{rngs::StdRng, Rng};
 use rom::Error;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 
 pub mod check;
 pub mod cracks;
+pub mod entrances;
 pub mod filler_item;
 pub mod item_pools;
-mod loading_zone_pair;
+pub mod loading_zone_pair;
 pub mod location;
 pub mod location_node;
 pub mod logic;
 pub mod path;
 pub mod progress;
+pub mod requirement;
 pub(crate) mod text;
 pub mod tower_stage;
 pub(crate) mod treacherous_tower;
@@ -46,7 +50,7 @@ pub fn fill_all_locations_reachable(
     verify_all_locations_accessible(seed_info, check_map, &mut progression_pool)?;
 
     preplace_items(rng, seed_info, check_map, &mut progression_pool)?;
-    handle_exclusions(rng, seed_info, check_map, &mut junk_pool);
+    handle_exclusions(rng, seed_info, check_map, &mut junk_pool)?;
 
     assumed_fill(rng, seed_info, check_map, &mut progression_pool, &mut junk_pool)?;
     fill_junk(rng, check_map, &mut junk_pool)?;
@@ -56,8 +60,87 @@ pub fn fill_all_locations_reachable(
     Ok(())
 }
 
+/// Fill Seed around the items hand-placed by a Plando
+///
+/// The Plando's placements are made first so that static items, exclusions, and the filler algorithm all work around
+/// them. Any checks the Plando leaves empty are filled as normal.
+pub fn fill_plando(
+    rng: &mut StdRng, seed_info: &mut SeedInfo, check_map: &mut CheckMap, placements: &BTreeMap<String, Item>,
+) -> crate::Result<()> {
+    let (mut progression_pool, mut junk_pool) = item_pools::get_item_pools(rng, seed_info);
+
+    place_cracks(seed_info, check_map);
+    place_weather_vanes(seed_info, check_map);
+
+    verify_all_locations_accessible(seed_info, check_map, &mut progression_pool)?;
+
+    place_plando_items(seed_info, check_map, placements, &mut progression_pool, &mut junk_pool)?;
+    preplace_items(rng, seed_info, check_map, &mut progression_pool)?;
+    handle_exclusions(rng, seed_info, check_map, &mut junk_pool)?;
+
+    assumed_fill(rng, seed_info, check_map, &mut progression_pool, &mut junk_pool)?;
+    balance_junk(rng, check_map, &mut junk_pool);
+    fill_junk(rng, check_map, &mut junk_pool)?;
+
+    build_layout(seed_info, check_map)?;
+
+    Ok(())
+}
+
+/// Places the hand-picked items of a Plando, removing each one from whichever pool it came from. Items that aren't in
+/// either pool (e.g. extra copies of an item) take the place of a piece of junk instead.
+fn place_plando_items(
+    SeedInfo { world_graph, .. }: &SeedInfo, check_map: &mut CheckMap, placements: &BTreeMap<String, Item>,
+    progression: &mut Pool, junk: &mut Pool,
+) -> crate::Result<()> {
+    info!("Placing Plando Items...");
+
+    for (check_name, &item) in placements {
+        validate_plando_check(world_graph, check_map, check_name)?;
+        check_map.insert(check_name.to_owned(), Some(item.into()));
+
+        if let Some(index) = progression.iter().position(|&x| x == item) {
+            progression.remove(index);
+        } else if let Some(index) = junk.iter().position(|&x| x == item) {
+            junk.remove(index);
+        } else {
+            junk.pop();
+        }
+    }
+
+    Ok(())
+}
+
+/// Makes sure a Plando's `check_name` exists and is an empty location that can hold an item.
+pub(crate) fn validate_plando_check(world_graph: &WorldGraph, check_map: &CheckMap, check_name: &str) -> crate::Result<()> {
+    let check = match world_graph.get_check(check_name) {
+        Some(check) => check,
+        None => {
+            return Err(crate::Error::game(format!("Plando places an item at unknown check: \"{}\"", check_name)));
+        },
+    };
+
+    // Cracks, Weather Vanes, Goals, etc. aren't item locations
+    if check.get_location_info().is_none() || !matches!(check_map.get(check_name), Some(None)) {
+        return Err(crate::Error::game(format!("Plando cannot place an item at check: \"{}\"", check_name)));
+    }
+
+    Ok(())
+}
+
+/// Adds or removes junk so that there's exactly one junk item for every empty check.
+///
+/// Plandos can freely displace statically placed items, so the pools won't always line up with the remaining checks.
+fn balance_junk(rng: &mut StdRng, check_map: &CheckMap, junk: &mut Pool) {
+    let empty_checks = check_map.values().filter(|item| item.is_none()).count();
+    while junk.len() < empty_checks {
+        item_pools::add_random_junk_item(rng, junk);
+    }
+    junk.truncate(empty_checks);
+}
+
 /// Crack randomization
-fn place_cracks(SeedInfo { crack_map, .. }: &SeedInfo, check_map: &mut CheckMap) {
+pub(crate) fn place_cracks(SeedInfo { crack_map, .. }: &SeedInfo, check_map: &mut CheckMap) {
     use crate::filler::cracks::Crack::*;
     let crack_lut = vec![
         ("[HC] Crack", HyruleCastle),
@@ -125,7 +208,7 @@ fn place_cracks(SeedInfo { crack_map, .. }: &SeedInfo, check_map: &mut CheckMap)
 }
 
 /// Weather Vane randomization
-fn place_weather_vanes(SeedInfo { vane_map, .. }: &SeedInfo, check_map: &mut CheckMap) {
+pub(crate) fn place_weather_vanes(SeedInfo { vane_map, .. }: &SeedInfo, check_map: &mut CheckMap) {
     use crate::filler::filler_item::Vane::*;
     let vane_lut = vec![
         ("Your House Weather Vane", YourHouseWV),
@@ -230,12 +313,16 @@ fn preplace_items(
         "[LC] Zelda",
     ];
 
-    if !settings.progressive_bow_of_light && settings.bow_of_light_in_castle {
-        check_map.insert(
-            String::from(bow_light_positions.remove(rng.gen_range(0..bow_light_positions.len()))),
-            Some(Item::BowOfLight.into()),
-        );
-        progression.retain(|x| *x != Item::BowOfLight);
+    if !settings.progressive_bow_of_light && settings.bow_of_light_in_castle && !is_placed(check_map, Item::BowOfLight)
+    {
+        bow_light_positions.retain(|check_name| matches!(check_map.get(*check_name), Some(None)));
+        if !bow_light_positions.is_empty() {
+            check_map.insert(
+                String::from(bow_light_positions.remove(rng.gen_range(0..bow_light_positions.len()))),
+                Some(Item::BowOfLight.into()),
+            );
+            progression.retain(|x| *x != Item::BowOfLight);
+        }
     }
 
     let mut shop_items = vec![];
@@ -308,7 +395,14 @@ fn preplace_items(
 fn insert_items_into_random_locations(
     items: Vec<Item>, mut locations: Vec<&str>, rng: &mut StdRng, check_map: &mut CheckMap, progression: &mut Vec<Item>,
 ) -> crate::Result<()> {
+    locations.retain(|check_name| matches!(check_map.get(*check_name), Some(None)));
     for item in items {
+        if is_placed(check_map, item) {
+            continue;
+        } else if locations.is_empty() {
+            return Err(crate::Error::game(format!("No empty locations left to place: {:?}", item)));
+        }
+
         check_map.insert(String::from(locations.remove(rng.gen_range(0..locations.len()))), Some(item.into()));
         progression.retain(|x| *x != item);
     }
@@ -317,7 +411,9 @@ fn insert_items_into_random_locations(
 }
 
 /// Handle Exclusions
-fn handle_exclusions(rng: &mut StdRng, seed_info: &mut SeedInfo, check_map: &mut CheckMap, junk: &mut Vec<Item>) {
+fn handle_exclusions(
+    rng: &mut StdRng, seed_info: &mut SeedInfo, check_map: &mut CheckMap, junk: &mut Vec<Item>,
+) -> crate::Result<()> {
     seed_info.full_exclusions = seed_info.settings.user_exclusions.clone();
 
     // Always exclude 100 Maiamai check unless the Maiamai Limit is explicitly set to 100
@@ -367,26 +463,46 @@ fn handle_exclusions(rng: &mut StdRng, seed_info: &mut SeedInfo, check_map: &mut
             let index = rng.gen_range(0..junk.len());
             check_map.insert(check_name, Some(junk.remove(index).into()));
         } else {
-            println!();
-            error!("Could not exclude \"{}\", no matching check found with that name.", exclusion);
-            fail!("Consult a spoiler log for a list of valid check names.");
+            return Err(crate::Error::settings(format!(
+                "Could not exclude \"{}\", no matching check found with that name.\n\
+                Consult a spoiler log for a list of valid check names.",
+                exclusion
+            )));
         }
     }
+
+    Ok(())
 }
 
 // Statically place an item in a given location, then remove it from the item pool provided
+// Checks and items that have already been placed (e.g. by a Plando) are left alone.
 fn place_static(check_map: &mut CheckMap, pool: &mut Pool, item: Item, check_name: &str) {
+    if !matches!(check_map.get(check_name), Some(None)) || is_placed(check_map, item) {
+        return;
+    }
+
     check_map.insert(check_name.to_owned(), Some(item.into()));
     pool.retain(|x| *x != item);
 }
 
+/// Whether the given `item` has already been placed somewhere in the `check_map`
+fn is_placed(check_map: &CheckMap, item: Item) -> bool {
+    let item = Some(Randomizable::from(item));
+    check_map.values().any(|placed| *placed == item)
+}
+
 /// Super dirty mapping I hate it
-pub fn build_layout(SeedInfo { layout, world_graph, .. }: &mut SeedInfo, check_map: &mut CheckMap) -> Result<(), Error> {
+pub fn build_layout(
+    SeedInfo { layout, placements, world_graph, .. }: &mut SeedInfo, check_map: &mut CheckMap,
+) -> Result<(), Error> {
     for location_node in world_graph.values() {
         for check in location_node.clone().get_checks().iter().flatten().collect::<Vec<&Check>>() {
             if let Some(loc_info) = check.get_location_info() {
                 if let Some(item) = check_map.get(check.get_name()).unwrap() {
                     layout.set(loc_info, *item);
+                    if let Randomizable::Item(item) = item {
+                        placements.insert(check.get_name().to_owned(), *item);
+                    }
                 } else {
                     panic!("No item placed at {}", loc_info.name);
                 }
@@ -397,6 +513,25 @@ pub fn build_layout(SeedInfo { layout, world_graph, .. }: &mut SeedInfo, check_m
     Ok(())
 }
 
+/// Rebuilds the [`CheckMap`] from an existing [`Layout`], i.e. the reverse of [`build_layout`].
+///
+/// [`Layout`]: crate::Layout
+pub(crate) fn read_layout(seed_info: &mut SeedInfo) -> CheckMap {
+    let mut check_map = prefill_check_map(&mut seed_info.world_graph);
+    place_cracks(seed_info, &mut check_map);
+    place_weather_vanes(seed_info, &mut check_map);
+
+    for check in seed_info.world_graph.values().flat_map(|node| node.get_checks().iter().flatten()) {
+        if let Some(loc_info) = check.get_location_info() {
+            if let Some(item) = seed_info.layout.get(loc_info.name, loc_info.subregion) {
+                check_map.insert(check.get_name().to_owned(), Some(item));
+            }
+        }
+    }
+
+    check_map
+}
+
 fn is_dungeon_prize(item: Item) -> bool {
     matches!(
         item,
@@ -503,7 +638,7 @@ fn fill_junk(rng: &mut StdRng, check_map: &mut CheckMap, junk_items: &mut Pool)
     Ok(())
 }
 
-fn filter_checks(item: Item, checks: &[Check], check_map: &mut CheckMap) -> Vec<Check> {
+fn filter_checks(item: Item, checks: &[Check], check_map: &mut CheckMap, settings: &Settings) -> Vec<Check> {
     // Filter out non-empty checks
     let mut filtered_checks =
         checks.iter().filter(|&x| check_map.get(x.get_name()).unwrap().is_none()).cloned().collect::<Vec<_>>();
@@ -512,10 +647,7 @@ fn filter_checks(item: Item, checks: &[Check], check_map: &mut CheckMap) -> Vec<
     if is_dungeon_prize(item) {
         filtered_checks = filter_dungeon_prize_checks(&filtered_checks);
     } else if is_dungeon_item(item) {
-        let is_keysanity = false; // No keysanity yet, hardcode to false
-        if !is_keysanity {
-            filtered_checks = filter_dungeon_checks(item, filtered_checks);
-        }
+        filtered_checks = filter_dungeon_checks(item, filtered_checks, settings);
     }
 
     filtered_checks
@@ -529,14 +661,112 @@ fn filter_dungeon_prize_checks(eligible_checks: &[Check]) -> Vec<Check> {
 
     eligible_checks
         .iter()
-        .filter_map(|check| if dungeon_prize_checks.contains(&check.get_name()) { Some(*check) } else { None })
+        .filter_map(|check| if dungeon_prize_checks.contains(&check.get_name()) { Some(check.clone()) } else { None })
+        .collect()
+}
+
+/// Filters the eligible checks down to those where the dungeon item may be placed under its [`Keysanity`] setting.
+fn filter_dungeon_checks(item: Item, eligible_checks: Vec<Check>, settings: &Settings) -> Vec<Check> {
+    let keysanity = if is_compass(item) {
+        settings.compasses
+    } else if is_big_key(item) {
+        settings.big_keys
+    } else {
+        settings.small_keys
+    };
+
+    let dungeon_checks = match keysanity {
+        Keysanity::OwnDungeon => get_dungeon_checks(item),
+        Keysanity::OwnWorld => {
+            let world = get_dungeon_world(item);
+            DUNGEON_ITEMS
+                .iter()
+                .filter(|&&dungeon_item| get_dungeon_world(dungeon_item) == world)
+                .flat_map(|&dungeon_item| get_dungeon_checks(dungeon_item))
+                .collect()
+        },
+        Keysanity::AnyDungeon => DUNGEON_ITEMS.iter().flat_map(|&dungeon_item| get_dungeon_checks(dungeon_item)).collect(),
+        Keysanity::Anywhere => return eligible_checks,
+    };
+
+    eligible_checks
+        .into_iter()
+        .filter(|check| {
+            dungeon_checks.contains(&check.get_name())
+                || (keysanity == Keysanity::OwnWorld
+                    && check.get_location_info().map(|info| info.world()) == Some(get_dungeon_world(item)))
+        })
         .collect()
 }
 
-fn filter_dungeon_checks(item: Item, eligible_checks: Vec<Check>) -> Vec<Check> {
+/// One dungeon item from each dungeon, used to look up every dungeon's checks.
+const DUNGEON_ITEMS: [Item; 13] = [
+    Item::HyruleSanctuaryKey,
+    Item::LoruleSanctuaryKey,
+    Item::EasternCompass,
+    Item::GalesCompass,
+    Item::HeraCompass,
+    Item::DarkCompass,
+    Item::SwampCompass,
+    Item::SkullCompass,
+    Item::ThievesCompass,
+    Item::IceCompass,
+    Item::DesertCompass,
+    Item::TurtleCompass,
+    Item::LoruleCastleCompass,
+];
+
+fn is_compass(item: Item) -> bool {
     use Item::*;
+    matches!(
+        item,
+        EasternCompass
+            | GalesCompass
+            | HeraCompass
+            | DarkCompass
+            | SwampCompass
+            | SkullCompass
+            | ThievesCompass
+            | IceCompass
+            | DesertCompass
+            | TurtleCompass
+            | LoruleCastleCompass
+    )
+}
 
-    let dungeon_checks = match item {
+fn is_big_key(item: Item) -> bool {
+    use Item::*;
+    matches!(
+        item,
+        EasternKeyBig
+            | GalesKeyBig
+            | HeraKeyBig
+            | DarkKeyBig
+            | SwampKeyBig
+            | SkullKeyBig
+            | ThievesKeyBig
+            | IceKeyBig
+            | DesertKeyBig
+            | TurtleKeyBig
+    )
+}
+
+/// The world whose overworld a dungeon item's dungeon is entered from.
+fn get_dungeon_world(item: Item) -> World {
+    use Item::*;
+    match item {
+        HyruleSanctuaryKey | EasternCompass | EasternKeyBig | EasternKeySmall01 | EasternKeySmall02 | GalesCompass
+        | GalesKeyBig | GalesKeySmall01 | GalesKeySmall02 | GalesKeySmall03 | GalesKeySmall04 | HeraCompass
+        | HeraKeyBig | HeraKeySmall01 | HeraKeySmall02 => World::Hyrule,
+        _ => World::Lorule,
+    }
+}
+
+/// The checks within the given dungeon item's own dungeon.
+fn get_dungeon_checks(item: Item) -> Vec<&'static str> {
+    use Item::*;
+
+    match item {
         HyruleSanctuaryKey => vec!["[HS] Entrance", "[HS] Ledge", "[HS] Lower Chest", "[HS] Upper Chest"],
         LoruleSanctuaryKey => vec!["[LS] Entrance Chest", "[LS] Ledge", "[LS] Lower Chest", "[LS] Upper Chest"],
         EasternCompass | EasternKeyBig | EasternKeySmall01 | EasternKeySmall02 => vec![
@@ -618,15 +848,24 @@ fn filter_dungeon_checks(item: Item, eligible_checks: Vec<Check>) -> Vec<Check>
             // "[LC] Zelda",
         ],
 
-        _ => {
-            fail!("Item {:?} is not a dungeon item", item);
-        },
-    };
+        _ => unreachable!("Item {:?} is not a dungeon item", item),
+    }
+}
 
-    eligible_checks
-        .iter()
-        .filter_map(|check| if dungeon_checks.contains(&check.get_name()) { Some(*check) } else { None })
-        .collect()
+/// Finds the dungeon items placed outside of their own dungeon, returning each check name and item.
+pub(crate) fn find_out_of_dungeon_items(seed_info: &SeedInfo) -> Vec<(&'static str, Item)> {
+    let mut out_of_dungeon_items = Vec::new();
+    for check in seed_info.world_graph.values().flat_map(|node| node.get_checks().iter().flatten()) {
+        if let Some(loc_info) = check.get_location_info() {
+            if let Some(Randomizable::Item(item)) = seed_info.layout.get(loc_info.name, loc_info.subregion) {
+                if is_dungeon_item(item) && !get_dungeon_checks(item).contains(&check.get_name()) {
+                    out_of_dungeon_items.push((check.get_name(), item));
+                }
+            }
+        }
+    }
+
+    out_of_dungeon_items
 }
 
 fn exist_empty_reachable_check(checks: &Vec<Check>, check_map: &mut CheckMap) -> bool {
@@ -649,7 +888,7 @@ pub fn prefill_check_map(world_graph: &mut WorldGraph) -> CheckMap {
     for location_node in world_graph.values_mut() {
         for check in location_node.clone().get_checks().iter().flatten().collect::<Vec<&Check>>() {
             if check_map.insert(check.get_name().to_owned(), check.get_quest()).is_some() {
-                fail!("Multiple checks have duplicate name: {}", check.get_name());
+                panic!("Multiple checks have duplicate name: {}", check.get_name());
             }
         }
     }
@@ -680,11 +919,11 @@ fn verify_all_locations_accessible(
     info!("Verifying all locations accessible...");
     let reachable_checks = assumed_search(seed_info, progression_pool, check_map); //find_reachable_checks(loc_map, &everything, &mut check_map); //
 
-    const STANDARD_CHECKS: usize = 264;
+    const STANDARD_CHECKS: usize = 263;
     const MAIAMAI: usize = 100;
     const DUNGEON_PRIZES: usize = 10;
     const STATIC_ITEMS: usize = 20;
-    const PROGRESSION_EVENTS: usize = 36; // "Progression Events" (non-item checks that are still progression)
+    const PROGRESSION_EVENTS: usize = 38; // "Progression Events" (non-item checks that are still progression)
     const WEATHER_VANES: usize = 22;
     const HINT_GHOSTS_OW: usize = 58; // Hint Ghosts (Overworld)
 
@@ -729,17 +968,13 @@ pub(crate) fn find_reachable_checks(SeedInfo { world_graph, .. }: &SeedInfo, pro
         let location = loc_queue.dequeue().unwrap();
 
         // Grab the location from the map, verify it is defined
-        let location_node = match world_graph.get(&location) {
-            Some(loc) => loc,
-            None => {
-                fail!("Location Undefined: {:?}", location);
-            },
-        };
+        let location_node =
+            world_graph.get(&location).unwrap_or_else(|| panic!("Location Undefined: {:?}", location));
 
         // Iterate over the location's checks
         for check in location_node.clone().get_checks().iter().flatten().collect::<Vec<&Check>>() {
             if check.can_access(progress) {
-                reachable_checks.push(*check);
+                reachable_checks.push(check.clone());
             }
         }
 
@@ -757,7 +992,7 @@ pub(crate) fn find_reachable_checks(SeedInfo { world_graph, .. }: &SeedInfo, pro
 }
 
 pub(crate) fn get_items_from_reachable_checks<'s>(
-    seed_info: &'s SeedInfo, reachable_checks: &Vec<Check>, check_map: &mut CheckMap,
+    seed_info: &'s SeedInfo, reachable_checks: &Vec<Check>, check_map: &CheckMap,
 ) -> Progress<'s> {
     let mut progress = Progress::new(seed_info);
 
@@ -813,7 +1048,7 @@ fn assumed_fill(
 
         reachable_checks = assumed_search(seed_info, items_owned, check_map);
 
-        let filtered_checks = filter_checks(item, &reachable_checks, check_map);
+        let filtered_checks = filter_checks(item, &reachable_checks, check_map, &seed_info.settings);
 
         if filtered_checks.is_empty() {
             // info!("item:            {:?}", item);
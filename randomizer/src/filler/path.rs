@@ -1,7 +1,7 @@
 use crate::filler::{location::Location, logic::Logic, progress::Progress};
 use serde::Serialize;
 
-#[derive(Copy, Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize)]
 pub struct Path {
     destination: Location,
     logic: Logic,
@@ -12,15 +12,15 @@ impl Path {
         Self { destination, logic }
     }
 
-    pub fn get_destination(self) -> Location {
+    pub fn get_destination(&self) -> Location {
         self.destination
     }
 
-    pub fn get_logic(&self) -> Logic {
-        self.logic
+    pub fn get_logic(&self) -> &Logic {
+        &self.logic
     }
 
-    pub fn can_access(self, progress: &Progress) -> bool {
+    pub fn can_access(&self, progress: &Progress) -> bool {
         self.logic.can_access(progress)
     }
 }
@@ -5,6 +5,7 @@ use crate::filler::item_pools;
 use crate::{DashSet, SeedInfo};
 use modinfo::settings::cracks::Cracks;
 use modinfo::settings::cracksanity::Cracksanity;
+use modinfo::settings::goal::GoalSetting;
 use modinfo::settings::keysy::Keysy;
 use modinfo::settings::nice_items::NiceItems;
 use modinfo::settings::ravios_shop::RaviosShop;
@@ -871,6 +872,37 @@ impl<'s> Progress<'s> {
         )
     }
 
+    pub fn has_all_dungeon_prizes(&self) -> bool {
+        use Item::*;
+        self.has_amount(
+            10,
+            [
+                PendantOfPower, PendantOfWisdom, PendantOfCourage, SageGulley, SageOren, SageSeres, SageOsfala,
+                SageImpa, SageIrene, SageRosso,
+            ],
+        )
+    }
+
+    pub fn has_triforce_pieces(&self) -> bool {
+        self.has_amount(self.seed_info.settings.triforce_pieces_required, item_pools::get_triforce_piece_pool())
+    }
+
+    pub fn goal_yuga_ganon(&self) -> bool {
+        self.seed_info.settings.goal == GoalSetting::YugaGanon
+    }
+
+    pub fn goal_triforce_hunt(&self) -> bool {
+        self.seed_info.settings.goal == GoalSetting::TriforceHunt
+    }
+
+    pub fn goal_all_dungeons(&self) -> bool {
+        self.seed_info.settings.goal == GoalSetting::AllDungeons
+    }
+
+    pub fn goal_pedestal(&self) -> bool {
+        self.seed_info.settings.goal == GoalSetting::Pedestal
+    }
+
     pub fn has_saved_thief_girl(&self) -> bool {
         self.has(Goal::Stalblind)
     }
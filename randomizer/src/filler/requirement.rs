@@ -0,0 +1,322 @@
+use crate::filler::filler_item::Vane;
+use crate::filler::progress::Progress;
+use std::iter::Peekable;
+use std::str::{CharIndices, FromStr};
+use strum::IntoEnumIterator;
+
+/// A requirement written in the logic file's expression language, e.g. `can_merge && (has_bombs || has_boots)`.
+///
+/// Requirements are built from the [`Progress`] helpers, called by name:
+/// - Most helpers take no arguments, e.g. `has_lamp` or `can_hit_far_switch`.
+/// - Key and Ore helpers take a count, e.g. `has_eastern_keys(2)` or `has_master_ore(4)`.
+/// - `hearts(9.0)`, `has_rupees(300)`, `has_maiamai(10)`, and `has_weather_vane(SanctuaryWV)` take their argument.
+///
+/// These combine with `!`, `&&`, `||`, and parentheses, with the same precedence as in Rust. `true` and `false` are
+/// also accepted.
+#[derive(Clone, Debug)]
+pub enum Requirement {
+    Const(bool),
+    Helper(fn(&Progress) -> bool),
+    Amount(fn(&Progress, u8) -> bool, u8),
+    Hearts(f32),
+    Rupees(u16),
+    WeatherVane(Vane),
+    Not(Box<Requirement>),
+    And(Vec<Requirement>),
+    Or(Vec<Requirement>),
+}
+
+impl Requirement {
+    pub fn eval(&self, progress: &Progress) -> bool {
+        match self {
+            Self::Const(value) => *value,
+            Self::Helper(helper) => helper(progress),
+            Self::Amount(helper, amount) => helper(progress, *amount),
+            Self::Hearts(amount) => progress.hearts(*amount),
+            Self::Rupees(amount) => progress.has_rupees(*amount),
+            Self::WeatherVane(vane) => progress.has_weather_vane(*vane),
+            Self::Not(requirement) => !requirement.eval(progress),
+            Self::And(requirements) => requirements.iter().all(|requirement| requirement.eval(progress)),
+            Self::Or(requirements) => requirements.iter().any(|requirement| requirement.eval(progress)),
+        }
+    }
+}
+
+impl FromStr for Requirement {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser { source: s, chars: s.char_indices().peekable() };
+        let requirement = parser.parse_or()?;
+        match parser.next_token()? {
+            None => Ok(requirement),
+            Some(token) => Err(format!("Unexpected \"{}\" in \"{}\"", token, s)),
+        }
+    }
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    chars: Peekable<CharIndices<'a>>,
+}
+
+impl<'a> Parser<'a> {
+    fn parse_or(&mut self) -> Result<Requirement, String> {
+        let mut requirements = vec![self.parse_and()?];
+        while self.eat("||")? {
+            requirements.push(self.parse_and()?);
+        }
+        Ok(if requirements.len() == 1 { requirements.remove(0) } else { Requirement::Or(requirements) })
+    }
+
+    fn parse_and(&mut self) -> Result<Requirement, String> {
+        let mut requirements = vec![self.parse_unary()?];
+        while self.eat("&&")? {
+            requirements.push(self.parse_unary()?);
+        }
+        Ok(if requirements.len() == 1 { requirements.remove(0) } else { Requirement::And(requirements) })
+    }
+
+    fn parse_unary(&mut self) -> Result<Requirement, String> {
+        match self.next_token()? {
+            Some("!") => Ok(Requirement::Not(Box::new(self.parse_unary()?))),
+            Some("(") => {
+                let requirement = self.parse_or()?;
+                self.expect(")")?;
+                Ok(requirement)
+            },
+            Some("true") => Ok(Requirement::Const(true)),
+            Some("false") => Ok(Requirement::Const(false)),
+            Some(name) if name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') => {
+                if self.eat("(")? {
+                    let argument = self.next_token()?.ok_or_else(|| self.error("Missing argument"))?;
+                    self.expect(")")?;
+                    self.helper_with_argument(name, argument)
+                } else {
+                    helper(name)
+                        .map(Requirement::Helper)
+                        .ok_or_else(|| self.error(&format!("Unknown helper \"{}\"", name)))
+                }
+            },
+            Some(token) => Err(self.error(&format!("Unexpected \"{}\"", token))),
+            None => Err(self.error("Unexpected end")),
+        }
+    }
+
+    fn helper_with_argument(&self, name: &str, argument: &str) -> Result<Requirement, String> {
+        let invalid = || self.error(&format!("Invalid argument \"{}\" for \"{}\"", argument, name));
+        match name {
+            "hearts" => argument.parse().map(Requirement::Hearts).map_err(|_| invalid()),
+            "has_rupees" => argument.parse().map(Requirement::Rupees).map_err(|_| invalid()),
+            "has_weather_vane" => Vane::iter()
+                .find(|vane| format!("{:?}", vane) == argument)
+                .map(Requirement::WeatherVane)
+                .ok_or_else(invalid),
+            _ => {
+                let helper = amount_helper(name).ok_or_else(|| self.error(&format!("Unknown helper \"{}\"", name)))?;
+                argument.parse().map(|amount| Requirement::Amount(helper, amount)).map_err(|_| invalid())
+            },
+        }
+    }
+
+    fn eat(&mut self, expected: &str) -> Result<bool, String> {
+        self.skip_whitespace();
+        if self.source[self.position()..].starts_with(expected) {
+            self.next_token()?;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    fn expect(&mut self, expected: &str) -> Result<(), String> {
+        match self.next_token()? {
+            Some(token) if token == expected => Ok(()),
+            _ => Err(self.error(&format!("Expected \"{}\"", expected))),
+        }
+    }
+
+    /// Reads the next token: an operator, a parenthesis, or a word such as a helper name or number.
+    fn next_token(&mut self) -> Result<Option<&'a str>, String> {
+        self.skip_whitespace();
+        let Some(&(start, c)) = self.chars.peek() else {
+            return Ok(None);
+        };
+        self.chars.next();
+        let end = match c {
+            '(' | ')' | '!' => start + 1,
+            '&' | '|' => match self.chars.next() {
+                Some((_, next)) if next == c => start + 2,
+                _ => return Err(self.error(&format!("Expected \"{}{}\"", c, c))),
+            },
+            c if c.is_ascii_alphanumeric() || c == '_' || c == '.' => {
+                while self.chars.next_if(|&(_, c)| c.is_ascii_alphanumeric() || c == '_' || c == '.').is_some() {}
+                self.position()
+            },
+            c => return Err(self.error(&format!("Unexpected \"{}\"", c))),
+        };
+        Ok(Some(&self.source[start..end]))
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.chars.next_if(|&(_, c)| c.is_whitespace()).is_some() {}
+    }
+
+    fn position(&mut self) -> usize {
+        self.chars.peek().map_or(self.source.len(), |&(i, _)| i)
+    }
+
+    fn error(&self, message: &str) -> String {
+        format!("{} in \"{}\"", message, self.source)
+    }
+}
+
+/// Progress helpers that take no arguments, by name.
+macro_rules! helpers {
+    ($($name:ident,)+) => {
+        fn helper(name: &str) -> Option<fn(&Progress) -> bool> {
+            match name {
+                $(stringify!($name) => Some(|p| p.$name()),)+
+                _ => None,
+            }
+        }
+    };
+}
+
+/// Progress helpers that take an amount, by name.
+macro_rules! amount_helpers {
+    ($($name:ident,)+) => {
+        fn amount_helper(name: &str) -> Option<fn(&Progress, u8) -> bool> {
+            match name {
+                $(stringify!($name) => Some(|p, amount| p.$name(amount)),)+
+                _ => None,
+            }
+        }
+    };
+}
+
+#[rustfmt::skip]
+helpers!(
+    // Items
+    has_lamp, has_super_lamp, has_net, has_super_net, has_bow, has_nice_bow, has_boomerang, has_nice_boomerang,
+    has_hookshot, has_nice_hookshot, has_hammer, has_nice_hammer, has_bombs, has_nice_bombs, has_fire_rod,
+    has_nice_fire_rod, has_ice_rod, has_nice_ice_rod, has_tornado_rod, has_nice_tornado_rod, has_sand_rod,
+    has_nice_sand_rod, has_scoot_fruit, has_foul_fruit, has_bell, has_stamina_scroll, has_bottle, has_boots,
+    has_power_glove, has_titans_mitt, has_flippers, has_mail, has_90_maiamai, has_100_maiamai, has_smooth_gem,
+    has_letter_in_a_bottle, has_premium_milk, has_gold_bee, has_sword, has_master_sword, has_bow_of_light,
+    has_pendant_of_courage, has_required_pendants, has_sage_gulley, has_sage_oren, has_sage_seres, has_sage_osfala,
+    has_sage_impa, has_sage_irene, has_sage_rosso, has_lc_requirement, has_yuganon_requirement,
+    has_all_dungeon_prizes, has_triforce_pieces,
+
+    // Abilities
+    can_use_shield, has_fire_source, can_destroy_curtain, can_extinguish_torches, can_escape, can_escape_dungeon,
+    can_merge, can_great_spin, can_destroy_skull, can_cut_grass, can_attack, can_attack_bowproof, can_attack_fireproof,
+    can_attack_iceproof, has_lamp_or_net_as_weapon, has_lamp_as_weapon, has_net_as_weapon, can_hit_switch,
+    can_hit_switch_bootless, can_hit_far_switch, can_hit_shielded_switch, can_hit_hog_1f_switch, can_rescue_turtles,
+    can_get_potion,
+
+    // Settings
+    are_hyrule_vanes_active, are_lorule_vanes_active, cracksanity, are_cracks_open, swordless_mode,
+    progression_enemies, break_floor_tiles, not_nice_mode, lampless, is_trials_door_open_from_both_sides,
+    goal_yuga_ganon, goal_triforce_hunt, goal_all_dungeons, goal_pedestal,
+
+    // Bosses
+    can_defeat_margomill, can_defeat_moldorm, can_defeat_yuga2, can_defeat_gemesaur, can_defeat_arrghus,
+    can_defeat_knucklemaster, can_defeat_grinexx, can_defeat_dharkstare,
+
+    // Keys
+    has_sanctuary_key, has_lorule_sanctuary_key, has_eastern_compass, has_eastern_big_key, has_gales_big_key,
+    has_hera_big_key, has_dark_big_key, has_swamp_big_key, has_skull_big_key, has_thieves_key, has_thieves_big_key,
+    has_ice_compass, has_ice_big_key, has_desert_big_key, has_turtle_big_key,
+
+    // Events
+    has_completed_trials, has_opened_stylish_womans_house, has_skull_eye_right, has_skull_eyes, thieves_b1_door_open,
+    thieves_b2_door_open, thieves_b3_water_drained, thieves_b1b2_doors_open, thieves_escape_equipment,
+    adv_thieves_statue_clip, hell_thieves_statue_clip, has_seen_ravio_signs, is_ravio_shop_open, has_bomb_flower,
+    has_shady_guy_trigger, has_saved_thief_girl, has_woman_roof_maiamai, has_opened_sanctuary_doors,
+    can_access_milk_bar, can_access_hyrule_blacksmith, can_access_lorule_castle_field,
+);
+
+#[rustfmt::skip]
+amount_helpers!(
+    has_master_ore, has_maiamai, has_eastern_keys, has_gales_keys, has_hera_keys, has_dark_keys, has_swamp_keys,
+    has_skull_keys, has_ice_keys, has_desert_keys, has_turtle_keys, has_lorule_keys,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::Requirement::{self, *};
+    use crate::filler::filler_item::Vane;
+    use std::str::FromStr;
+
+    fn parse(s: &str) -> Requirement {
+        Requirement::from_str(s).unwrap()
+    }
+
+    fn error(s: &str) -> String {
+        Requirement::from_str(s).unwrap_err()
+    }
+
+    /// Writes out how a requirement was grouped, with every `&&` and `||` in parentheses.
+    fn grouped(requirement: &Requirement) -> String {
+        let join = |requirements: &[Requirement], operator| {
+            let requirements = requirements.iter().map(grouped).collect::<Vec<_>>();
+            format!("({})", requirements.join(operator))
+        };
+        match requirement {
+            Const(value) => value.to_string(),
+            Not(requirement) => format!("!{}", grouped(requirement)),
+            And(requirements) => join(requirements, " && "),
+            Or(requirements) => join(requirements, " || "),
+            _ => "helper".to_owned(),
+        }
+    }
+
+    #[test]
+    fn it_binds_and_tighter_than_or() {
+        assert_eq!(grouped(&parse("true || false && true")), "(true || (false && true))");
+        assert_eq!(grouped(&parse("true && false || true")), "((true && false) || true)");
+        assert_eq!(grouped(&parse("true && true && false")), "(true && true && false)");
+    }
+
+    #[test]
+    fn it_binds_not_to_the_next_term() {
+        assert_eq!(grouped(&parse("!true && !!false")), "(!true && !!false)");
+        assert_eq!(grouped(&parse("!true || can_merge")), "(!true || helper)");
+    }
+
+    #[test]
+    fn it_groups_with_parentheses() {
+        assert_eq!(grouped(&parse("(true || false) && ( true )")), "((true || false) && true)");
+        assert_eq!(grouped(&parse("!(true || false)")), "!(true || false)");
+        assert_eq!(grouped(&parse("((can_merge))")), "helper");
+    }
+
+    #[test]
+    fn it_parses_helpers_and_their_arguments() {
+        assert!(matches!(parse("can_merge"), Helper(_)));
+        assert!(matches!(parse("has_eastern_keys(2)"), Amount(_, 2)));
+        assert!(matches!(parse("hearts(9.0)"), Hearts(hearts) if hearts == 9.0));
+        assert!(matches!(parse("has_rupees( 300 )"), Rupees(300)));
+        assert!(matches!(parse("has_weather_vane(SanctuaryWV)"), WeatherVane(Vane::SanctuaryWV)));
+    }
+
+    #[test]
+    fn it_rejects_unknown_helpers_and_arguments() {
+        assert_eq!(error("can_fly"), "Unknown helper \"can_fly\" in \"can_fly\"");
+        assert_eq!(error("can_fly(2)"), "Unknown helper \"can_fly\" in \"can_fly(2)\"");
+        assert!(error("has_weather_vane(NowhereWV)").starts_with("Invalid argument \"NowhereWV\""));
+        assert!(error("has_eastern_keys(many)").starts_with("Invalid argument \"many\""));
+        assert!(error("has_eastern_keys()").starts_with("Expected \")\""));
+    }
+
+    #[test]
+    fn it_rejects_malformed_requirements() {
+        assert!(error("").starts_with("Unexpected end"));
+        assert!(error("true &").starts_with("Expected \"&&\""));
+        assert!(error("(true").starts_with("Expected \")\""));
+        assert!(error("true)").starts_with("Unexpected \")\""));
+        assert!(error("true false").starts_with("Unexpected \"false\""));
+        assert!(error("can_merge + has_bombs").starts_with("Unexpected \"+\""));
+    }
+}
@@ -1,5 +1,5 @@
 use game::Course::{self, EnemyAttackL, EnemyAttackM, EnemyAttackS};
-use serde::{Serialize, Serializer};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
 #[derive(PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Debug)]
 pub struct TowerStage {
@@ -28,3 +28,24 @@ impl Serialize for TowerStage {
         serializer.serialize_str(&format!("{} {: >2}F", course_name, self.stage))
     }
 }
+
+impl<'de> Deserialize<'de> for TowerStage {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let text = String::deserialize(deserializer)?;
+        let invalid = || de::Error::custom(format!("Invalid Treacherous Tower floor: \"{}\"", text));
+
+        let (course_name, floor) = text.rsplit_once(' ').ok_or_else(invalid)?;
+        let course = match course_name.trim_end() {
+            "Beginner" => EnemyAttackS,
+            "Intermediate" => EnemyAttackM,
+            "Advanced" => EnemyAttackL,
+            _ => return Err(invalid()),
+        };
+        let stage = floor.strip_suffix('F').and_then(|floor| floor.parse().ok()).ok_or_else(invalid)?;
+
+        Ok(Self { course, stage })
+    }
+}
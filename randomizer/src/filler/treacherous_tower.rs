@@ -1,7 +1,6 @@
 use crate::filler::tower_stage::TowerStage;
-use crate::Result;
+use crate::{Error, Result};
 use game::Course::{EnemyAttackL, EnemyAttackM, EnemyAttackS};
-use macros::fail;
 use modinfo::Settings;
 use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
@@ -11,9 +10,9 @@ pub fn choose_floors(settings: &Settings, rng: &mut StdRng) -> Result<Vec<TowerS
     let mut chosen_floors = Vec::with_capacity(settings.treacherous_tower_floors);
 
     if settings.treacherous_tower_floors < 2 {
-        fail!("Treacherous Tower must have at least 2 floors.");
+        return Err(Error::settings("Treacherous Tower must have at least 2 floors."));
     } else if settings.treacherous_tower_floors > 66 {
-        fail!("Treacherous Tower may have at most 66 floors.");
+        return Err(Error::settings("Treacherous Tower may have at most 66 floors."));
     }
 
     // Choose (n - 2) random filler floors.
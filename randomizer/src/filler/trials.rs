@@ -2,9 +2,9 @@ use modinfo::settings::trials_door::TrialsDoor;
 use modinfo::Settings;
 use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Default, Debug, Clone, Serialize)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub struct TrialsConfig {
     pub bomb_trial: bool,
     pub tile_trial: bool,
@@ -43,3 +43,13 @@ where
 
     Ok(map)
 }
+
+/// Chain together entries from a Vec into a single loop, mapping each entry onto the one after it (and the last entry
+/// back onto the first), and return the resultant Map.
+pub(crate) fn chain<T>(vec: Vec<T>) -> BTreeMap<T, T>
+where
+    T: Clone + Ord,
+{
+    let next = vec.iter().cycle().skip(1).cloned();
+    vec.iter().cloned().zip(next).collect()
+}
@@ -6,20 +6,20 @@ use crate::filler::util::shuffle;
 use crate::filler::{find_reachable_checks, get_items_from_reachable_checks};
 use crate::hints::formatting::name;
 use crate::patch::util::is_sage;
-use crate::{CheckMap, DashSet, SeedInfo};
+use crate::regions::Subregion;
+use crate::{CheckMap, CrackMap, DashSet, Error, SeedInfo};
 use game::ghosts::HintGhost;
 use log::{debug, info};
-use macros::fail;
 use modinfo::settings::cracksanity::Cracksanity;
-use modinfo::settings::NiceItems;
+use modinfo::settings::{HintDistribution, NiceItems};
 use rand::seq::IteratorRandom;
 use rand::seq::SliceRandom;
 use rand::{rngs::StdRng, Rng};
-use rom::Error;
 use serde::{
     ser::{SerializeSeq, SerializeStruct},
     Serialize, Serializer,
 };
+use std::collections::BTreeMap;
 use strum::IntoEnumIterator;
 use Item::*;
 
@@ -32,6 +32,12 @@ pub struct Hints {
     pub always_hints: Vec<LocationHint>,
     pub maiamai_hints: Vec<LocationHint>,
     pub sometimes_hints: Vec<LocationHint>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub crack_hints: Vec<CrackHint>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub hero_hints: Vec<HeroHint>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub barren_hints: Vec<BarrenHint>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bow_of_light_hint: Option<BowOfLightHint>,
 }
@@ -64,14 +70,7 @@ impl LocationHint {
     pub(crate) fn choose_ghost(
         &mut self, rng: &mut StdRng, taken_ghosts: &mut Vec<HintGhost>,
     ) -> Result<(), &'static str> {
-        match self.logical_ghosts.iter().filter(|ghost| !taken_ghosts.contains(ghost)).choose_stable(rng) {
-            None => Err("No Ghosts available to place this hint"),
-            Some(ghost) => {
-                self.ghosts.push(*ghost);
-                taken_ghosts.push(*ghost);
-                Ok(())
-            },
-        }
+        choose_logical_ghost(&self.logical_ghosts, &mut self.ghosts, rng, taken_ghosts)
     }
 }
 
@@ -108,8 +107,9 @@ impl Serialize for LocationHint {
     where
         S: Serializer,
     {
-        let mut ser = serializer.serialize_struct("LocationHint", 2)?;
+        let mut ser = serializer.serialize_struct("LocationHint", 3)?;
         ser.serialize_field("hint", &self.get_hint_spoiler())?;
+        ser.serialize_field("location", &self.check.get_name())?;
         ser.serialize_field("ghosts", &SerializeGhosts(&self.ghosts))?;
         ser.end()
     }
@@ -158,8 +158,9 @@ impl Serialize for PathHint {
     where
         S: Serializer,
     {
-        let mut ser = serializer.serialize_struct("PathHint", 4)?;
+        let mut ser = serializer.serialize_struct("PathHint", 5)?;
         ser.serialize_field("hint", &self.get_hint_spoiler())?;
+        ser.serialize_field("goal", &self.goal)?;
         ser.serialize_field("path_item", &self.path_item.as_str())?;
         ser.serialize_field("path_item_location", &self.check.get_name())?;
         ser.serialize_field("ghosts", &SerializeGhosts(&self.ghosts))?;
@@ -173,11 +174,13 @@ pub struct CrackHint {
     /// The Crack whose destination will be hinted
     pub crack: Crack,
 
-    /// The
+    /// The Crack that `crack` leads to
     pub destination: Crack,
 
-    #[allow(unused)]
-    /// List of Hint Ghosts that are guaranteed to be logically reachable before the hinted item.
+    /// Where the `destination` Crack leads in turn, if not back to `crack` (i.e. Decoupled Cracksanity)
+    pub return_destination: Option<Crack>,
+
+    /// List of Hint Ghosts that are guaranteed to be logically reachable before the hinted Crack.
     pub logical_ghosts: Vec<HintGhost>,
 
     /// Hint Ghosts that will give out this hint. <br />
@@ -185,17 +188,51 @@ pub struct CrackHint {
     pub ghosts: Vec<HintGhost>,
 }
 
+impl CrackHint {
+    pub(crate) fn new(crack: Crack, crack_map: &CrackMap) -> Self {
+        let destination = *crack_map.get(&crack).unwrap_or_else(|| panic!("crack_map entry for {}", crack));
+        let return_destination =
+            *crack_map.get(&destination).unwrap_or_else(|| panic!("crack_map entry for {}", destination));
+        Self {
+            crack,
+            destination,
+            return_destination: (return_destination != crack).then_some(return_destination),
+            logical_ghosts: vec![],
+            ghosts: vec![],
+        }
+    }
+
+    pub(crate) fn choose_ghost(
+        &mut self, rng: &mut StdRng, taken_ghosts: &mut Vec<HintGhost>,
+    ) -> Result<(), &'static str> {
+        choose_logical_ghost(&self.logical_ghosts, &mut self.ghosts, rng, taken_ghosts)
+    }
+}
+
 impl Hint for CrackHint {
     fn get_ghosts(&self) -> &Vec<HintGhost> {
         &self.ghosts
     }
 
     fn get_hint(&self) -> String {
-        format!("The {} leads to\n{}.", name(self.crack.as_str()), name(self.destination.as_str()))
+        match self.return_destination {
+            None => format!("The {} leads to\n{}.", name(self.crack.as_str()), name(self.destination.as_str())),
+            Some(return_destination) => format!(
+                "The {} leads to\n{},\nwhich leads on to\n{}.",
+                name(self.crack.as_str()),
+                name(self.destination.as_str()),
+                name(return_destination.as_str())
+            ),
+        }
     }
 
     fn get_hint_spoiler(&self) -> String {
-        format!("The {} leads to\n{}.", self.crack, self.destination)
+        match self.return_destination {
+            None => format!("The {} leads to {}.", self.crack, self.destination),
+            Some(return_destination) => {
+                format!("The {} leads to {}, which leads on to {}.", self.crack, self.destination, return_destination)
+            },
+        }
     }
 }
 
@@ -213,6 +250,108 @@ impl Serialize for CrackHint {
     }
 }
 
+/// A [`Hint`] telling how many items needed to beat the game are in a region, i.e. that it's on the Way of the Hero
+#[derive(Debug, Clone)]
+pub struct HeroHint {
+    /// A [`Subregion`] of the hinted region, all of which share its name and color
+    pub subregion: &'static Subregion,
+
+    /// How many items in the region are needed to beat the game
+    pub count: usize,
+
+    /// List of Hint Ghosts that are guaranteed to be logically reachable before all of the hinted items.
+    pub logical_ghosts: Vec<HintGhost>,
+
+    /// Hint Ghosts that will give out this hint. <br />
+    /// Only one of these is guaranteed to be from `logical_ghosts`, the other(s) are placed completely at random.
+    pub ghosts: Vec<HintGhost>,
+}
+
+impl HeroHint {
+    pub(crate) fn choose_ghost(
+        &mut self, rng: &mut StdRng, taken_ghosts: &mut Vec<HintGhost>,
+    ) -> Result<(), &'static str> {
+        choose_logical_ghost(&self.logical_ghosts, &mut self.ghosts, rng, taken_ghosts)
+    }
+}
+
+impl Hint for HeroHint {
+    fn get_ghosts(&self) -> &Vec<HintGhost> {
+        &self.ghosts
+    }
+
+    fn get_hint(&self) -> String {
+        format!(
+            "{}\nholds {} item{} on the\nway to {}.",
+            self.subregion.name_colorized(),
+            self.count,
+            if self.count == 1 { "" } else { "s" },
+            name("Yuga Ganon")
+        )
+    }
+
+    fn get_hint_spoiler(&self) -> String {
+        format!(
+            "{} holds {} item{} on the way to Yuga Ganon.",
+            self.subregion.name(),
+            self.count,
+            if self.count == 1 { "" } else { "s" }
+        )
+    }
+}
+
+impl Serialize for HeroHint {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut ser = serializer.serialize_struct("HeroHint", 4)?;
+        ser.serialize_field("hint", &self.get_hint_spoiler())?;
+        ser.serialize_field("region", self.subregion.name())?;
+        ser.serialize_field("count", &self.count)?;
+        ser.serialize_field("ghosts", &SerializeGhosts(&self.ghosts))?;
+        ser.end()
+    }
+}
+
+/// A [`Hint`] naming a region that holds nothing required or major, i.e. a foolish place to search
+#[derive(Debug, Clone)]
+pub struct BarrenHint {
+    /// A [`Subregion`] of the hinted region, all of which share its name and color
+    pub subregion: &'static Subregion,
+
+    /// Hint Ghosts that will give out this hint. <br />
+    /// There's nothing to reach in a barren region, so these are all placed completely at random.
+    pub ghosts: Vec<HintGhost>,
+}
+
+impl Hint for BarrenHint {
+    fn get_ghosts(&self) -> &Vec<HintGhost> {
+        &self.ghosts
+    }
+
+    fn get_hint(&self) -> String {
+        format!("{}\nis a foolish choice.", self.subregion.name_colorized())
+    }
+
+    fn get_hint_spoiler(&self) -> String {
+        format!("{} is a foolish choice.", self.subregion.name())
+    }
+}
+
+impl Serialize for BarrenHint {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut ser = serializer.serialize_struct("BarrenHint", 3)?;
+        ser.serialize_field("hint", &self.get_hint_spoiler())?;
+        ser.serialize_field("region", self.subregion.name())?;
+        ser.serialize_field("ghosts", &SerializeGhosts(&self.ghosts))?;
+        ser.end()
+    }
+}
+
 /// A [`Hint`] specifically for the Bow of Light.
 #[derive(Debug, Clone)]
 pub struct BowOfLightHint {
@@ -250,64 +389,223 @@ impl Serialize for BowOfLightHint {
 /// Generates Always, Path, and Sometimes Hints based on settings
 pub fn generate_hints(rng: &mut StdRng, seed_info: &mut SeedInfo, check_map: &mut CheckMap) -> Result<(), Error> {
     info!("Generating Hints...");
-    const NUM_TOTAL_HINTS: usize = 29;
+    let distribution = &seed_info.settings.hint_distribution;
 
     //
     let mut taken_checks = seed_info.full_exclusions.iter().cloned().collect();
     let mut taken_ghosts = Vec::new();
 
-    // let mut crack_hints =
-    //     generate_crack_hints(settings, crack_map, world_graph, check_map, &mut taken_checks, &mut taken_ghosts, rng);
+    let always_hints = generate_always_hints(rng, seed_info, check_map, &mut taken_checks, &mut taken_ghosts)?;
+    let maiamai_hints = generate_maiamai_hints(rng, seed_info, check_map, &mut taken_checks, &mut taken_ghosts)?;
+    let path_hints = generate_path_hints(rng, seed_info, check_map, &mut taken_checks, &mut taken_ghosts);
+    let hero_hints = generate_hero_hints(rng, seed_info, check_map, &mut taken_ghosts);
+    let crack_hints = generate_crack_hints(rng, seed_info, check_map, &mut taken_ghosts);
+    let barren_hints = generate_barren_hints(rng, seed_info, check_map, &taken_checks, &mut taken_ghosts);
+
+    let num_other_hints = always_hints.len()
+        + maiamai_hints.len()
+        + path_hints.len()
+        + hero_hints.len()
+        + crack_hints.len()
+        + barren_hints.len();
+    let num_sometimes_hints = distribution.total.checked_sub(num_other_hints).ok_or_else(|| {
+        Error::settings(format!(
+            "Hint Distribution \"{}\" has a total of {} hints, but {} Always, Maiamai, Path, Way of the Hero, Crack, \
+            and Barren Hints were made.",
+            distribution.name, distribution.total, num_other_hints
+        ))
+    })?;
+    let sometimes_hints =
+        generate_sometimes_hints(rng, seed_info, check_map, num_sometimes_hints, &taken_checks, &mut taken_ghosts)?;
 
-    let mut always_hints = generate_always_hints(rng, seed_info, check_map, &mut taken_checks, &mut taken_ghosts);
-    let mut maiamai_hints = generate_maiamai_hints(rng, seed_info, check_map, &mut taken_checks, &mut taken_ghosts);
-    let mut path_hints = generate_path_hints(rng, seed_info, check_map, &mut taken_checks, &mut taken_ghosts);
+    let bow_of_light_hint = generate_bow_of_light_hint(seed_info, check_map);
 
-    let num_sometimes_hints = NUM_TOTAL_HINTS - always_hints.len() - maiamai_hints.len() - path_hints.len();
-    let mut sometimes_hints =
-        generate_sometimes_hints(rng, seed_info, check_map, num_sometimes_hints, &taken_checks, &mut taken_ghosts);
+    let mut hints = Hints {
+        path_hints,
+        always_hints,
+        maiamai_hints,
+        sometimes_hints,
+        hero_hints,
+        crack_hints,
+        barren_hints,
+        bow_of_light_hint,
+    };
+    duplicate_hints(&mut hints, &taken_ghosts, distribution, rng);
+    seed_info.hints = hints;
 
-    duplicate_hints(
-        &mut taken_ghosts, &mut always_hints, &mut maiamai_hints, &mut path_hints, &mut sometimes_hints,
-        NUM_TOTAL_HINTS, rng,
-    );
+    Ok(())
+}
 
-    let bow_of_light_hint = generate_bow_of_light_hint(seed_info, check_map);
+/**
+ * Way of the Hero Hints
+ * Generates hints for random regions holding items needed to beat the game, telling how many such items are there.
+ * An item is needed if Yuga Ganon can't be beaten without it, tested the same way as the items behind Path Hints.
+ */
+fn generate_hero_hints(
+    rng: &mut StdRng, seed_info: &SeedInfo, check_map: &mut CheckMap, taken_ghosts: &mut Vec<HintGhost>,
+) -> Vec<HeroHint> {
+    let num_hero_hints = seed_info.settings.hint_distribution.hero;
+    if num_hero_hints == 0 {
+        return vec![];
+    }
 
-    seed_info.hints = Hints { path_hints, always_hints, maiamai_hints, sometimes_hints, bow_of_light_hint };
+    let nothing_but_hearts_and_rupees = Progress::nothing_but_hearts_and_rupees(seed_info);
+    let mut potential_checks = find_checks_before_goal(seed_info, check_map, Goal::Triforce)
+        .into_iter()
+        .filter(|check| {
+            check.get_location_info().is_some()
+                && check_map
+                    .get(check.get_name())
+                    .copied()
+                    .flatten()
+                    .is_some_and(Randomizable::include_in_sphere_search)
+        })
+        .collect::<Vec<_>>();
+    potential_checks.sort_by(|a, b| a.get_name().cmp(b.get_name()));
+
+    // Subregions sharing a name make up one region, as far as the player can tell
+    let mut regions = BTreeMap::<&'static str, HeroHint>::new();
+    for check in potential_checks {
+        let Some(ghosts) =
+            find_ghosts_if_required(seed_info, check_map, &check, Goal::Triforce, &nothing_but_hearts_and_rupees)
+        else {
+            continue;
+        };
+        let location_info = check.get_location_info().unwrap();
+        match regions.get_mut(location_info.region()) {
+            Some(hero_hint) => {
+                hero_hint.count += 1;
+                hero_hint.logical_ghosts.retain(|ghost| ghosts.contains(ghost));
+            },
+            None => {
+                regions.insert(
+                    location_info.region(),
+                    HeroHint { subregion: location_info.subregion(), count: 1, logical_ghosts: ghosts, ghosts: vec![] },
+                );
+            },
+        }
+    }
 
-    Ok(())
+    let mut hero_hints = Vec::with_capacity(num_hero_hints);
+    for mut hero_hint in shuffle(rng, regions.into_values().collect()) {
+        if hero_hints.len() >= num_hero_hints {
+            break;
+        }
+        if hero_hint.choose_ghost(rng, taken_ghosts).is_ok() {
+            hero_hints.push(hero_hint);
+        }
+    }
+
+    hero_hints
 }
 
-/// Crack Hints
-#[allow(unused)]
+/**
+ * Crack Hints
+ * Generates hints revealing where the Hyrule and Lorule Castle Cracks lead, followed by random other Cracks, when
+ * Cracksanity is on.
+ */
 fn generate_crack_hints(
-    rng: &mut StdRng, seed_info: &mut SeedInfo, check_map: &mut CheckMap, taken_checks: &mut [&str],
-    taken_ghosts: &mut [HintGhost],
+    rng: &mut StdRng, seed_info: &SeedInfo, check_map: &mut CheckMap, taken_ghosts: &mut Vec<HintGhost>,
 ) -> Vec<CrackHint> {
     if seed_info.settings.cracksanity == Cracksanity::Off {
-        return Vec::with_capacity(0);
+        return vec![];
     }
 
-    let cracks_to_hint: Vec<Crack> =
-        vec![Crack::HyruleCastle, Crack::LoruleCastle, Crack::DesertPalace, Crack::Zaganaga, Crack::RossosHouse];
+    let num_crack_hints = seed_info.settings.hint_distribution.crack;
+    let castle_cracks = [Crack::HyruleCastle, Crack::LoruleCastle];
+
+    // The Desert Palace and Zaganaga Cracks are always vanilla, so there's no point hinting them
+    let mut other_cracks = Crack::iter()
+        .filter(|crack| !castle_cracks.contains(crack) && ![Crack::DesertPalace, Crack::Zaganaga].contains(crack))
+        .collect::<Vec<_>>();
+    other_cracks.shuffle(rng);
+    let cracks_to_hint = castle_cracks.into_iter().chain(other_cracks);
 
+    let mut revealed_cracks = vec![];
+    let mut crack_hints = Vec::with_capacity(num_crack_hints);
     for crack in cracks_to_hint {
-        let crack_hint = CrackHint {
-            crack,
-            destination: *seed_info.crack_map.get(&crack).unwrap_or_else(|| panic!("crack_map entry for {}", crack)),
-            logical_ghosts: vec![],
-            ghosts: vec![],
+        if crack_hints.len() >= num_crack_hints {
+            break;
+        }
+
+        // Skip Cracks whose destination an earlier hint already gave away
+        if revealed_cracks.contains(&crack) {
+            continue;
+        }
+
+        let mut crack_hint = CrackHint::new(crack, &seed_info.crack_map);
+
+        // The Crack's own check holds its destination, so the ghosts found before it are those before the Crack
+        crack_hint.logical_ghosts = find_checks_before_goal(seed_info, check_map, crack_hint.destination)
+            .iter()
+            .filter_map(|check| {
+                if let Some(Randomizable::HintGhost(ghost)) = check.get_quest() {
+                    return Some(ghost);
+                };
+                None
+            })
+            .collect();
+        if crack_hint.choose_ghost(rng, taken_ghosts).is_err() {
+            continue;
+        }
+
+        revealed_cracks.extend([crack_hint.crack, crack_hint.destination]);
+        crack_hints.push(crack_hint);
+    }
+
+    crack_hints
+}
+
+/**
+ * Barren Hints
+ * Generates hints naming random regions where no check holds a major item or anything logic needs.
+ * Regions made up entirely of excluded or already hinted checks are skipped, as the player already knows what's there.
+ */
+fn generate_barren_hints(
+    rng: &mut StdRng, seed_info: &SeedInfo, check_map: &CheckMap, taken_checks: &[String],
+    taken_ghosts: &mut Vec<HintGhost>,
+) -> Vec<BarrenHint> {
+    let num_barren_hints = seed_info.settings.hint_distribution.barren;
+    if num_barren_hints == 0 {
+        return vec![];
+    }
+
+    // Subregions sharing a name make up one region, as far as the player can tell
+    let mut regions = BTreeMap::<&'static str, (&'static Subregion, bool, bool)>::new();
+    for check in seed_info.world_graph.values().flat_map(|node| node.get_checks().iter().flatten()) {
+        let Some(location_info) = check.get_location_info() else {
+            continue;
+        };
+        let is_required = check_map
+            .get(check.get_name())
+            .copied()
+            .flatten()
+            .is_some_and(|item| item.is_major_item() || item.include_in_sphere_search());
+        let is_taken = taken_checks.iter().any(|taken| taken == check.get_name());
+        let (_, is_barren, is_unknown) =
+            regions.entry(location_info.region()).or_insert((location_info.subregion(), true, false));
+        *is_barren &= !is_required;
+        *is_unknown |= !is_taken;
+    }
+
+    let barren_regions = regions
+        .into_values()
+        .filter_map(|(subregion, is_barren, is_unknown)| (is_barren && is_unknown).then_some(subregion));
+    let mut barren_hints = vec![];
+    for subregion in barren_regions.choose_multiple(rng, num_barren_hints) {
+        let Some(ghost) = HintGhost::iter().filter(|ghost| !taken_ghosts.contains(ghost)).choose_stable(rng) else {
+            break;
         };
+        taken_ghosts.push(ghost);
+        barren_hints.push(BarrenHint { subregion, ghosts: vec![ghost] });
     }
 
-    todo!()
+    barren_hints
 }
 
-fn duplicate_hints(
-    taken_ghosts: &mut [HintGhost], always_hints: &mut Vec<LocationHint>, maiamai_hints: &mut Vec<LocationHint>,
-    path_hints: &mut Vec<PathHint>, sometimes_hints: &mut Vec<LocationHint>, num_total_hints: usize, rng: &mut StdRng,
-) {
+/// Gives each hint its duplicate Hint Ghosts at random, then has any Ghosts still left over repeat random hints.
+fn duplicate_hints(hints: &mut Hints, taken_ghosts: &[HintGhost], distribution: &HintDistribution, rng: &mut StdRng) {
+    let num_total_hints = distribution.total;
     assert_eq!(
         taken_ghosts.len(),
         num_total_hints,
@@ -315,40 +613,46 @@ fn duplicate_hints(
         taken_ghosts.len(),
         num_total_hints
     );
-    let hint_count = always_hints.len() + maiamai_hints.len() + path_hints.len() + sometimes_hints.len();
+
+    let Hints {
+        path_hints, always_hints, maiamai_hints, sometimes_hints, hero_hints, crack_hints, barren_hints, ..
+    } = hints;
+    let mut hint_ghosts = always_hints
+        .iter_mut()
+        .map(|hint| &mut hint.ghosts)
+        .chain(maiamai_hints.iter_mut().map(|hint| &mut hint.ghosts))
+        .chain(path_hints.iter_mut().map(|hint| &mut hint.ghosts))
+        .chain(sometimes_hints.iter_mut().map(|hint| &mut hint.ghosts))
+        .chain(hero_hints.iter_mut().map(|hint| &mut hint.ghosts))
+        .chain(crack_hints.iter_mut().map(|hint| &mut hint.ghosts))
+        .chain(barren_hints.iter_mut().map(|hint| &mut hint.ghosts))
+        .collect::<Vec<_>>();
     assert_eq!(
-        hint_count, num_total_hints,
+        hint_ghosts.len(),
+        num_total_hints,
         "Only {} of the expected {} hints were actually created",
-        hint_count, num_total_hints
+        hint_ghosts.len(),
+        num_total_hints
     );
 
-    // todo probably don't need to duplicate this
-
     let mut ghosts = HintGhost::iter().collect::<Vec<_>>();
     ghosts.retain(|ghost| !taken_ghosts.contains(ghost));
 
-    for hint in always_hints {
-        hint.ghosts.push(ghosts.remove(rng.gen_range(0..ghosts.len())));
-    }
-
-    for hint in maiamai_hints {
-        hint.ghosts.push(ghosts.remove(rng.gen_range(0..ghosts.len())));
-    }
-
-    for hint in path_hints {
-        hint.ghosts.push(ghosts.remove(rng.gen_range(0..ghosts.len())));
+    for _ in 0..distribution.duplicates {
+        for hint_ghosts in hint_ghosts.iter_mut() {
+            hint_ghosts.push(ghosts.remove(rng.gen_range(0..ghosts.len())));
+        }
     }
 
-    for hint in sometimes_hints {
-        hint.ghosts.push(ghosts.remove(rng.gen_range(0..ghosts.len())));
+    while !ghosts.is_empty() && !hint_ghosts.is_empty() {
+        let i = rng.gen_range(0..hint_ghosts.len());
+        hint_ghosts[i].push(ghosts.remove(rng.gen_range(0..ghosts.len())));
     }
-
-    assert_eq!(ghosts.len(), 0, "There were leftover Hint Ghosts: {:?}", ghosts);
 }
 
 /// Generates the Bow of Light Hint
 /// todo need a generic "find where item be at" function
-fn generate_bow_of_light_hint(
+pub(crate) fn generate_bow_of_light_hint(
     SeedInfo { settings, world_graph, .. }: &SeedInfo, check_map: &mut CheckMap,
 ) -> Option<BowOfLightHint> {
     if settings.progressive_bow_of_light {
@@ -356,10 +660,10 @@ fn generate_bow_of_light_hint(
     }
 
     for location_node in world_graph.values() {
-        for &check in location_node.clone().get_checks().iter().flatten().collect::<Vec<&Check>>() {
+        for check in location_node.get_checks().iter().flatten() {
             if let Randomizable::Item(item) = check_map.get(check.get_name()).unwrap().unwrap() {
                 if BowOfLight == item {
-                    return Some(BowOfLightHint { check });
+                    return Some(BowOfLightHint { check: check.clone() });
                 }
             }
         }
@@ -375,18 +679,29 @@ fn generate_bow_of_light_hint(
 fn generate_always_hints(
     rng: &mut StdRng, seed_info: &SeedInfo, check_map: &mut CheckMap, taken_checks: &mut Vec<String>,
     taken_ghosts: &mut Vec<HintGhost>,
-) -> Vec<LocationHint> {
+) -> Result<Vec<LocationHint>, Error> {
     let mut always_checks = vec![
         "Blacksmith (Lorule)", "Bouldering Guy", "Great Rupee Fairy", "Haunted Grove Stump", "Irene",
         "Master Sword Pedestal", "Octoball Derby", "Queen Oren", "Rosso (1)", "Rosso (2)", "Thief Girl",
         "Treacherous Tower", "[HC] Throne",
     ];
+    for check_name in &seed_info.settings.hint_distribution.always_checks {
+        if seed_info.world_graph.get_check(check_name).and_then(Check::get_location_info).is_none() {
+            return Err(Error::settings(format!(
+                "Invalid Hint Distribution: \"{}\" has an Always Hint for an unknown check: \"{}\"",
+                seed_info.settings.hint_distribution.name, check_name
+            )));
+        }
+        if !always_checks.contains(&check_name.as_str()) {
+            always_checks.push(check_name);
+        }
+    }
 
     always_checks.retain(|check| !taken_checks.contains(&check.to_string()));
 
     let mut always_hints = Vec::new();
     for check_name in always_checks {
-        let mut location_hint = generate_location_hint(check_name, seed_info, check_map);
+        let mut location_hint = generate_location_hint(check_name, seed_info, check_map)?;
         if location_hint.choose_ghost(rng, taken_ghosts).is_err() {
             continue;
         }
@@ -394,20 +709,20 @@ fn generate_always_hints(
         taken_checks.push(check_name.to_string());
     }
 
-    always_hints
+    Ok(always_hints)
 }
 
 /// Generates hints for Mother Maiamai's Upgrades.
 fn generate_maiamai_hints(
     rng: &mut StdRng, seed_info: &SeedInfo, check_map: &mut CheckMap, taken_checks: &mut Vec<String>,
     taken_ghosts: &mut Vec<HintGhost>,
-) -> Vec<LocationHint> {
+) -> Result<Vec<LocationHint>, Error> {
     // Don't generate Maiamai Hints when Nice Items are vanilla or the Maiamai Limit is zero
     if seed_info.settings.nice_items == NiceItems::Vanilla || seed_info.settings.maiamai_limit / 10 == 0 {
-        return vec![];
+        return Ok(vec![]);
     }
 
-    const NUM_MAI_HINTS: usize = 5;
+    let num_mai_hints = seed_info.settings.hint_distribution.maiamai;
     let mut available_maiamai_checks = vec![
         "Maiamai Bow Upgrade", "Maiamai Boomerang Upgrade", "Maiamai Hookshot Upgrade", "Maiamai Hammer Upgrade",
         "Maiamai Bombs Upgrade", "Maiamai Fire Rod Upgrade", "Maiamai Ice Rod Upgrade", "Maiamai Tornado Rod Upgrade",
@@ -420,7 +735,7 @@ fn generate_maiamai_hints(
     // First find any and all checks with major items to make hints
     let mut chosen_maiamai_checks = vec![];
     let mut i = 0;
-    while i < available_maiamai_checks.len() && chosen_maiamai_checks.len() < NUM_MAI_HINTS {
+    while i < available_maiamai_checks.len() && chosen_maiamai_checks.len() < num_mai_hints {
         if let Some(Some(item)) = check_map.get(available_maiamai_checks[i]) {
             if item.is_major_item() {
                 chosen_maiamai_checks.push(available_maiamai_checks.remove(i));
@@ -431,15 +746,15 @@ fn generate_maiamai_hints(
     }
 
     // Add junk hints to reach the desired hint amount
-    if chosen_maiamai_checks.len() < NUM_MAI_HINTS {
+    if chosen_maiamai_checks.len() < num_mai_hints {
         chosen_maiamai_checks
-            .extend(available_maiamai_checks.choose_multiple(rng, NUM_MAI_HINTS - chosen_maiamai_checks.len()));
+            .extend(available_maiamai_checks.choose_multiple(rng, num_mai_hints - chosen_maiamai_checks.len()));
     }
 
     // Generate the actual Location Hints
-    let mut maiamai_hints = Vec::with_capacity(NUM_MAI_HINTS);
+    let mut maiamai_hints = Vec::with_capacity(num_mai_hints);
     for check_name in chosen_maiamai_checks {
-        let mut location_hint = generate_location_hint(check_name, seed_info, check_map);
+        let mut location_hint = generate_location_hint(check_name, seed_info, check_map)?;
         if location_hint.choose_ghost(rng, taken_ghosts).is_err() {
             continue;
         }
@@ -447,26 +762,20 @@ fn generate_maiamai_hints(
         taken_checks.push(check_name.to_string());
     }
 
-    maiamai_hints
+    Ok(maiamai_hints)
 }
 
-fn generate_location_hint(check_name: &'static str, seed_info: &SeedInfo, check_map: &mut CheckMap) -> LocationHint {
-    // fixme this sucks
-    let mut check = None;
-    'outer: for (_, loc_node) in seed_info.world_graph.clone() {
-        let checks = loc_node.get_checks().clone();
-        for c in checks.into_iter().flatten().collect::<Vec<Check>>() {
-            if check_name.eq(c.get_name()) {
-                check = Some(c);
-                break 'outer;
-            }
-        }
-    }
-
-    let (item, check) = if let Some(check) = check {
-        (check_map.get(check.get_name()).unwrap().unwrap(), check)
-    } else {
-        fail!("Failed to lookup Check from check_name: {}", check_name);
+fn generate_location_hint(
+    check_name: &str, seed_info: &SeedInfo, check_map: &mut CheckMap,
+) -> Result<LocationHint, Error> {
+    let check = seed_info
+        .world_graph
+        .get_check(check_name)
+        .cloned()
+        .ok_or_else(|| Error::internal(format!("Failed to lookup Check from check_name: {}", check_name)))?;
+    let item = check_map.get(check.get_name()).copied().flatten();
+    let Some(Randomizable::Item(item)) = item else {
+        return Err(Error::internal(format!("Tried to hint a non-item at: {}", check_name)));
     };
 
     let logical_ghosts = find_checks_before_goal(seed_info, check_map, item)
@@ -479,7 +788,7 @@ fn generate_location_hint(check_name: &'static str, seed_info: &SeedInfo, check_
         })
         .collect::<Vec<_>>();
 
-    LocationHint { item: item.as_item().unwrap(), check, logical_ghosts, ghosts: vec![] }
+    Ok(LocationHint { item, check, logical_ghosts, ghosts: vec![] })
 }
 
 /**
@@ -490,7 +799,7 @@ fn generate_location_hint(check_name: &'static str, seed_info: &SeedInfo, check_
 fn generate_sometimes_hints(
     rng: &mut StdRng, seed_info: &SeedInfo, check_map: &mut CheckMap, num_sometimes_hints: usize,
     taken_checks: &[String], taken_ghosts: &mut Vec<HintGhost>,
-) -> Vec<LocationHint> {
+) -> Result<Vec<LocationHint>, Error> {
     let mut sometimes_checks = vec![
         "Bee Guy (2)", "Behind Ice Gimos", "Bird Lover", "Blacksmith", "Blacksmith Cave", "Cucco Mini-Dungeon",
         "Hookshot Mini-Dungeon", "Donkey Cave", "Eastern Ruins Peg Circle", "Merge Mini-Dungeon", "Fire Cave Pillar",
@@ -540,7 +849,7 @@ fn generate_sometimes_hints(
         }
 
         let selected_hint = sometimes_checks.remove(rng.gen_range(0..sometimes_checks.len()));
-        let mut location_hint = generate_location_hint(selected_hint, seed_info, check_map);
+        let mut location_hint = generate_location_hint(selected_hint, seed_info, check_map)?;
         if location_hint.choose_ghost(rng, taken_ghosts).is_err() {
             continue;
         }
@@ -549,13 +858,14 @@ fn generate_sometimes_hints(
         sometimes_hint_count += 1;
     }
 
-    sometimes_hints
+    Ok(sometimes_hints)
 }
 
 /**
  * Path Hints
  *
- * Generates up to 6 Path Hints for each Boss guarding a Sage Portrait.
+ * Generates a Path Hint for each Boss guarding a Sage Portrait, up to the number set by the Hint Distribution. Any
+ * more Path Hints than that go to random Bosses.
  *
  * A "Path Hint" is a hint that specifies the location of a "Path Item" that is required to reach
  * and defeat a certain Boss, according the chosen Logic Mode and Settings.
@@ -579,11 +889,15 @@ fn generate_path_hints(
 
     bosses_and_prize_locations = shuffle(rng, bosses_and_prize_locations);
 
+    let num_path_hints = seed_info.settings.hint_distribution.path;
     let mut chosen_paths: Vec<PathHint> = Vec::new();
     let mut backup_paths: Vec<PathHint> = Vec::new();
-    let mut extra_paths_needed = 0;
 
     for (goal, prize_loc) in bosses_and_prize_locations {
+        if chosen_paths.len() >= num_path_hints {
+            break;
+        }
+
         if is_sage(check_map.get(prize_loc).unwrap().unwrap()) {
             let mut potential_paths = get_potential_path_hints(rng, seed_info, check_map, taken_checks, goal);
 
@@ -592,23 +906,17 @@ fn generate_path_hints(
                 backup_paths.extend(potential_paths);
             } else {
                 debug!("No Path Hints possible for Goal: {}", goal.as_str());
-                extra_paths_needed += 1;
             }
         }
     }
 
-    // Add extra paths if some bosses didn't have any path items
-    if extra_paths_needed > 0 {
+    // Add extra paths if some bosses didn't have any path items, or there are more hints than bosses
+    if chosen_paths.len() < num_path_hints {
         backup_paths = shuffle(rng, backup_paths);
-        let mut extra_paths_added = 0;
-        loop {
-            if extra_paths_added >= extra_paths_needed || backup_paths.is_empty() {
-                break;
-            }
-
-            if let Some(backup_path) = choose_path_hint(&mut backup_paths, taken_checks, taken_ghosts, rng) {
-                chosen_paths.push(backup_path);
-                extra_paths_added += 1;
+        while chosen_paths.len() < num_path_hints {
+            match choose_path_hint(&mut backup_paths, taken_checks, taken_ghosts, rng) {
+                Some(backup_path) => chosen_paths.push(backup_path),
+                None => break,
             }
         }
     }
@@ -652,7 +960,7 @@ fn find_checks_before_goal(
     // Find candidate Path Checks with a modified sphere search
     loop {
         reachable_checks = find_reachable_checks(seed_info, &progress);
-        potential_path_checks.extend(&reachable_checks);
+        potential_path_checks.extend(reachable_checks.iter().cloned());
         let reachable_items = get_items_from_reachable_checks(seed_info, &reachable_checks, check_map);
 
         let new_items = reachable_items.difference(&progress);
@@ -673,7 +981,6 @@ fn find_checks_before_goal(
 fn get_potential_path_hints(
     rng: &mut StdRng, seed_info: &SeedInfo, check_map: &mut CheckMap, taken_checks: &mut [String], goal: Goal,
 ) -> Vec<PathHint> {
-    let mut reachable_checks: Vec<Check>;
     let mut potential_paths: Vec<PathHint> = Vec::new();
 
     let mut potential_path_checks = find_checks_before_goal(seed_info, check_map, goal);
@@ -693,54 +1000,70 @@ fn get_potential_path_hints(
 
     // Test candidate items to see if Boss can be defeated without them
     for check in potential_path_checks {
-        // Reset Progression.
-        // Cloning is more efficient than constructing here because that constructor is fat. (and ugly)
-        let mut progress = nothing_but_hearts_and_rupees.clone();
-
-        loop {
-            reachable_checks = find_reachable_checks(seed_info, &progress);
-
-            // Remove Potential Path Location
-            reachable_checks.retain(|c| check.ne(c));
-
-            let reachable_items = get_items_from_reachable_checks(seed_info, &reachable_checks, check_map);
-
-            let new_items = reachable_items.difference(&progress);
-
-            if new_items.is_empty() {
-                // Item could be Path if goal couldn't be reached without it
-                if !progress.has(goal) {
-                    let hint_locations = reachable_items
-                        .get_items()
-                        .iter()
-                        .filter_map(|&item| if let Randomizable::HintGhost(ghost) = item { Some(ghost) } else { None })
-                        .collect::<_>();
-
-                    let path_item = check_map
-                        .get(check.get_name())
-                        .expect("Path check should be in Check Map")
-                        .expect("Path check should have Path Item");
-
-                    potential_paths.push(PathHint {
-                        goal,
-                        check,
-                        ghosts: vec![],
-                        logical_ghosts: hint_locations,
-                        path_item,
-                    });
-                }
-                break;
-            }
-
-            for new_item in &new_items {
-                progress.add_item(*new_item);
-            }
+        if let Some(logical_ghosts) =
+            find_ghosts_if_required(seed_info, check_map, &check, goal, &nothing_but_hearts_and_rupees)
+        {
+            let path_item = check_map
+                .get(check.get_name())
+                .expect("Path check should be in Check Map")
+                .expect("Path check should have Path Item");
+
+            potential_paths.push(PathHint { goal, check, ghosts: vec![], logical_ghosts, path_item });
         }
     }
 
     shuffle(rng, potential_paths)
 }
 
+/// Checks if a `goal` can't be reached without the item at `check`, starting from `progress`. <br />
+/// If so, returns the Hint Ghosts that can be reached without it.
+fn find_ghosts_if_required(
+    seed_info: &SeedInfo, check_map: &CheckMap, check: &Check, goal: Goal, progress: &Progress,
+) -> Option<Vec<HintGhost>> {
+    // Cloning is more efficient than constructing here because that constructor is fat. (and ugly)
+    let mut progress = progress.clone();
+
+    loop {
+        let mut reachable_checks = find_reachable_checks(seed_info, &progress);
+
+        // Remove Potential Path Location
+        reachable_checks.retain(|c| check.ne(c));
+
+        let reachable_items = get_items_from_reachable_checks(seed_info, &reachable_checks, check_map);
+
+        let new_items = reachable_items.difference(&progress);
+
+        if new_items.is_empty() {
+            // Item could be Path if goal couldn't be reached without it
+            return (!progress.has(goal)).then(|| {
+                reachable_items
+                    .get_items()
+                    .iter()
+                    .filter_map(|&item| if let Randomizable::HintGhost(ghost) = item { Some(ghost) } else { None })
+                    .collect()
+            });
+        }
+
+        for new_item in &new_items {
+            progress.add_item(*new_item);
+        }
+    }
+}
+
+/// Chooses a Ghost to give out a hint from the ones logically reachable before what it hints, of those not already taken.
+fn choose_logical_ghost(
+    logical_ghosts: &[HintGhost], ghosts: &mut Vec<HintGhost>, rng: &mut StdRng, taken_ghosts: &mut Vec<HintGhost>,
+) -> Result<(), &'static str> {
+    match logical_ghosts.iter().filter(|ghost| !taken_ghosts.contains(ghost)).choose_stable(rng) {
+        None => Err("No Ghosts available to place this hint"),
+        Some(ghost) => {
+            ghosts.push(*ghost);
+            taken_ghosts.push(*ghost);
+            Ok(())
+        },
+    }
+}
+
 struct SerializeGhosts<'a>(&'a [HintGhost]);
 
 impl Serialize for SerializeGhosts<'_> {
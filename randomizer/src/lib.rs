@@ -3,21 +3,25 @@ use crate::filler::tower_stage::TowerStage;
 use crate::filler::location::Location;
 use crate::filler::progress::Progress;
 use crate::filler::trials::TrialsConfig;
+use crate::filler::entrances::{self, EntranceMap};
 use crate::filler::{cracks, text, treacherous_tower, trials, vanes};
 use crate::world::WorldGraph;
 use crate::{
-    constants::VERSION,
+    constants::{SPOILER_SCHEMA_VERSION, VERSION},
     hints::{formatting::*, Hints},
     metrics::Metrics,
     patch::lms::msbf::MsbfKey,
-    system::UserConfig,
+    system::{OutputMode, UserConfig},
 };
 use filler::cracks::Crack;
 use filler::filler_item::{PyRandomizable, Randomizable};
+use game::ghosts::HintGhost;
 use game::Item::{self};
-use log::{debug, error, info};
-use macros::fail;
-use modinfo::Settings;
+use log::{debug, info};
+use modinfo::settings::goal::GoalSetting;
+use modinfo::settings::keysanity::Keysanity;
+use modinfo::settings::keysy::Keysy;
+use modinfo::{schema, settings_string, Settings};
 use patch::Patcher;
 use path_absolutize::*;
 use pyo3::prelude::*;
@@ -25,26 +29,34 @@ use rand::{rngs::StdRng, SeedableRng};
 use regex::Regex;
 use regions::Subregion;
 use rom::Rom;
-use serde::{ser::SerializeMap, Deserialize, Serialize, Serializer};
+use serde::{de, ser::SerializeMap, Deserialize, Deserializer, Serialize, Serializer};
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::hash::BuildHasherDefault;
 use std::{
     error::Error as StdError,
-    fs::File,
+    fmt::{self, Display, Formatter},
+    fs::{self, File},
     hash::{Hash, Hasher},
     io::{self, Write},
     ops::Deref,
+    path::PathBuf,
     str::FromStr,
 };
+use strum::IntoEnumIterator;
 use twox_hash::XxHash64;
 
 pub mod constants;
 pub mod filler;
 mod hints;
 mod metrics;
+mod migration;
 mod patch;
+pub mod plando;
 pub mod regions;
+mod spoiler;
+pub mod stats;
 pub mod system;
+pub mod verify;
 mod world;
 
 pub type Result<T, E = Error> = core::result::Result<T, E>;
@@ -70,6 +82,20 @@ impl Error {
         Self { kind: ErrorKind::Game, inner: err.into() }
     }
 
+    fn settings<S>(err: S) -> Self
+    where
+        S: Into<Box<dyn StdError + Send + Sync + 'static>>,
+    {
+        Self { kind: ErrorKind::Settings, inner: err.into() }
+    }
+
+    fn rom<S>(err: S) -> Self
+    where
+        S: Into<Box<dyn StdError + Send + Sync + 'static>>,
+    {
+        Self { kind: ErrorKind::Rom, inner: err.into() }
+    }
+
     fn io<S>(err: S) -> Self
     where
         S: Into<Box<dyn StdError + Send + Sync + 'static>>,
@@ -88,6 +114,12 @@ impl Error {
     }
 }
 
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
 impl From<rom::Error> for Error {
     fn from(err: rom::Error) -> Self {
         let kind = match err.kind() {
@@ -114,9 +146,34 @@ impl From<system::Error> for Error {
 pub enum ErrorKind {
     Internal,
     Game,
+    /// The settings can't make a seed, so trying again with another seed won't help.
+    Settings,
+    /// The ROM can't be patched, so trying again with another seed won't help.
+    Rom,
     Io,
 }
 
+// Python exceptions, one per ErrorKind, all catchable as RandomizerError
+pyo3::create_exception!(albwrandomizer, RandomizerError, pyo3::exceptions::PyException);
+pyo3::create_exception!(albwrandomizer, InternalError, RandomizerError);
+pyo3::create_exception!(albwrandomizer, GameError, RandomizerError);
+pyo3::create_exception!(albwrandomizer, SettingsError, RandomizerError);
+pyo3::create_exception!(albwrandomizer, RomError, RandomizerError);
+pyo3::create_exception!(albwrandomizer, IoError, RandomizerError);
+
+impl From<Error> for PyErr {
+    fn from(err: Error) -> Self {
+        let message = err.to_string();
+        match err.kind {
+            ErrorKind::Internal => InternalError::new_err(message),
+            ErrorKind::Game => GameError::new_err(message),
+            ErrorKind::Settings => SettingsError::new_err(message),
+            ErrorKind::Rom => RomError::new_err(message),
+            ErrorKind::Io => IoError::new_err(message),
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Serialize)]
 pub struct LocationInfo {
     subregion: &'static Subregion,
@@ -132,6 +189,10 @@ impl LocationInfo {
         self.subregion.world()
     }
 
+    pub fn subregion(&self) -> &'static Subregion {
+        self.subregion
+    }
+
     pub fn region(&self) -> &'static str {
         self.subregion.name()
     }
@@ -268,9 +329,16 @@ where
     map.end()
 }
 
+fn serialize_placements<S>(placements: &BTreeMap<String, filler::filler_item::Item>, ser: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    ser.collect_map(placements.iter().map(|(check_name, &item)| (check_name, <&'static str>::from(item))))
+}
+
 /// Align JSON Key-Values for readability
 /// Can't find a decent library for this, so we're doing it manually
-fn align_json_values(json: &mut String) {
+fn align_json_values(json: &mut String) -> Result<()> {
     const KEY_ALIGNMENT: usize = 56;
     let mut index_colon = 0;
     while index_colon < json.len() {
@@ -284,18 +352,18 @@ fn align_json_values(json: &mut String) {
             continue;
         }
 
-        let index_prev_new_line = json[..index_colon].rfind('\n').unwrap_or_else(|| {
-            fail!("Couldn't fine new line character before index: {}", index_colon);
-        });
+        let index_prev_new_line = json[..index_colon].rfind('\n').ok_or_else(|| {
+            Error::internal(format!("Couldn't find new line character before index: {}", index_colon))
+        })?;
         let line_length_up_to_value = index_colon - index_prev_new_line;
 
         if KEY_ALIGNMENT < line_length_up_to_value {
-            error!("Failed to write Spoiler Log");
-            error!(
-                "JSON Key Alignment value smaller than line length up to that point: {} < {}",
-                KEY_ALIGNMENT, line_length_up_to_value
-            );
-            fail!("Problem line: {}", &json[index_prev_new_line..index_colon]);
+            return Err(Error::internal(format!(
+                "JSON Key Alignment value smaller than line length up to that point: {} < {}\nProblem line: {}",
+                KEY_ALIGNMENT,
+                line_length_up_to_value,
+                &json[index_prev_new_line..index_colon]
+            )));
         }
 
         let spaces_to_add = KEY_ALIGNMENT - line_length_up_to_value;
@@ -303,9 +371,11 @@ fn align_json_values(json: &mut String) {
         json.insert_str(&index_colon + 1, (0..spaces_to_add).map(|_| " ").collect::<String>().as_str());
         index_colon += 1;
     }
+
+    Ok(())
 }
 
-#[derive(Serialize, Default, Debug)]
+#[derive(Serialize, Deserialize, Default, Debug)]
 pub struct Text {
     credits: String,
 }
@@ -317,7 +387,7 @@ fn sanitize(string: &str) -> String {
     remove.replace_all(&to_space.replace_all(string, " "), "").to_string()
 }
 
-#[derive(Serialize, Default, Debug, Clone)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
 #[pyclass]
 pub struct ArchipelagoInfo {
     #[pyo3(get, set)]
@@ -344,52 +414,59 @@ impl ArchipelagoInfo {
     }
 }
 
+/// Everything about a Seed. Serializes into the Spoiler Log, and deserializes from either a Spoiler Log or a Preset.
 #[derive(Serialize, Deserialize, Debug)]
+#[serde(try_from = "spoiler::SpoilerLog")]
 #[pyclass]
 pub struct SeedInfo {
-    #[serde(default)]
+    #[pyo3(get)]
     pub seed: u32,
 
     pub version: String,
 
-    #[serde(skip_deserializing)]
+    /// The [`SPOILER_SCHEMA_VERSION`] this Seed is serialized with.
+    pub schema_version: u32,
+
     pub hash: SeedHash,
 
-    #[serde(skip_deserializing)]
+    /// The seed and its [`settings`] as a compact Settings String, to be shared instead of a Preset.
+    #[pyo3(get)]
+    pub settings_string: String,
+
     pub archipelago_info: Option<ArchipelagoInfo>,
 
     pub settings: Settings,
 
     /// The list of exclusions provided by the user in [`settings`], enhanced by the randomizer based on settings.
-    #[serde(skip_deserializing)]
     pub full_exclusions: BTreeSet<String>,
 
-    #[serde(skip_deserializing)]
     pub treacherous_tower_floors: Vec<TowerStage>,
 
-    #[serde(skip_deserializing)]
     pub trials_config: TrialsConfig,
 
-    #[serde(skip_deserializing)]
     pub layout: Layout,
 
-    #[serde(skip_deserializing)]
+    /// The item at each check of the [`layout`], by the same identifier Plandos use (e.g. `"Sword02"`). Several items
+    /// share a name in the `layout`, so a Spoiler Log's items are read back from here.
+    #[serde(serialize_with = "serialize_placements")]
+    pub placements: BTreeMap<String, filler::filler_item::Item>,
+
     pub crack_map: CrackMap,
 
-    #[serde(skip_deserializing, rename = "weather_vane_map")]
+    #[serde(rename = "weather_vane_map")]
     #[pyo3(get)]
     pub vane_map: VaneMap,
 
-    #[serde(skip_deserializing)]
+    #[serde(skip_serializing_if = "EntranceMap::is_empty")]
+    pub entrance_map: EntranceMap,
+
     pub metrics: Metrics,
 
-    #[serde(skip_deserializing, skip_serializing)]
     pub text: Text,
 
-    #[serde(skip_deserializing)]
     pub hints: Hints,
 
-    #[serde(skip_deserializing, skip_serializing)]
+    #[serde(skip_serializing)]
     pub world_graph: WorldGraph,
 }
 
@@ -408,13 +485,17 @@ impl Default for SeedInfo {
         Self {
             seed: 0,
             version: "".to_owned(),
+            schema_version: SPOILER_SCHEMA_VERSION,
             hash: Default::default(),
+            settings_string: Default::default(),
             archipelago_info: None,
             settings: Default::default(),
             full_exclusions: Default::default(),
             crack_map: Default::default(),
             vane_map: Default::default(),
+            entrance_map: Default::default(),
             layout: Default::default(),
+            placements: Default::default(),
             metrics: Default::default(),
             hints: Default::default(),
             trials_config: Default::default(),
@@ -429,20 +510,47 @@ impl Default for SeedInfo {
 pub fn generate_seed(
     seed: u32, settings: Settings, user_config: &UserConfig, no_patch: bool, no_spoiler: bool,
 ) -> Result<()> {
+    println!();
+
+    if !no_patch {
+        validate_patchable(&settings)?;
+        validate_rom(user_config)?;
+    }
+
+    let seed_info = &generate_seed_info(seed, settings)?;
+
+    info!("Hash:                           {}", seed_info.hash.text_hash);
+    info!("Settings String:                {}", seed_info.settings_string);
+
+    patch_seed(seed_info, user_config, no_patch, no_spoiler)?;
+
+    Ok(())
+}
+
+/// Generates one ALBWR Seed without patching it or writing a Spoiler Log.
+#[pyfunction]
+pub fn generate_seed_info(seed: u32, settings: Settings) -> Result<SeedInfo> {
     validate_settings(&settings)?;
 
     let rng = &mut StdRng::seed_from_u64(seed as u64);
 
     let hash = SeedHash::new(seed, &settings);
 
-    info!("Hash:                           {}", hash.text_hash);
-
     // settings.log_settings();
 
-    let seed_info = &calculate_seed_info(seed, settings, hash, rng)?;
-    patch_seed(seed_info, user_config, no_patch, no_spoiler)?;
+    calculate_seed_info(seed, settings, hash, rng)
+}
 
-    Ok(())
+/// Encodes a seed and its Settings as a compact Settings String, to be shared instead of a Preset.
+#[pyfunction]
+pub fn encode_settings_string(seed: u32, settings: Settings) -> String {
+    settings_string::encode(seed, &settings)
+}
+
+/// Decodes a Settings String back into the seed and Settings it was encoded from.
+#[pyfunction]
+pub fn decode_settings_string(settings_string: &str) -> Result<(u32, Settings)> {
+    settings_string::decode(settings_string).map_err(Error::settings)
 }
 
 /// A hash used in-game to quickly verify that two players are playing the same seed.
@@ -463,18 +571,7 @@ impl SeedHash {
         let mut hash = hasher.finish() % 100_000;
 
         // Convert to Item Hash
-        let hash_item_lut: Vec<(&String, &str)> = vec![
-            (A_BUTTON.deref(), "(A)"),
-            (B_BUTTON.deref(), "(B)"),
-            (X_BUTTON.deref(), "(X)"),
-            (Y_BUTTON.deref(), "(Y)"),
-            (L_BUTTON.deref(), "(L)"),
-            (R_BUTTON.deref(), "(R)"),
-            (RAVIO.deref(), "(Ravio)"),
-            (SYMBOL_BOW.deref(), "(Bow)"),
-            (SYMBOL_BOMBS.deref(), "(Bomb)"),
-            (SYMBOL_FIRE_ROD.deref(), "(Fire)"),
-        ];
+        let hash_item_lut = Self::symbols();
 
         const HASH_LEN: usize = 5;
         let mut digit = Vec::with_capacity(HASH_LEN);
@@ -488,6 +585,22 @@ impl SeedHash {
 
         Self { item_hash, text_hash }
     }
+
+    /// Pairs each in-game hash symbol with its text form.
+    fn symbols() -> Vec<(&'static String, &'static str)> {
+        vec![
+            (A_BUTTON.deref(), "(A)"),
+            (B_BUTTON.deref(), "(B)"),
+            (X_BUTTON.deref(), "(X)"),
+            (Y_BUTTON.deref(), "(Y)"),
+            (L_BUTTON.deref(), "(L)"),
+            (R_BUTTON.deref(), "(R)"),
+            (RAVIO.deref(), "(Ravio)"),
+            (SYMBOL_BOW.deref(), "(Bow)"),
+            (SYMBOL_BOMBS.deref(), "(Bomb)"),
+            (SYMBOL_FIRE_ROD.deref(), "(Fire)"),
+        ]
+    }
 }
 
 impl Serialize for SeedHash {
@@ -499,45 +612,116 @@ impl Serialize for SeedHash {
     }
 }
 
+impl<'de> Deserialize<'de> for SeedHash {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let text_hash = String::deserialize(deserializer)?;
+        let symbols = Self::symbols();
+        let item_hash = text_hash
+            .split(' ')
+            .map(|text| match symbols.iter().find(|(_, symbol)| *symbol == text) {
+                Some((item, _)) => Ok(item.as_str()),
+                None => Err(de::Error::custom(format!("Unknown hash symbol: \"{}\"", text))),
+            })
+            .collect::<std::result::Result<Vec<_>, _>>()?
+            .join(" ");
+
+        Ok(Self { item_hash, text_hash })
+    }
+}
+
 /// Validates the Settings to make sure the user hasn't made incompatible selections
 fn validate_settings(settings: &Settings) -> Result<()> {
-    // LC Requirement
-    if !(0..=7).contains(&settings.lc_requirement) {
-        fail!("Invalid Lorule Castle Requirement: \"{}\" was not between 0-7, inclusive.", settings.lc_requirement);
+    // Ranges, limits, and conflicts between settings
+    schema::validate(settings).map_err(Error::settings)?;
+
+    // Hint Distribution
+    let distribution = &settings.hint_distribution;
+    let num_ghosts = HintGhost::iter().count();
+    if distribution.total * (1 + distribution.duplicates) > num_ghosts {
+        return Err(Error::settings(format!(
+            "Invalid Hint Distribution: \"{}\" needs {} Hint Ghosts for {} hints with {} duplicates each, but only {} \
+            Hint Ghosts give out hints.",
+            distribution.name,
+            distribution.total * (1 + distribution.duplicates),
+            distribution.total,
+            distribution.duplicates,
+            num_ghosts
+        )));
+    }
+    if distribution.path + distribution.maiamai + distribution.hero + distribution.crack + distribution.barren
+        > distribution.total
+    {
+        return Err(Error::settings(format!(
+            "Invalid Hint Distribution: \"{}\" has more Path, Maiamai, Way of the Hero, Crack, and Barren Hints than its \
+            total of {}.",
+            distribution.name,
+            distribution.total
+        )));
     }
 
-    // Yuganon Requirement
-    // if !(0..=7).contains(&settings.logic.yuganon_requirement) {
-    //     fail!("Invalid Yuga Ganon Requirement: \"{}\" was not between 0-7, inclusive.", settings.logic.yuganon_requirement);
-    // }
+    Ok(())
+}
 
+/// Validates that the patcher can honor the Settings, so an unsupported seed is refused before it's ever filled.
+fn validate_patchable(settings: &Settings) -> Result<()> {
+    // The game credits Small Keys, Big Keys, and Compasses to whichever dungeon they're picked up in
+    let small_keys = !matches!(settings.keysy, Keysy::SmallKeysy | Keysy::AllKeysy);
+    let big_keys = !matches!(settings.keysy, Keysy::BigKeysy | Keysy::AllKeysy);
+    for (name, shuffled, keysanity) in [
+        ("Small Keys", small_keys, settings.small_keys),
+        ("Big Keys", big_keys, settings.big_keys),
+        ("Compasses", true, settings.compasses),
+    ] {
+        if shuffled && keysanity != Keysanity::OwnDungeon {
+            return Err(Error::settings(format!(
+                "{}: \"{}\" is not yet supported by the patcher.\n\
+                Dungeon items found outside of their own dungeon would be credited to the wrong dungeon.\n\
+                Use --no-patch to generate this seed's spoiler log only.",
+                name, keysanity
+            )));
+        }
+    }
+
+    // The Throne Room opens with the Lorule Castle barrier in-game, so the patcher can't yet honor a separate count
     if settings.yuganon_requirement != settings.lc_requirement {
-        fail!(
+        return Err(Error::settings(format!(
             "Yuga Ganon Requirement: \"{}\" is different than Lorule Castle Requirement: \"{}\"\n\
-        Different values for these settings are not yet supported!",
+            Different values for these settings are not yet supported by the patcher.\n\
+            Use --no-patch to generate this seed's spoiler log only.",
             settings.yuganon_requirement,
             settings.lc_requirement
-        );
+        )));
     }
 
-    // Progressive Bow of Light
-    if settings.progressive_bow_of_light && settings.bow_of_light_in_castle {
-        fail!("The progressive_bow_of_light and bow_of_light_in_castle settings cannot both be enabled.");
+    // The credits only roll after the Yuga Ganon fight in-game, so the patcher can't yet end the game anywhere else
+    if settings.goal != GoalSetting::YugaGanon {
+        return Err(Error::settings(format!(
+            "Goal: \"{}\" is not yet supported by the patcher.\n\
+            Use --no-patch to generate this seed's spoiler log only.",
+            settings.goal
+        )));
     }
 
-    // Swords
-    if settings.sword_in_shop && settings.swordless_mode {
-        fail!("The sword_in_shop and swordless_mode settings cannot both be enabled.");
-    }
+    Ok(())
+}
 
-    // Assured Weapons
-    if settings.assured_weapon && (settings.sword_in_shop || settings.boots_in_shop) {
-        fail!(
-            "The assured_weapon setting cannot be enabled when either sword_in_shop or boots_in_shop is also enabled."
-        );
-    }
+/// Validates that the ROM is one the patcher supports, so it's refused before a seed is generated for it.
+fn validate_rom(user_config: &UserConfig) -> Result<()> {
+    Patcher::validate_region(with_rom(user_config, Rom::read_region)?)
+}
 
-    Ok(())
+/// Reads the user's ROM with `read`.
+fn with_rom<T>(user_config: &UserConfig, read: impl Fn(PathBuf) -> rom::Result<T>) -> Result<T> {
+    match read(user_config.rom().to_path_buf()) {
+        Ok(value) => Ok(value),
+        Err(_) => {
+            // Retry once, people keep naming their ROMs "ALBW.3ds.3ds" :P
+            Ok(read(format!("{}.3ds", user_config.rom().to_str().unwrap()).into())?)
+        },
+    }
 }
 
 /// "Deterministic `HashMap`" that uses a hashing algorithm not based on any random number generation, unlike the Rust
@@ -551,34 +735,38 @@ pub type DashSet<T> = HashSet<T, BuildHasherDefault<XxHash64>>;
 /// Map of all checks (as Strings) to their held item
 pub type CheckMap = DashMap<String, Option<Randomizable>>;
 
-/// Map of all cracks to their destination cracks. Map is not bidirectional to allow for (eventual) decoupled shuffle,
-/// so each Crack and its destination must have a corresponding reversed entry.
+/// Map of all cracks to their destination cracks. Map is not bidirectional to allow for decoupled shuffle, so each Crack
+/// needs its own entry even when its destination leads straight back to it.
 pub type CrackMap = BTreeMap<Crack, Crack>;
 
 /// Map of all Weather Vanes to the destination Vanes they unlock.
 pub type VaneMap = BTreeMap<Vane, Vane>;
 
 fn calculate_seed_info(seed: u32, settings: Settings, hash: SeedHash, rng: &mut StdRng) -> Result<SeedInfo> {
-    println!();
     info!("Calculating Seed Info...");
 
     let crack_map = cracks::build_crack_map(&settings, rng)?;
     let vane_map = vanes::build_vanes_map(&settings, rng)?;
+    let entrance_map = entrances::build_entrance_map(&settings, rng)?;
     let text = text::generate(rng)?;
     let trials_config = trials::configure(rng, &settings)?;
     let treacherous_tower_floors = treacherous_tower::choose_floors(&settings, rng)?;
-    let world_graph = world::build_world_graph(&crack_map);
+    let world_graph = world::build_world_graph(&settings, &crack_map, &entrance_map)?;
 
     let mut seed_info = SeedInfo {
         seed,
         version: VERSION.to_owned(),
+        schema_version: SPOILER_SCHEMA_VERSION,
         hash,
+        settings_string: settings_string::encode(seed, &settings),
         archipelago_info: None,
         settings,
         full_exclusions: Default::default(),
         vane_map,
         crack_map,
+        entrance_map,
         layout: Default::default(),
+        placements: Default::default(),
         metrics: Default::default(),
         hints: Default::default(),
         trials_config,
@@ -601,33 +789,40 @@ fn calculate_seed_info(seed: u32, settings: Settings, hash: SeedHash, rng: &mut
 }
 
 #[pyfunction]
-pub fn randomize_pre_fill(seed: u32, settings: Settings, archipelago_info: Option<ArchipelagoInfo>) -> SeedInfo {
+pub fn randomize_pre_fill(
+    seed: u32, settings: Settings, archipelago_info: Option<ArchipelagoInfo>,
+) -> Result<SeedInfo> {
     let rng = &mut StdRng::seed_from_u64(seed.clone() as u64);
     let hash = SeedHash::new(seed.clone(), &settings);
-    let crack_map = cracks::build_crack_map(&settings, rng).unwrap();
-    let vane_map = vanes::build_vanes_map(&settings, rng).unwrap();
-    let text = text::generate(rng).unwrap();
-    let trials_config = trials::configure(rng, &settings).unwrap();
-    let treacherous_tower_floors = treacherous_tower::choose_floors(&settings, rng).unwrap();
-    let world_graph = world::build_world_graph(&crack_map);
-
-    SeedInfo {
+    let crack_map = cracks::build_crack_map(&settings, rng)?;
+    let vane_map = vanes::build_vanes_map(&settings, rng)?;
+    let entrance_map = entrances::build_entrance_map(&settings, rng)?;
+    let text = text::generate(rng)?;
+    let trials_config = trials::configure(rng, &settings)?;
+    let treacherous_tower_floors = treacherous_tower::choose_floors(&settings, rng)?;
+    let world_graph = world::build_world_graph(&settings, &crack_map, &entrance_map)?;
+
+    Ok(SeedInfo {
         seed,
         version: VERSION.to_owned(),
+        schema_version: SPOILER_SCHEMA_VERSION,
         hash,
+        settings_string: settings_string::encode(seed, &settings),
         archipelago_info,
         settings,
         full_exclusions: Default::default(),
         vane_map,
         crack_map,
+        entrance_map,
         layout: Default::default(),
+        placements: Default::default(),
         metrics: Default::default(),
         hints: Default::default(),
         trials_config,
         world_graph,
         text,
         treacherous_tower_floors,
-    }
+    })
 }
 
 #[pymethods]
@@ -647,49 +842,67 @@ impl SeedInfo {
         ).collect()
     }
 
-    pub fn can_reach(&self, check_name: &str, items: Vec<PyRandomizable>) -> bool {
+    pub fn can_reach(&self, check_name: &str, items: Vec<PyRandomizable>) -> Result<bool> {
         let mut progress = Progress::new(&self);
         for item in items {
             progress.add_item(item);
         }
 
         if let Some(check) = self.world_graph.get_check(check_name) {
-            return check.can_access(&progress);
+            return Ok(check.can_access(&progress));
         }
 
-        panic!("{} is not the name of a check", check_name);
+        Err(Error::game(format!("{} is not the name of a check", check_name)))
     }
 
-    pub fn can_traverse(&self, source_name: &str, target_name: &str, items: Vec<PyRandomizable>) -> bool {
+    pub fn can_traverse(&self, source_name: &str, target_name: &str, items: Vec<PyRandomizable>) -> Result<bool> {
         let mut progress = Progress::new(&self);
         for item in items {
             progress.add_item(item);
         }
 
-        let source_region = Location::from_str(source_name)
-            .expect(&format!("No region {} found", source_name));
-        if let Some(paths) = self.world_graph[&source_region].get_paths() {
+        let source_region =
+            Location::from_str(source_name).map_err(|_| Error::game(format!("No region {} found", source_name)))?;
+        if let Some(paths) = self.world_graph.get(&source_region).and_then(|node| node.get_paths().as_ref()) {
             for path in paths {
                 if path.get_destination().to_string() == target_name {
-                    return path.can_access(&progress);
+                    return Ok(path.can_access(&progress));
                 }
             }
         }
 
-        panic!("No path from {} to {}", source_name, target_name);
+        Err(Error::game(format!("No path from {} to {}", source_name, target_name)))
     }
 
-    pub fn build_layout(&mut self, new_check_map: DashMap<String, PyRandomizable>) {
+    pub fn build_layout(&mut self, new_check_map: DashMap<String, PyRandomizable>) -> Result<()> {
         let mut check_map = filler::prefill_check_map(&mut self.world_graph);
         for (name, item) in new_check_map {
             check_map.insert(name, Some(item.into()));
         }
-        filler::build_layout(self, &mut check_map).unwrap();
+        Ok(filler::build_layout(self, &mut check_map)?)
     }
 
-    pub fn patch(&self, rom_path: &str, out_path: &str) {
-        let user_config = UserConfig::new(rom_path.into(), out_path.into());
-        patch_seed(self, &user_config, false, true).unwrap();
+    /// Calculates the Metrics and Hints for a layout made with `build_layout`, as `generate_seed_info` does after
+    /// filling a Seed.
+    pub fn calculate_metrics_and_hints(&mut self) -> Result<()> {
+        let check_map = &mut filler::read_layout(self);
+        metrics::calculate_metrics(self, check_map)?;
+        let rng = &mut StdRng::seed_from_u64(self.seed as u64);
+        hints::generate_hints(rng, self, check_map)?;
+        Ok(())
+    }
+
+    /// Gets the Spoiler Log for this Seed, as JSON.
+    pub fn spoiler(&self) -> Result<String> {
+        let mut serialized = serde_json::to_string_pretty(self).map_err(Error::internal)?;
+        align_json_values(&mut serialized)?;
+        Ok(serialized)
+    }
+
+    #[pyo3(signature = (rom_path, out_path, output_mode = OutputMode::LayeredFs))]
+    pub fn patch(&self, rom_path: &str, out_path: &str, output_mode: OutputMode) -> Result<()> {
+        let user_config = UserConfig::new(rom_path.into(), out_path.into(), output_mode);
+        patch_seed(self, &user_config, false, true)
     }
 }
 
@@ -697,15 +910,22 @@ pub fn patch_seed(seed_info: &SeedInfo, user_config: &UserConfig, no_patch: bool
     info!("");
 
     if !no_patch {
+        validate_patchable(&seed_info.settings)?;
+
+        // Plandos may still place dungeon items outside their dungeon by hand
+        if let Some((check, item)) = filler::find_out_of_dungeon_items(seed_info).first() {
+            return Err(Error::settings(format!(
+                "{} was placed outside of its dungeon at: {}\n\
+                Dungeon items found outside of their own dungeon are not yet supported by the patcher.\n\
+                Use --no-patch to generate this seed's spoiler log only.",
+                item.as_str(),
+                check
+            )));
+        }
+
         info!("Starting Patch Process...");
 
-        let game = match Rom::load(user_config.rom()) {
-            Ok(rom) => rom,
-            Err(_) => {
-                // Retry once, people keep naming their ROMs "ALBW.3ds.3ds" :P
-                Rom::load(format!("{}.3ds", user_config.rom().to_str().unwrap()))?
-            },
-        };
+        let game = with_rom(user_config, Rom::load)?;
         let mut patcher = Patcher::new(game)?;
 
         info!("ROM Loaded.\n");
@@ -719,18 +939,14 @@ pub fn patch_seed(seed_info: &SeedInfo, user_config: &UserConfig, no_patch: bool
 
         regions::patch(&mut patcher, seed_info)?;
         let patches = patcher.prepare(seed_info)?;
-        patches.dump(user_config.output())?;
+        patches.dump(user_config, seed_info.seed)?;
     }
     if !no_spoiler {
         let path = user_config.output().join(format!("{:0>10}_spoiler.json", seed_info.seed));
         info!("Writing Spoiler Log to:         {}", &path.absolutize()?.display());
 
-        //let spoiler = Spoiler::from(seed_info);
-
-        let mut serialized = serde_json::to_string_pretty(&seed_info).unwrap();
-        align_json_values(&mut serialized);
-
-        write!(File::create(path)?, "{}", serialized).expect("Could not write the spoiler log.");
+        fs::create_dir_all(user_config.output())?;
+        write!(File::create(path)?, "{}", seed_info.spoiler()?)?;
     }
 
     // let path = user_config.output().join(format!("{:0>10}_world_graph.json", seed_info.seed));
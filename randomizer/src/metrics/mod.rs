@@ -124,4 +124,11 @@ pub struct Metrics {
     playthrough: Playthrough,
 }
 
+impl Metrics {
+    /// Number of spheres in the Playthrough.
+    pub fn spheres(&self) -> usize {
+        self.spheres
+    }
+}
+
 pub type Playthrough = BTreeMap<String, Sphere>;
@@ -0,0 +1,253 @@
+//! Upgrading Presets written for older versions of the randomizer to the current [`Settings`] schema.
+//!
+//! [`Settings`]: modinfo::Settings
+
+use crate::constants::VERSION;
+use log::{info, warn};
+use serde_json::{Map, Value};
+use std::cmp::Ordering;
+
+/// Upgrades the JSON of a Preset from an older version in place, renaming and converting its settings to their current
+/// form and filling in defaults that keep it playing the way it used to.
+///
+/// Conversions that can't keep a setting's old behavior are logged as warnings. Whether the upgraded Preset is truly
+/// compatible is left to its deserialization, which rejects anything still unknown.
+pub(crate) fn migrate_preset(preset: &mut Value) -> Result<(), String> {
+    let preset = preset.as_object_mut().ok_or("Preset is not a JSON object")?;
+
+    let version = preset.get("version").and_then(Value::as_str).unwrap_or_default().to_owned();
+    if version == VERSION {
+        return Ok(());
+    }
+
+    let parsed = BuildVersion::parse(&version);
+    match &parsed {
+        Some(parsed) if BuildVersion::parse(VERSION).is_some_and(|current| *parsed > current) => {
+            warn!("Preset is from \"{}\", which is newer than this randomizer: \"{}\"", version, VERSION)
+        },
+        Some(_) => {},
+        None => warn!("Preset has an unrecognized version: \"{}\"", version),
+    }
+
+    if let Some(settings) = preset.get_mut("settings") {
+        let settings = settings.as_object_mut().ok_or("Preset settings are not a JSON object")?;
+        migrate_settings(settings, parsed.as_ref().is_some_and(|parsed| parsed.numbers < [0, 4, 0]));
+    }
+
+    info!("Migrated preset from \"{}\" to \"{}\"", version, VERSION);
+    preset.insert("version".to_owned(), VERSION.into());
+
+    Ok(())
+}
+
+fn migrate_settings(settings: &mut Map<String, Value>, before_v0_4: bool) {
+    // Settings used to be grouped, e.g. `settings.logic.logic_mode` is now `settings.logic_mode`
+    for group in ["logic", "options"] {
+        if let Some(Value::Object(group)) = settings.remove(group) {
+            for (name, value) in group {
+                settings.entry(name).or_insert(value);
+            }
+        }
+    }
+    if let Some(Value::Object(dev)) = settings.remove("dev") {
+        if !dev.is_empty() {
+            warn!("Dropped developer settings, which are no longer read from presets");
+        }
+    }
+    if let Some(exclusions) = settings.remove("exclusions") {
+        let mut check_names = Vec::new();
+        collect_strings(&exclusions, &mut check_names);
+        let user_exclusions = settings.entry("user_exclusions").or_insert_with(|| Value::Array(vec![]));
+        if let Some(user_exclusions) = user_exclusions.as_array_mut() {
+            user_exclusions.extend(check_names.into_iter().map(Value::String));
+        }
+    }
+
+    rename(settings, "randomize_dungeon_prizes", "dungeon_prize_shuffle");
+    rename(settings, "lorule_castle_requirement", "lc_requirement");
+
+    convert(settings, "nice_mode", "nice_items", |value| {
+        Some(if value.as_bool()? { "Shuffled" } else { "Vanilla" }.into())
+    });
+    // Skip Trials removed the Trials Door outright, so it could be passed from either side
+    convert(settings, "skip_trials", "trials_door", |value| {
+        Some(if value.as_bool()? { "OpenFromBothSides" } else { "AllTrialsRequired" }.into())
+    });
+    convert(settings, "weather_vanes_activated", "weather_vanes", |value| {
+        Some(if value.as_bool()? { "All" } else { "Standard" }.into())
+    });
+
+    // The Pedestal requirement used to be the number of Pendants needed
+    if let Some(Value::Number(number)) = settings.get("ped_requirement") {
+        let converted = match number.as_u64() {
+            Some(2) => Some("Vanilla"),
+            Some(3) => Some("Standard"),
+            _ => None,
+        };
+        if let Some(converted) = converted {
+            settings.insert("ped_requirement".to_owned(), converted.into());
+        }
+    }
+    if settings.get("ped_requirement").and_then(Value::as_str) == Some("Charmed") {
+        warn!("Pedestal Requirement \"Charmed\" no longer exists, as the Charm no longer stands in for a Pendant");
+        warn!("Using \"Standard\" instead, which requires all three Pendants");
+        settings.insert("ped_requirement".to_owned(), "Standard".into());
+    }
+
+    if let Some(pouch_in_shop) = settings.remove("pouch_in_shop") {
+        if pouch_in_shop.as_bool() == Some(true) {
+            warn!("\"pouch_in_shop\" no longer exists, using \"start_with_pouch\" instead");
+            settings.entry("start_with_pouch").or_insert(true.into());
+        }
+    }
+
+    for (name, default) in [
+        ("hyrule_castle_setting", None),
+        ("reverse_sage_events", Some(false.into())),
+        ("vanilla_charm", Some(false.into())),
+    ] {
+        if let Some(value) = settings.remove(name) {
+            if default.as_ref() != Some(&value) {
+                warn!("Dropped \"{}\": {}, which is no longer a setting", name, value);
+            }
+        }
+    }
+    if settings.remove("hint_ghost_price").is_some() {
+        info!("Dropped \"hint_ghost_price\", Hint Ghosts are now free");
+    }
+
+    // Settings added since, whose defaults would change how an older Preset plays
+    if before_v0_4 {
+        if !settings.contains_key("cracks") {
+            info!("Using \"cracks\": \"Open\", as every Crack was open before Quake was added");
+            settings.insert("cracks".to_owned(), "Open".into());
+        }
+        if !settings.contains_key("trials_door") {
+            info!("Using \"trials_door\": \"AllTrialsRequired\", as every Trial was required without Skip Trials");
+            settings.insert("trials_door".to_owned(), "AllTrialsRequired".into());
+        }
+    }
+}
+
+/// Moves a setting that was renamed without changing its values.
+fn rename(settings: &mut Map<String, Value>, old: &str, new: &str) {
+    if let Some(value) = settings.remove(old) {
+        settings.entry(new).or_insert(value);
+    }
+}
+
+/// Replaces a setting with the one that took its place, converting its value. Values that can't be converted are
+/// dropped with a warning, leaving the new setting's default.
+fn convert(settings: &mut Map<String, Value>, old: &str, new: &str, f: impl FnOnce(&Value) -> Option<Value>) {
+    if let Some(value) = settings.remove(old) {
+        match f(&value) {
+            Some(converted) => {
+                settings.entry(new).or_insert(converted);
+            },
+            None => warn!("Dropped \"{}\": {}, which couldn't be converted to \"{}\"", old, value, new),
+        }
+    }
+}
+
+/// Collects every string nested anywhere in `value`.
+fn collect_strings(value: &Value, strings: &mut Vec<String>) {
+    match value {
+        Value::String(string) => strings.push(string.clone()),
+        Value::Array(values) => values.iter().for_each(|value| collect_strings(value, strings)),
+        Value::Object(map) => map.values().for_each(|value| collect_strings(value, strings)),
+        _ => {},
+    }
+}
+
+/// The numbered part of a version like `v0.4.0 - Beta Build 2024-08-15`, and the date of its build if it has one.
+#[derive(Debug, PartialEq)]
+struct BuildVersion {
+    numbers: [u32; 3],
+    build_date: Option<String>,
+}
+
+impl BuildVersion {
+    fn parse(version: &str) -> Option<Self> {
+        let version = version.trim().trim_start_matches('v');
+        let (numbers, rest) = version.split_once(' ').unwrap_or((version, ""));
+
+        let mut parts = numbers.split('.').map(str::parse::<u32>);
+        let numbers = [parts.next()?.ok()?, parts.next().unwrap_or(Ok(0)).ok()?, parts.next().unwrap_or(Ok(0)).ok()?];
+        let build_date = rest.split_whitespace().last().filter(|date| date.contains('-')).map(str::to_owned);
+
+        Some(Self { numbers, build_date })
+    }
+}
+
+impl PartialOrd for BuildVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self.numbers.cmp(&other.numbers), &self.build_date, &other.build_date) {
+            (Ordering::Equal, Some(date), Some(other_date)) => Some(date.cmp(other_date)),
+            (ordering, ..) => Some(ordering),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{migrate_preset, BuildVersion};
+    use crate::constants::VERSION;
+    use modinfo::settings::{Cracks, NiceItems, PedestalSetting, TrialsDoor, WeatherVanes};
+    use modinfo::Settings;
+    use serde_json::json;
+
+    fn migrate(mut preset: serde_json::Value) -> Settings {
+        migrate_preset(&mut preset).unwrap();
+        assert_eq!(preset["version"], VERSION);
+        serde_json::from_value(preset["settings"].clone()).unwrap()
+    }
+
+    #[test]
+    fn it_migrates_grouped_settings() {
+        let settings = migrate(json!({
+            "seed": 0,
+            "version": "0.3.1",
+            "settings": {
+                "dev": {},
+                "logic": {
+                    "logic_mode": "Hard",
+                    "randomize_dungeon_prizes": false,
+                    "ped_requirement": "Charmed",
+                    "hyrule_castle_setting": "EarlyLoruleCastle",
+                    "nice_mode": true,
+                    "skip_trials": true,
+                    "weather_vanes_activated": true,
+                    "pouch_in_shop": true,
+                    "hint_ghost_price": 30
+                },
+                "options": { "night_mode": true },
+                "exclusions": { "Hyrule": { "Eastern Ruins": ["Eastern Ruins Merge Chest"] } }
+            }
+        }));
+
+        assert!(!settings.dungeon_prize_shuffle);
+        assert!(settings.night_mode && settings.start_with_pouch);
+        assert_eq!(settings.ped_requirement, PedestalSetting::Standard);
+        assert_eq!(settings.nice_items, NiceItems::Shuffled);
+        assert_eq!(settings.trials_door, TrialsDoor::OpenFromBothSides);
+        assert_eq!(settings.weather_vanes, WeatherVanes::All);
+        assert_eq!(settings.cracks, Cracks::Open);
+        assert!(settings.user_exclusions.contains("Eastern Ruins Merge Chest"));
+    }
+
+    #[test]
+    fn it_keeps_current_presets() {
+        let settings = migrate(json!({ "version": VERSION, "settings": { "cracks": "Closed" } }));
+        assert_eq!(settings.cracks, Cracks::Closed);
+        assert_eq!(settings.trials_door, TrialsDoor::OneTrialRequired);
+    }
+
+    #[test]
+    fn it_orders_versions() {
+        let current = BuildVersion::parse("v0.4.0 - Beta Build 2024-08-15");
+        assert!(BuildVersion::parse("0.3.1") < current);
+        assert!(BuildVersion::parse("v0.4.0 - Beta Build 2024-01-31") < current);
+        assert!(BuildVersion::parse("v0.4.1") > current);
+        assert_eq!(BuildVersion::parse("nonsense"), None);
+    }
+}
@@ -0,0 +1,249 @@
+use std::{collections::HashMap, hash::BuildHasherDefault, io::Write};
+
+use twox_hash::XxHash64;
+
+use crate::Result;
+
+/// Writes a BPS patch that turns `source` into `target`.
+///
+/// Matches are looked for at every [`STEP`] bytes of the target, against windows taken every [`STRIDE`] bytes of the
+/// source. This finds data that moved by a multiple of [`STEP`], which is how file data shifts when the RomFS is
+/// rebuilt, without indexing every offset of a ROM-sized source.
+///
+/// Both inputs are read at random, so ROM-sized ones are best memory-mapped. Besides them, memory use is the index of
+/// one entry per [`STRIDE`] bytes of the source (tens of MB for a full ROM) and the patch itself, which is buffered to
+/// compute its CRC.
+pub fn write<W>(source: &[u8], target: &[u8], mut out: W) -> Result<()>
+where
+    W: Write,
+{
+    let mut index = HashMap::<u128, usize, BuildHasherDefault<XxHash64>>::default();
+    for offset in (0..source.len().saturating_sub(WINDOW)).step_by(STRIDE) {
+        index.entry(window(source, offset)).or_insert(offset);
+    }
+
+    let mut patch = b"BPS1".to_vec();
+    encode(&mut patch, source.len() as u64);
+    encode(&mut patch, target.len() as u64);
+    encode(&mut patch, 0);
+
+    let mut offset = 0;
+    let mut literal = 0;
+    let mut source_offset = 0;
+    let mut matched = 0;
+    while offset + WINDOW <= target.len() {
+        let key = window(target, offset);
+        // Try the same offset, then carrying on from the last copy as if the bytes in between were replaced
+        let found = [Some(offset), Some(matched + offset - literal), index.get(&key).copied()]
+            .into_iter()
+            .flatten()
+            .filter(|&from| from + WINDOW <= source.len() && window(source, from) == key)
+            .map(|from| {
+                let back = common_len_rev(&source[..from], &target[literal..offset]);
+                (from - back, offset - back, back + common_len(&source[from..], &target[offset..]))
+            })
+            .max_by_key(|&(.., len)| len);
+        match found {
+            Some((from, start, len)) if len >= MIN_MATCH => {
+                target_read(&mut patch, &target[literal..start]);
+                if from == start {
+                    action(&mut patch, SOURCE_READ, len);
+                } else {
+                    action(&mut patch, SOURCE_COPY, len);
+                    let relative = from as i64 - source_offset as i64;
+                    encode(&mut patch, relative.unsigned_abs() << 1 | (relative < 0) as u64);
+                    source_offset = from + len;
+                }
+                matched = from + len;
+                offset = start + len;
+                literal = offset;
+            },
+            _ => offset = (offset + 1).next_multiple_of(STEP),
+        }
+    }
+    target_read(&mut patch, &target[literal..]);
+
+    patch.extend(crc32fast::hash(source).to_le_bytes());
+    patch.extend(crc32fast::hash(target).to_le_bytes());
+    patch.extend(crc32fast::hash(&patch).to_le_bytes());
+    out.write_all(&patch)?;
+    Ok(())
+}
+
+fn target_read(patch: &mut Vec<u8>, data: &[u8]) {
+    if !data.is_empty() {
+        action(patch, TARGET_READ, data.len());
+        patch.extend(data);
+    }
+}
+
+fn action(patch: &mut Vec<u8>, command: u64, len: usize) {
+    encode(patch, (len as u64 - 1) << 2 | command);
+}
+
+fn encode(patch: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let byte = (value & 0x7F) as u8;
+        value >>= 7;
+        if value == 0 {
+            patch.push(0x80 | byte);
+            break;
+        }
+        patch.push(byte);
+        value -= 1;
+    }
+}
+
+fn window(data: &[u8], offset: usize) -> u128 {
+    u128::from_le_bytes(data[offset..offset + WINDOW].try_into().unwrap())
+}
+
+/// Gets the length of the common prefix of two slices.
+fn common_len(a: &[u8], b: &[u8]) -> usize {
+    let max = a.len().min(b.len());
+    let mut len = 0;
+    while len + CHUNK <= max && a[len..len + CHUNK] == b[len..len + CHUNK] {
+        len += CHUNK;
+    }
+    len + a[len..max].iter().zip(&b[len..max]).take_while(|(a, b)| a == b).count()
+}
+
+/// Gets the length of the common suffix of two slices.
+fn common_len_rev(a: &[u8], b: &[u8]) -> usize {
+    a.iter().rev().zip(b.iter().rev()).take_while(|(a, b)| a == b).count()
+}
+
+const SOURCE_READ: u64 = 0;
+const TARGET_READ: u64 = 1;
+const SOURCE_COPY: u64 = 2;
+
+const WINDOW: usize = 16;
+const STEP: usize = 16;
+const STRIDE: usize = 0x200;
+const MIN_MATCH: usize = 32;
+const CHUNK: usize = 0x1000;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Applies a BPS patch to `source`, checking its CRCs along the way.
+    fn apply(source: &[u8], patch: &[u8]) -> Vec<u8> {
+        let crc = |offset: usize| u32::from_le_bytes(patch[offset..offset + 4].try_into().unwrap());
+        let end = patch.len() - 12;
+        assert_eq!(crc(end), crc32fast::hash(source), "source CRC");
+        assert_eq!(crc(end + 8), crc32fast::hash(&patch[..end + 8]), "patch CRC");
+        assert_eq!(&patch[..4], b"BPS1");
+
+        let mut position = 4;
+        let decode = |position: &mut usize| {
+            let (mut value, mut shift) = (0, 1);
+            loop {
+                let byte = patch[*position];
+                *position += 1;
+                value += (byte & 0x7F) as u64 * shift;
+                if byte & 0x80 != 0 {
+                    return value;
+                }
+                shift <<= 7;
+                value += shift;
+            }
+        };
+        let relative = |position: &mut usize| {
+            let data = decode(position);
+            (data >> 1) as i64 * if data & 1 == 1 { -1 } else { 1 }
+        };
+
+        assert_eq!(decode(&mut position) as usize, source.len());
+        let mut target = Vec::with_capacity(decode(&mut position) as usize);
+        position += decode(&mut position) as usize;
+
+        let (mut source_offset, mut target_offset) = (0, 0);
+        while position < end {
+            let data = decode(&mut position);
+            let len = (data >> 2) as usize + 1;
+            match data & 3 {
+                SOURCE_READ => target.extend_from_slice(&source[target.len()..target.len() + len]),
+                TARGET_READ => {
+                    target.extend_from_slice(&patch[position..position + len]);
+                    position += len;
+                },
+                SOURCE_COPY => {
+                    source_offset = (source_offset as i64 + relative(&mut position)) as usize;
+                    target.extend_from_slice(&source[source_offset..source_offset + len]);
+                    source_offset += len;
+                },
+                _ => {
+                    target_offset = (target_offset as i64 + relative(&mut position)) as usize;
+                    for _ in 0..len {
+                        target.push(target[target_offset]);
+                        target_offset += 1;
+                    }
+                },
+            }
+        }
+
+        assert_eq!(position, end);
+        assert_eq!(crc(end + 4), crc32fast::hash(&target), "target CRC");
+        target
+    }
+
+    /// Noise that won't match itself by accident, standing in for ROM data.
+    fn noise(len: usize, seed: u32) -> Vec<u8> {
+        let mut state = seed;
+        (0..len)
+            .map(|_| {
+                state = state.wrapping_mul(1664525).wrapping_add(1013904223);
+                (state >> 24) as u8
+            })
+            .collect()
+    }
+
+    fn patch(source: &[u8], target: &[u8]) -> Vec<u8> {
+        let mut patch = vec![];
+        write(source, target, &mut patch).unwrap();
+        assert_eq!(apply(source, &patch), target);
+        patch
+    }
+
+    #[test]
+    fn it_patches_identical_data_with_source_reads() {
+        let source = noise(0x10000, 1);
+        let patch = patch(&source, &source);
+        assert!(patch.len() < 0x40, "patch was {} bytes", patch.len());
+    }
+
+    #[test]
+    fn it_patches_edits_in_place() {
+        let source = noise(0x10000, 2);
+        let mut target = source.clone();
+        target[0x1234..0x1300].fill(0xAA);
+        target[0x8000] ^= 0xFF;
+        patch(&source, &target);
+    }
+
+    #[test]
+    fn it_copies_shifted_blocks() {
+        let source = noise(0x20000, 3);
+        let mut target = vec![];
+        // Blocks move forward and backward by multiples of the step, as files do when the RomFS is rebuilt
+        target.extend_from_slice(&source[..0x4000]);
+        target.extend_from_slice(&noise(0x30, 4));
+        target.extend_from_slice(&source[0x8000..0xC000]);
+        target.extend_from_slice(&source[0x4000..0x8000]);
+        target.extend_from_slice(&source[0xC010..0x1F000]);
+        target.extend_from_slice(&noise(0x123, 5));
+
+        let patch = patch(&source, &target);
+        assert!(patch.len() < 0x400, "patch was {} bytes", patch.len());
+    }
+
+    #[test]
+    fn it_patches_growing_and_shrinking_data() {
+        let source = noise(0x1000, 6);
+        patch(&source, &source[..0x800]);
+        patch(&source, &[source.as_slice(), &noise(0x1001, 7)].concat());
+        patch(&source, &[]);
+        patch(&[], &source);
+    }
+}
@@ -3,7 +3,6 @@ use crate::patch::Patcher;
 use crate::{patch::util::*, regions, Result, SeedInfo};
 use game::Course::{self, *};
 use log::info;
-use macros::fail;
 use modinfo::settings::cracksanity::Cracksanity;
 use modinfo::settings::keysy::Keysy;
 use modinfo::settings::{Settings, TrialsDoor};
@@ -133,6 +132,7 @@ pub fn patch(patcher: &mut Patcher, seed_info: &SeedInfo) -> Result<()> {
     patch_magic_shop(patcher);
     patch_ice_ruins(patcher);
     patch_npc_hinox(patcher);
+    patch_entrances(patcher, seed_info);
 
     patcher.modify_objs(FieldLight, 18, [disable(529)]);
 
@@ -985,7 +985,7 @@ fn patch_blacksmith_hyrule(patcher: &mut Patcher) {
                     35 => Vec3 { x: 1.00000, y: 2.00000, z: 2.22222 },
                     34 => Vec3 { x: 0.52632, y: 2.00000, z: 1.66667 },
                     _ => {
-                        fail!("PackageSword wasn't a chest")
+                        unreachable!("PackageSword wasn't a chest")
                     },
                 }
             }),
@@ -1338,10 +1338,17 @@ fn patch_keysy_big(patcher: &mut Patcher, settings: &Settings) {
 }
 
 /// Patch the Curtain in Zelda's Study
+///
+/// The Curtain is wired to the Crack leading into Hyrule Castle, which is only its pair when Cracksanity isn't
+/// decoupled.
 fn patch_curtain(patcher: &mut Patcher, seed_info: &SeedInfo) {
-    let crack_paired_with_hc = seed_info.crack_map.get(&Crack::HyruleCastle).unwrap();
+    let (crack_into_hc, _) = seed_info
+        .crack_map
+        .iter()
+        .find(|(_, there)| **there == Crack::HyruleCastle)
+        .expect("No crack_map entry leads to the Hyrule Castle Crack");
 
-    if Crack::LoruleCastle == *crack_paired_with_hc {
+    if Crack::LoruleCastle == *crack_into_hc {
         // Vanilla HC/LC pair - Delete the curtain and no merge zone
         patcher.modify_objs(
             IndoorLight,
@@ -1353,7 +1360,7 @@ fn patch_curtain(patcher: &mut Patcher, seed_info: &SeedInfo) {
         );
     } else {
         // Wire the curtain + no merge zone to the other crack's flag
-        let other_crack_flag = crack_paired_with_hc.get_flag();
+        let other_crack_flag = crack_into_hc.get_flag();
         patcher.modify_objs(
             IndoorLight,
             7,
@@ -1411,6 +1418,25 @@ fn patch_cracksanity(patcher: &mut Patcher) {
     );
 }
 
+/// Entrance Shuffle
+///
+/// Points each door's Loading Zone at the Spawn Point on the far side of its new destination.
+fn patch_entrances(patcher: &mut Patcher, seed_info: &SeedInfo) {
+    for (door, interior) in seed_info.entrance_map.entrances() {
+        let (entry, exit) = (door.get_entry(), interior.get_exit());
+        patcher.modify_objs(
+            entry.get_scene(),
+            entry.get_scene_id(),
+            [redirect(entry.get_unq(), exit.get_spawn_point())],
+        );
+    }
+
+    for (interior, door) in seed_info.entrance_map.exits() {
+        let (exit, entry) = (interior.get_exit(), door.get_entry());
+        patcher.modify_objs(exit.get_scene(), exit.get_scene_id(), [redirect(exit.get_unq(), entry.get_spawn_point())]);
+    }
+}
+
 fn patch_trials_door(patcher: &mut Patcher, settings: &Settings) {
     let door_flag = Flag::Event(421);
 
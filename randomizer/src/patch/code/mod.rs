@@ -15,7 +15,7 @@ use rom::scene::SpawnPoint;
 use rom::ExHeader;
 use std::{
     collections::HashMap,
-    fs::{self, File},
+    fs,
     io::Write,
     path::Path,
 };
@@ -70,12 +70,20 @@ impl Code {
         P: AsRef<Path>,
     {
         let path = path.as_ref();
+        let (exheader, ips) = self.finish(exheader)?;
+        fs::write(path.join("code.ips"), ips)?;
+        fs::write(path.join("exheader.bin"), exheader.as_ref())?;
+        Ok(())
+    }
+
+    /// Gets the extended header, with the code segments grown to fit everything declared in them, and the IPS patch.
+    pub fn finish(self, exheader: &ExHeader) -> Result<(ExHeader, Vec<u8>)> {
         let mut exheader = exheader.clone();
         exheader.set_text_size(self.text - exheader.get_text_address());
         exheader.set_rodata_size(self.rodata - exheader.get_rodata_address());
-        self.ips.write(File::create(path.join("code.ips"))?)?;
-        fs::write(path.join("exheader.bin"), exheader.as_ref())?;
-        Ok(())
+        let mut ips = vec![];
+        self.ips.write(&mut ips)?;
+        Ok((exheader, ips))
     }
 }
 
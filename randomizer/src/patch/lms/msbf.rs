@@ -1,5 +1,5 @@
 use crate::patch::Patcher;
-use crate::{Result, SeedInfo};
+use crate::{Error, Result, SeedInfo};
 use game::Course;
 use log::info;
 use modinfo::settings::keysy::Keysy;
@@ -48,6 +48,8 @@ where
 {
     let course = course.into();
 
+    let region = patcher.game.region();
+    let language_dir = patcher.game.language_dir();
     if let Some(file) = patcher.flow(course)?.get_mut(file_name) {
         if edotor {
             file?.get().edotor(labels.into().expect("No MSBT Message Info provided"));
@@ -55,11 +57,14 @@ where
             file?.get().research();
         }
     } else {
-        macros::fail!(
-            "File not found: US{}.szs -> World/Flow/{}.msbf",
-            if course.is_some() { "_English/".to_owned() + course.unwrap().as_str() } else { "/RegionBoot".to_owned() },
+        return Err(Error::game(format!(
+            "File not found: {}.szs -> World/Flow/{}.msbf",
+            match course {
+                Some(course) => format!("{}/{}", language_dir, course.as_str()),
+                None => format!("{}/RegionBoot", region.boot_dir()),
+            },
             file_name
-        );
+        )));
     };
 
     info!("Finished MSBF Research");
@@ -331,13 +331,24 @@ fn patch_street_merchant(patcher: &mut Patcher, seed_info: &SeedInfo) -> Result<
     let mut street_merchant = load_msbt(patcher, FieldLight, "FieldLight_18")?;
     street_merchant.set(
         "lgt_NpcStand_BottleEmpty_00_select",
-        &format!("That's a {}.\nUseful for a bunch of things.\nHow about {}?{}", name(&item_name_left), *PRICE, *CHOICE_2),
+        &format!(
+            "That's {}{}.\nUseful for a bunch of things.\nHow about {}?{}",
+            article_prefix(item_left),
+            name(&item_name_left),
+            *PRICE,
+            *CHOICE_2
+        ),
     );
 
+    let mut article_right = article_prefix(item_right);
+    if let Some(first) = article_right.get_mut(..1) {
+        first.make_ascii_uppercase();
+    }
     street_merchant.set(
         "lgt_NpcStand_ZoraTreasure_00_select",
         &format!(
-            "Ah, yes! A {}\nof remarkable quality. Smooth as silk!\nAnd for you? Only {}!{}",
+            "Ah, yes! {}{}\nof remarkable quality. Smooth as silk!\nAnd for you? Only {}!{}",
+            article_right,
             name(&item_name_right),
             *PRICE,
             *CHOICE_2
@@ -356,6 +367,16 @@ fn patch_street_merchant(patcher: &mut Patcher, seed_info: &SeedInfo) -> Result<
     Ok(())
 }
 
+/// The article to put in front of an item's name, with a trailing space if there is one, e.g. "an " for an Eastern
+/// Palace Small Key.
+fn article_prefix(item: Randomizable) -> String {
+    match item {
+        Randomizable::Item(item) if item.get_article().is_empty() => String::new(),
+        Randomizable::Item(item) => format!("{} ", item.get_article()),
+        _ => String::from("a "),
+    }
+}
+
 /// Sahasrahla gives out the locations of the Red & Blue Pendants
 fn patch_sahasrahla(patcher: &mut Patcher, seed_info: &SeedInfo) -> Result<()> {
     let (power, _) = seed_info.layout.find_single(PendantOfPower).unwrap();
@@ -394,6 +415,9 @@ fn patch_hint_ghosts(patcher: &mut Patcher, seed_info: &SeedInfo) -> Result<()>
     add_to_msbt_hint_map(&mut msbt_hint_map, &seed_info.hints.maiamai_hints)?;
     add_to_msbt_hint_map(&mut msbt_hint_map, &seed_info.hints.always_hints)?;
     add_to_msbt_hint_map(&mut msbt_hint_map, &seed_info.hints.sometimes_hints)?;
+    add_to_msbt_hint_map(&mut msbt_hint_map, &seed_info.hints.hero_hints)?;
+    add_to_msbt_hint_map(&mut msbt_hint_map, &seed_info.hints.crack_hints)?;
+    add_to_msbt_hint_map(&mut msbt_hint_map, &seed_info.hints.barren_hints)?;
 
     // FIXME extremely dumb. Clear out some unused messages in Lost Woods to keep file size down.
     msbt_hint_map.get_mut(&(FieldLight, "FieldLight_00")).unwrap().extend(BTreeMap::from([
@@ -282,7 +282,7 @@ struct Txt2Block {
 
 /// Load MSBT File
 pub(crate) fn load_msbt(patcher: &mut Patcher, course: Course, file: &str) -> Result<MsbtFile> {
-    let filename = format!("US_English/{}.msbt", file);
+    let filename = format!("{}/{}.msbt", patcher.game.language_dir(), file);
     let mut file = patcher
         .language(course)
         .unwrap()
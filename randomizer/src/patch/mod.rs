@@ -1,5 +1,6 @@
 use crate::filler::cracks::Crack;
 use crate::filler::filler_item::{Randomizable, Vane};
+use crate::system::{OutputMode, UserConfig};
 use crate::{patch::util::*, Error, Result, SeedInfo};
 use code::Code;
 use fs_extra::dir::CopyOptions;
@@ -7,8 +8,8 @@ use game::{
     Course::{self as CourseId, *},
     Item, World,
 };
-use log::{debug, error, info};
-use macros::fail;
+use log::{debug, info};
+use memmap2::Mmap;
 use modinfo::settings::weather_vanes::WeatherVanes::*;
 use path_absolutize::*;
 use rom::byaml::scene_env::SceneEnvFile;
@@ -17,15 +18,23 @@ use rom::scene::{Transform, Vec3};
 use rom::{
     flow::FlowMut,
     scene::{Arg, Obj, Rail, SceneMeta},
-    File, IntoBytes, Language, Rom, Scene,
+    File, IntoBytes, Language, Region, Rom, Scene,
 };
 use serde::Serialize;
 use std::ops::Add;
-use std::{collections::HashMap, fs, path::Path};
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs,
+    io::{self, BufWriter, Seek, Write},
+    path::Path,
+};
 use tempfile::tempdir;
 use try_insert_ext::EntryInsertExt;
+use zip::{write::FileOptions, CompressionMethod, ZipWriter};
 
 mod actors;
+mod bps;
 mod byaml;
 mod code;
 mod demo;
@@ -59,6 +68,8 @@ pub struct Patcher {
 
 impl Patcher {
     pub fn new(game: Rom) -> Result<Self> {
+        Self::validate_region(game.region())?;
+
         let boot = game.boot()?;
         Ok(Self {
             game,
@@ -69,6 +80,19 @@ impl Patcher {
         })
     }
 
+    /// The code patches are written against the US build's addresses, which don't line up with other regions.
+    pub(crate) fn validate_region(region: Region) -> Result<()> {
+        if region != Region::US {
+            return Err(Error::rom(format!(
+                "{} ROMs can be loaded, but the code patches haven't been ported to them yet. Please use a US ROM.\n\
+                Use --no-patch to generate this seed's spoiler log only.",
+                region
+            )));
+        }
+
+        Ok(())
+    }
+
     fn add_obj(&mut self, id: CourseId, stage_index: u16, obj: Obj) {
         self.scene(id, stage_index - 1).unwrap().stage_mut().get_mut().add_obj(obj);
     }
@@ -381,10 +405,8 @@ impl Patcher {
             here_crack.get_type()
         };
 
-        // Enable Flag - if it's the HC Crack or its pair leave it open, else use the Quake Flag.
-        let enable_flag = if here_crack == Crack::HyruleCastle
-            || here_crack == *seed_info.crack_map.get(&Crack::HyruleCastle).unwrap()
-        {
+        // Enable Flag - if it's the HC Crack or the Crack leading into it leave it open, else use the Quake Flag.
+        let enable_flag = if here_crack == Crack::HyruleCastle || *there_crack == Crack::HyruleCastle {
             Flag::ZERO_ZERO
         } else {
             Flag::QUAKE
@@ -723,35 +745,117 @@ pub struct Patches {
 }
 
 impl Patches {
-    pub fn dump<P>(self, path: P) -> Result<()>
-    where
-        P: AsRef<Path>,
-    {
+    pub fn dump(self, user_config: &UserConfig, seed: u32) -> Result<()> {
+        let path = user_config.output();
+        let name = format!("{:0>10}_{:016X}", seed, self.game.id());
+        info!("");
+
+        match user_config.output_mode() {
+            mode @ (OutputMode::LayeredFs | OutputMode::Zip) => self.dump_layered_fs(path, &name, mode),
+            OutputMode::Image => {
+                let image = path.join(format!("{}.3ds", name));
+                info!("Writing Patched ROM to:         {}", &image.absolutize()?.display());
+                self.rebuild(create(&image)?)
+            },
+            OutputMode::Bps => {
+                // The patch is made against the patched ROM, which only needs to exist until it's been diffed
+                let temp = tempdir()?;
+                let image = temp.path().join(format!("{}.3ds", name));
+                let source = map(self.game.path())?;
+                info!("Rebuilding Patched ROM...");
+                self.rebuild(BufWriter::new(fs::File::create(&image)?))?;
+                let target = map(&image)?;
+                let patch = path.join(format!("{}.bps", name));
+                info!("Writing BPS Patch to:           {}", &patch.absolutize()?.display());
+                bps::write(&source, &target, create(&patch)?)
+            },
+        }
+    }
+
+    /// Writes the `<Title ID>/` folder of loose files for LayeredFS, either as is or packed into a zip.
+    fn dump_layered_fs(self, path: &Path, name: &str, mode: OutputMode) -> Result<()> {
         let temp = tempdir()?;
-        let moddir = temp.path().join(format!("{:016X}", self.game.id()));
+        let title = format!("{:016X}", self.game.id());
+        let moddir = temp.path().join(&title);
         let romfs = moddir.join("romfs");
         fs::create_dir_all(&romfs)?;
         self.code.dump(&moddir, self.game.exheader())?;
         for file in self.romfs.0 {
             file.dump(&romfs)?;
         }
-        let path = path.as_ref();
-        info!("");
-        info!("Writing Patch Files to:         {}\\{:016X}", &path.absolutize()?.display(), self.game.id());
 
-        match fs_extra::copy_items(&[moddir], path, &CopyOptions { overwrite: true, ..Default::default() })
-            .map_err(Error::io)
-        {
-            Ok(_) => Ok(()),
-            Err(_) => {
-                error!("Couldn't write to:              {}", path.display());
-                error!("Please check that config.json points to a valid output destination.");
-                fail!();
-            },
-        }
+        let written = if mode == OutputMode::Zip {
+            let zip = path.join(format!("{}.zip", name));
+            info!("Writing Patch Zip to:           {}", &zip.absolutize()?.display());
+            fs::create_dir_all(path).map_err(Error::from).and_then(|_| write_zip(temp.path(), &moddir, &zip))
+        } else {
+            info!("Writing Patch Files to:         {}\\{}", &path.absolutize()?.display(), title);
+            fs_extra::copy_items(&[moddir], path, &CopyOptions { overwrite: true, ..Default::default() })
+                .map(|_| ())
+                .map_err(Error::io)
+        };
+
+        written.map_err(|err| unwritable(path, err))
+    }
+
+    /// Writes a complete ROM image with the patches applied.
+    fn rebuild<W>(self, out: W) -> Result<()>
+    where
+        W: Write + Seek,
+    {
+        let (exheader, ips) = self.code.finish(self.game.exheader())?;
+        self.game.rebuild(&exheader, &ips, self.romfs.0, out)?;
+        Ok(())
     }
 }
 
+/// Creates an output file, failing with a pointer to config.json if it can't be.
+fn create(file: &Path) -> Result<BufWriter<fs::File>> {
+    fs::File::create(file).map(BufWriter::new).map_err(|err| unwritable(file, err))
+}
+
+fn unwritable<E>(path: &Path, err: E) -> Error
+where
+    E: Display,
+{
+    Error::io(format!(
+        "Couldn't write to: {}\n\
+        Please check that config.json points to a valid output destination.\n\
+        Full Error: {}",
+        path.display(),
+        err
+    ))
+}
+
+/// Maps a ROM-sized file into memory, so it's paged in by the OS as it's read rather than all loaded up front.
+fn map(path: &Path) -> Result<Mmap> {
+    let file = fs::File::open(path)?;
+    // SAFETY: Nothing else writes to the ROMs while they're diffed, the patched one only exists in our temp directory
+    Ok(unsafe { Mmap::map(&file)? })
+}
+
+/// Packs the files under `dir` into a zip at `zip`, named by their paths relative to `root`.
+fn write_zip(root: &Path, dir: &Path, zip: &Path) -> Result<()> {
+    let mut writer = ZipWriter::new(fs::File::create(zip)?);
+    let options = FileOptions::default().compression_method(CompressionMethod::Deflated);
+    let mut files = fs_extra::dir::get_dir_content(dir).map_err(Error::io)?.files;
+    files.sort();
+    for file in files {
+        let file = Path::new(&file);
+        let name = file
+            .strip_prefix(root)
+            .map_err(Error::internal)?
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        writer.start_file(name, options).map_err(Error::io)?;
+        io::copy(&mut fs::File::open(file)?, &mut writer)?;
+    }
+    writer.finish().map_err(Error::io)?;
+    Ok(())
+}
+
 #[derive(Debug)]
 struct Files(Vec<File<Box<[u8]>>>);
 
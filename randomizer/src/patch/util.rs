@@ -5,7 +5,6 @@ use crate::filler::filler_item;
 use crate::filler::filler_item::Randomizable;
 use crate::filler::filler_item::Randomizable::Item;
 use crate::{regions, Layout};
-use macros::fail;
 use rom::flag::Flag;
 use rom::scene::{Obj, Rail, SpawnPoint, Vec3};
 
@@ -112,7 +111,7 @@ pub(crate) fn prize_flag(prize: Randomizable) -> Flag {
         Item(SageRosso) => Flag::SAGE_ROSSO,
         Item(SageIrene) => Flag::SAGE_IRENE,
         Item(SageImpa) => Flag::SAGE_IMPA,
-        prize => fail!("{} is not a Dungeon Prize", prize.as_str()),
+        prize => unreachable!("{} is not a Dungeon Prize", prize.as_str()),
     }
 }
 
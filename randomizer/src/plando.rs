@@ -0,0 +1,218 @@
+use crate::filler::cracks::{self, Crack};
+use crate::filler::filler_item::{Item, Vane};
+use crate::filler::{entrances, item_pools, text, treacherous_tower, trials, vanes};
+use crate::system::UserConfig;
+use crate::{
+    constants::{SPOILER_SCHEMA_VERSION, VERSION},
+    filler, hints, metrics, patch_seed, validate_settings, world, CheckMap, CrackMap, Result, SeedHash, SeedInfo,
+    VaneMap,
+};
+use log::info;
+use modinfo::{settings_string, Settings};
+use rand::{rngs::StdRng, SeedableRng};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+/// A hand-crafted Seed, typically loaded from a JSON file.
+///
+/// Only the `settings` are required. Any checks without a placement are filled by the standard filler algorithm, and
+/// a missing `crack_map` or `weather_vane_map` will be randomized according to the `settings` as usual. Entrances are
+/// always shuffled according to the `settings`.
+///
+/// Items, Cracks, and Weather Vanes are identified by their variant names, e.g. `"Bow01"` or `"YourHouseWV"`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Plando {
+    pub seed: u32,
+    pub version: String,
+    pub settings: Settings,
+    pub placements: BTreeMap<String, Item>,
+    pub crack_map: Option<CrackMap>,
+    #[serde(rename = "weather_vane_map")]
+    pub vane_map: Option<VaneMap>,
+}
+
+/// Main entry point to generate one Plandomizer Seed.
+pub fn generate_plando(plando: Plando, user_config: &UserConfig, no_patch: bool, no_spoiler: bool) -> Result<()> {
+    validate_settings(&plando.settings)?;
+
+    let rng = &mut StdRng::seed_from_u64(plando.seed as u64);
+
+    let hash = SeedHash::new(plando.seed, &plando.settings);
+
+    info!("Hash:                           {}", hash.text_hash);
+    info!("Settings String:                {}", settings_string::encode(plando.seed, &plando.settings));
+
+    let seed_info = &calculate_seed_info(plando, hash, rng)?;
+    patch_seed(seed_info, user_config, no_patch, no_spoiler)?;
+
+    Ok(())
+}
+
+fn calculate_seed_info(plando: Plando, hash: SeedHash, rng: &mut StdRng) -> Result<SeedInfo> {
+    println!();
+    info!("Calculating Plando Seed Info...");
+
+    let (mut seed_info, mut check_map) = fill_seed_info(&plando, hash, rng)?;
+
+    // Post-analysis: Metrics and Hints
+    metrics::calculate_metrics(&mut seed_info, &mut check_map)?;
+    hints::generate_hints(rng, &mut seed_info, &mut check_map)?;
+
+    Ok(seed_info)
+}
+
+/// Builds the Plando's [`SeedInfo`] and fills every check without a placement, returning the filled Check Map.
+pub(crate) fn fill_seed_info(plando: &Plando, hash: SeedHash, rng: &mut StdRng) -> Result<(SeedInfo, CheckMap)> {
+    let mut seed_info = build_seed_info(plando, hash, rng)?;
+
+    // Check Map and Item Pools
+    let mut check_map = filler::prefill_check_map(&mut seed_info.world_graph);
+
+    // Filler Algorithm, working around the Plando's placements
+    filler::fill_plando(rng, &mut seed_info, &mut check_map, &plando.placements)?;
+
+    Ok((seed_info, check_map))
+}
+
+/// Builds everything about the Plando's [`SeedInfo`] except its item layout.
+///
+/// Randomized parts are drawn from the `rng` in a fixed order, so the same Plando always builds the same [`SeedInfo`].
+pub(crate) fn build_seed_info(plando: &Plando, hash: SeedHash, rng: &mut StdRng) -> Result<SeedInfo> {
+    let Plando { seed, settings, crack_map, vane_map, .. } = plando;
+
+    let crack_map = match crack_map {
+        Some(crack_map) => validate_crack_map(crack_map.clone())?,
+        None => cracks::build_crack_map(settings, rng)?,
+    };
+    let vane_map = match vane_map {
+        Some(vane_map) => validate_vane_map(vane_map.clone())?,
+        None => vanes::build_vanes_map(settings, rng)?,
+    };
+    let entrance_map = entrances::build_entrance_map(settings, rng)?;
+    let text = text::generate(rng)?;
+    let trials_config = trials::configure(rng, settings)?;
+    let treacherous_tower_floors = treacherous_tower::choose_floors(settings, rng)?;
+    let world_graph = world::build_world_graph(settings, &crack_map, &entrance_map)?;
+
+    Ok(SeedInfo {
+        seed: *seed,
+        version: VERSION.to_owned(),
+        schema_version: SPOILER_SCHEMA_VERSION,
+        hash,
+        settings_string: settings_string::encode(*seed, settings),
+        archipelago_info: None,
+        settings: settings.clone(),
+        full_exclusions: Default::default(),
+        vane_map,
+        crack_map,
+        entrance_map,
+        layout: Default::default(),
+        placements: Default::default(),
+        metrics: Default::default(),
+        hints: Default::default(),
+        trials_config,
+        world_graph,
+        text,
+        treacherous_tower_floors,
+    })
+}
+
+/// Makes sure every Crack has a destination, otherwise the World Graph can't be built.
+fn validate_crack_map(crack_map: CrackMap) -> Result<CrackMap> {
+    let all_cracks = [
+        item_pools::get_hyrule_up_cracks(),
+        item_pools::get_hyrule_down_cracks(),
+        item_pools::get_lorule_up_cracks(),
+        item_pools::get_lorule_down_cracks(),
+    ]
+    .concat();
+
+    let missing = all_cracks.iter().filter(|crack| !crack_map.contains_key(crack)).collect::<Vec<&Crack>>();
+    if missing.is_empty() {
+        Ok(crack_map)
+    } else {
+        Err(crate::Error::game(format!("Plando crack_map is missing destinations for: {:?}", missing)))
+    }
+}
+
+/// Makes sure every Weather Vane unlocks something.
+fn validate_vane_map(vane_map: VaneMap) -> Result<VaneMap> {
+    let missing =
+        item_pools::get_weather_vanes().into_iter().filter(|vane| !vane_map.contains_key(vane)).collect::<Vec<Vane>>();
+    if missing.is_empty() {
+        Ok(vane_map)
+    } else {
+        Err(crate::Error::game(format!("Plando weather_vane_map is missing destinations for: {:?}", missing)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fill_seed_info, Plando};
+    use crate::constants::VERSION;
+    use crate::filler::filler_item::Item;
+    use crate::system::System;
+    use crate::{ErrorKind, Result, SeedHash};
+    use modinfo::settings::NiceItems;
+    use rand::{rngs::StdRng, SeedableRng};
+    use serde_json::json;
+    use std::path::Path;
+
+    fn plando(plando: serde_json::Value) -> Plando {
+        serde_json::from_value(plando).unwrap()
+    }
+
+    fn fill(plando: &Plando) -> Result<()> {
+        let rng = &mut StdRng::seed_from_u64(plando.seed as u64);
+        fill_seed_info(plando, SeedHash::new(plando.seed, &plando.settings), rng)?;
+        Ok(())
+    }
+
+    #[test]
+    fn it_loads_the_example_plando() {
+        let plando = System::load_plando(Path::new("../presets/plando/Example.json")).unwrap();
+        assert_eq!(plando.placements.get("Ravio's Gift"), Some(&Item::RaviosBracelet01));
+        assert!(plando.vane_map.is_some());
+        fill(&plando).unwrap();
+    }
+
+    #[test]
+    fn it_migrates_plandos_from_other_versions() {
+        let file = std::env::temp_dir().join("albwr_plando_without_version.json");
+        std::fs::write(&file, json!({ "settings": { "logic": { "nice_mode": true } } }).to_string()).unwrap();
+        let plando = System::load_plando(&file);
+        std::fs::remove_file(&file).unwrap();
+
+        let plando = plando.unwrap();
+        assert_eq!(plando.version, VERSION);
+        assert_eq!(plando.settings.nice_items, NiceItems::Shuffled);
+    }
+
+    #[test]
+    fn it_rejects_unknown_checks() {
+        let plando = plando(json!({ "settings": {}, "placements": { "Ravio's Shop (10)": "Bow01" } }));
+        let err = fill(&plando).unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::Game));
+        assert!(err.to_string().contains("unknown check"));
+    }
+
+    #[test]
+    fn it_rejects_checks_that_cant_hold_items() {
+        let plando = plando(json!({ "settings": {}, "placements": { "Ravio's Signs": "Bow01" } }));
+        let err = fill(&plando).unwrap_err();
+        assert!(err.to_string().contains("cannot place an item"));
+    }
+
+    #[test]
+    fn it_rejects_incomplete_crack_maps() {
+        let plando = plando(json!({ "settings": {}, "crack_map": { "YourHouse": "YourHouse" } }));
+        let err = fill(&plando).unwrap_err();
+        assert!(err.to_string().contains("crack_map is missing destinations"));
+    }
+
+    #[test]
+    fn it_rejects_unknown_fields() {
+        assert!(serde_json::from_value::<Plando>(json!({ "settings": {}, "placement": {} })).is_err());
+    }
+}
@@ -136,6 +136,44 @@ pub(crate) fn patch(patcher: &mut Patcher, seed_info: &SeedInfo) -> crate::Resul
     Ok(())
 }
 
+/// Looks up a [`Subregion`] by its module path, e.g. `"hyrule::lake::hylia"`.
+pub(crate) fn subregion(path: &str) -> Option<&'static Subregion> {
+    Some(match path {
+        "dungeons::dark::palace" => dungeons::dark::palace::SUBREGION,
+        "dungeons::desert::palace" => dungeons::desert::palace::SUBREGION,
+        "dungeons::eastern::palace" => dungeons::eastern::palace::SUBREGION,
+        "dungeons::house::gales" => dungeons::house::gales::SUBREGION,
+        "dungeons::hyrule::castle" => dungeons::hyrule::castle::SUBREGION,
+        "dungeons::ice::ruins" => dungeons::ice::ruins::SUBREGION,
+        "dungeons::lorule::castle" => dungeons::lorule::castle::SUBREGION,
+        "dungeons::skull::woods" => dungeons::skull::woods::SUBREGION,
+        "dungeons::swamp::palace" => dungeons::swamp::palace::SUBREGION,
+        "dungeons::thieves::hideout" => dungeons::thieves::hideout::SUBREGION,
+        "dungeons::tower::hera" => dungeons::tower::hera::SUBREGION,
+        "dungeons::turtle::rock" => dungeons::turtle::rock::SUBREGION,
+        "hyrule::death::mountain" => hyrule::death::mountain::SUBREGION,
+        "hyrule::desert::mystery" => hyrule::desert::mystery::SUBREGION,
+        "hyrule::eastern::ruins" => hyrule::eastern::ruins::SUBREGION,
+        "hyrule::field::main" => hyrule::field::main::SUBREGION,
+        "hyrule::irene::witch" => hyrule::irene::witch::SUBREGION,
+        "hyrule::kakariko::village" => hyrule::kakariko::village::SUBREGION,
+        "hyrule::lake::hylia" => hyrule::lake::hylia::SUBREGION,
+        "hyrule::lake::cave" => hyrule::lake::cave::SUBREGION,
+        "hyrule::lost::woods" => hyrule::lost::woods::SUBREGION,
+        "hyrule::ravio::shop" => hyrule::ravio::shop::SUBREGION,
+        "hyrule::river::area" => hyrule::river::area::SUBREGION,
+        "hyrule::southern::ruins" => hyrule::southern::ruins::SUBREGION,
+        "lorule::dark::ruins" => lorule::dark::ruins::SUBREGION,
+        "lorule::death::mountain" => lorule::death::mountain::SUBREGION,
+        "lorule::field::main" => lorule::field::main::SUBREGION,
+        "lorule::graveyard::lorule" => lorule::graveyard::lorule::SUBREGION,
+        "lorule::lake::lorule" => lorule::lake::lorule::SUBREGION,
+        "lorule::misery::mire" => lorule::misery::mire::SUBREGION,
+        "lorule::skull::overworld" => lorule::skull::overworld::SUBREGION,
+        _ => return None,
+    })
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! region {
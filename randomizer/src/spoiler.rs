@@ -0,0 +1,366 @@
+//! Reading Spoiler Logs back into a [`SeedInfo`].
+
+use crate::constants::SPOILER_SCHEMA_VERSION;
+use crate::filler::cracks::Crack;
+use crate::filler::entrances::EntranceMap;
+use crate::filler::filler_item::{Goal, Item, Randomizable, Vane};
+use crate::filler::loading_zone_pair::LoadingZoneId;
+use crate::filler::tower_stage::TowerStage;
+use crate::filler::trials::TrialsConfig;
+use crate::hints::{self, hint_ghost_name, BarrenHint, CrackHint, HeroHint, Hints, LocationHint, PathHint};
+use crate::regions::Subregion;
+use crate::{
+    filler, metrics, world, ArchipelagoInfo, CheckMap, CrackMap, Error, Result, SeedHash, SeedInfo, Text, VaneMap,
+};
+use game::ghosts::HintGhost;
+use log::warn;
+use modinfo::{settings_string, Settings};
+use serde::Deserialize;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use strum::IntoEnumIterator;
+
+/// The serialized form of a [`SeedInfo`], as written to the Spoiler Log.
+///
+/// Items are read from `placements` by their identifiers, e.g. `"Sword02"`. Cracks, Weather Vanes, Entrances, and Hint
+/// Ghosts are identified by the same names the Spoiler Log uses to display them.
+/// Anything left out keeps its default, which is how Presets (only a `seed`, `version`, and `settings`) get loaded.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub(crate) struct SpoilerLog {
+    seed: u32,
+    version: String,
+    schema_version: u32,
+    hash: Option<SeedHash>,
+    archipelago_info: Option<ArchipelagoInfo>,
+    settings: Settings,
+    full_exclusions: BTreeSet<String>,
+    treacherous_tower_floors: Vec<TowerStage>,
+    trials_config: TrialsConfig,
+    layout: BTreeMap<String, BTreeMap<String, BTreeMap<String, String>>>,
+    placements: BTreeMap<String, Item>,
+    crack_map: BTreeMap<String, String>,
+    weather_vane_map: BTreeMap<String, String>,
+    entrance_map: SpoilerEntranceMap,
+    text: Text,
+    hints: SpoilerHints,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct SpoilerEntranceMap {
+    entrances: BTreeMap<String, String>,
+    exits: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct SpoilerHints {
+    path_hints: Vec<SpoilerHint>,
+    always_hints: Vec<SpoilerHint>,
+    maiamai_hints: Vec<SpoilerHint>,
+    sometimes_hints: Vec<SpoilerHint>,
+    hero_hints: Vec<SpoilerHint>,
+    crack_hints: Vec<SpoilerHint>,
+    barren_hints: Vec<SpoilerHint>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SpoilerHint {
+    #[serde(default, alias = "path_item_location")]
+    location: Option<String>,
+    #[serde(default)]
+    goal: Option<String>,
+    #[serde(default)]
+    crack: Option<String>,
+    #[serde(default)]
+    region: Option<String>,
+    #[serde(default)]
+    count: usize,
+    ghosts: Vec<String>,
+}
+
+impl TryFrom<SpoilerLog> for SeedInfo {
+    type Error = Error;
+
+    fn try_from(spoiler: SpoilerLog) -> Result<Self> {
+        if spoiler.schema_version > SPOILER_SCHEMA_VERSION {
+            return Err(Error::game(format!(
+                "Spoiler Log schema version {} is newer than the supported version {}",
+                spoiler.schema_version, SPOILER_SCHEMA_VERSION
+            )));
+        }
+
+        let crack_map = spoiler
+            .crack_map
+            .iter()
+            .map(|(crack, destination)| Ok((crack_from_name(crack)?, crack_from_name(destination)?)))
+            .collect::<Result<CrackMap>>()?;
+        let vane_map = spoiler
+            .weather_vane_map
+            .iter()
+            .map(|(vane, destination)| Ok((vane_from_name(vane)?, vane_from_name(destination)?)))
+            .collect::<Result<VaneMap>>()?;
+        let entrance_map = EntranceMap::new(
+            read_entrances(&spoiler.entrance_map.entrances)?,
+            read_entrances(&spoiler.entrance_map.exits)?,
+        );
+
+        let mut seed_info = SeedInfo {
+            seed: spoiler.seed,
+            hash: spoiler.hash.unwrap_or_else(|| SeedHash::new(spoiler.seed, &spoiler.settings)),
+            settings_string: settings_string::encode(spoiler.seed, &spoiler.settings),
+            version: spoiler.version,
+            archipelago_info: spoiler.archipelago_info,
+            settings: spoiler.settings,
+            full_exclusions: spoiler.full_exclusions,
+            treacherous_tower_floors: spoiler.treacherous_tower_floors,
+            trials_config: spoiler.trials_config,
+            crack_map,
+            vane_map,
+            entrance_map,
+            ..Default::default()
+        };
+
+        // Nothing more to read from a Preset
+        if spoiler.layout.is_empty() && spoiler.placements.is_empty() {
+            return Ok(seed_info);
+        }
+
+        seed_info.world_graph =
+            world::build_world_graph(&seed_info.settings, &seed_info.crack_map, &seed_info.entrance_map)?;
+        let check_map = &mut if spoiler.schema_version < 2 {
+            warn!("Spoiler Log predates schema version 2, items sharing a name may not be read back where they were.");
+            read_layout_names(&mut seed_info, &spoiler.layout)?
+        } else {
+            read_placements(&mut seed_info, &spoiler.placements)?
+        };
+        filler::build_layout(&mut seed_info, check_map)?;
+        metrics::calculate_metrics(&mut seed_info, check_map)?;
+
+        if spoiler.schema_version < 1 {
+            warn!("Spoiler Log predates schema version 1, its Hints and Text were not read.");
+        } else {
+            seed_info.text = spoiler.text;
+            seed_info.hints = read_hints(&seed_info, check_map, spoiler.hints)?;
+        }
+
+        Ok(seed_info)
+    }
+}
+
+/// Fills a [`CheckMap`] with the items in the Spoiler Log's `placements`.
+fn read_placements(seed_info: &mut SeedInfo, placements: &BTreeMap<String, Item>) -> Result<CheckMap> {
+    let mut check_map = prefill_check_map(seed_info);
+
+    for (check_name, &item) in placements {
+        match check_map.get(check_name) {
+            None => return Err(Error::game(format!("Spoiler Log has unknown check: \"{}\"", check_name))),
+            Some(Some(_)) => continue, // Cracks, Weather Vanes, etc. are placed from their own maps
+            Some(None) => check_map.insert(check_name.to_owned(), Some(item.into())),
+        };
+    }
+
+    check_filled(seed_info, check_map)
+}
+
+/// Fills a [`CheckMap`] with the items named in the `layout` of a Spoiler Log from before `placements` were written.
+fn read_layout_names(
+    seed_info: &mut SeedInfo, layout: &BTreeMap<String, BTreeMap<String, BTreeMap<String, String>>>,
+) -> Result<CheckMap> {
+    let mut check_map = prefill_check_map(seed_info);
+
+    // Let in-logic checks claim shared item names first, e.g. so the logically relevant Golden Bee isn't handed to one
+    // of the out-of-logic 9,999 Rupee shop slots.
+    let mut placements = layout.values().flat_map(BTreeMap::values).flatten().collect::<Vec<_>>();
+    placements.sort_by_key(|(check_name, _)| {
+        seed_info.world_graph.get_check(check_name).is_some_and(|check| check.get_logic().is_empty())
+    });
+
+    let mut used_items = HashSet::new();
+    for (check_name, item_name) in placements {
+        match check_map.get(check_name) {
+            None => return Err(Error::game(format!("Spoiler Log has unknown check: \"{}\"", check_name))),
+            Some(Some(_)) => continue, // Cracks, Weather Vanes, etc. are placed from their own maps
+            Some(None) => {
+                let item = item_from_name(item_name, &mut used_items)?;
+                check_map.insert(check_name.to_owned(), Some(item.into()));
+            },
+        }
+    }
+
+    check_filled(seed_info, check_map)
+}
+
+fn prefill_check_map(seed_info: &mut SeedInfo) -> CheckMap {
+    let mut check_map = filler::prefill_check_map(&mut seed_info.world_graph);
+    filler::place_cracks(seed_info, &mut check_map);
+    filler::place_weather_vanes(seed_info, &mut check_map);
+    check_map
+}
+
+/// Makes sure the Spoiler Log placed an item at every check.
+fn check_filled(seed_info: &SeedInfo, check_map: CheckMap) -> Result<CheckMap> {
+    for check in seed_info.world_graph.values().flat_map(|node| node.get_checks().iter().flatten()) {
+        if check.get_location_info().is_some() && check_map.get(check.get_name()) == Some(&None) {
+            return Err(Error::game(format!("Spoiler Log has no item at check: \"{}\"", check.get_name())));
+        }
+    }
+
+    Ok(check_map)
+}
+
+/// Rebuilds the in-game Hints from the checks and ghosts recorded in the Spoiler Log.
+fn read_hints(seed_info: &SeedInfo, check_map: &mut CheckMap, spoiler_hints: SpoilerHints) -> Result<Hints> {
+    let location_hints = |spoiler_hints: Vec<SpoilerHint>| {
+        spoiler_hints
+            .into_iter()
+            .map(|hint| {
+                let (check, item) = read_hint_location(seed_info, check_map, &hint)?;
+                let Randomizable::Item(item) = item else {
+                    return Err(Error::game(format!("Spoiler Log hints a non-item at: \"{}\"", check.get_name())));
+                };
+                Ok(LocationHint { item, check, logical_ghosts: vec![], ghosts: read_ghosts(&hint.ghosts)? })
+            })
+            .collect::<Result<Vec<_>>>()
+    };
+
+    let path_hints = spoiler_hints
+        .path_hints
+        .into_iter()
+        .map(|hint| {
+            let (check, path_item) = read_hint_location(seed_info, check_map, &hint)?;
+            let goal = goal_from_name(hint.goal.as_deref().unwrap_or_default())?;
+            Ok(PathHint { check, goal, logical_ghosts: vec![], ghosts: read_ghosts(&hint.ghosts)?, path_item })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let always_hints = location_hints(spoiler_hints.always_hints)?;
+    let maiamai_hints = location_hints(spoiler_hints.maiamai_hints)?;
+    let sometimes_hints = location_hints(spoiler_hints.sometimes_hints)?;
+    let hero_hints = spoiler_hints
+        .hero_hints
+        .into_iter()
+        .map(|hint| {
+            let subregion = read_hint_region(seed_info, hint.region.as_deref().unwrap_or_default())?;
+            Ok(HeroHint { subregion, count: hint.count, logical_ghosts: vec![], ghosts: read_ghosts(&hint.ghosts)? })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let crack_hints = spoiler_hints
+        .crack_hints
+        .into_iter()
+        .map(|hint| {
+            let crack = crack_from_name(hint.crack.as_deref().unwrap_or_default())?;
+            Ok(CrackHint { ghosts: read_ghosts(&hint.ghosts)?, ..CrackHint::new(crack, &seed_info.crack_map) })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let barren_hints = spoiler_hints
+        .barren_hints
+        .into_iter()
+        .map(|hint| {
+            let subregion = read_hint_region(seed_info, hint.region.as_deref().unwrap_or_default())?;
+            Ok(BarrenHint { subregion, ghosts: read_ghosts(&hint.ghosts)? })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let bow_of_light_hint = hints::generate_bow_of_light_hint(seed_info, check_map);
+
+    Ok(Hints {
+        path_hints,
+        always_hints,
+        maiamai_hints,
+        sometimes_hints,
+        hero_hints,
+        crack_hints,
+        barren_hints,
+        bow_of_light_hint,
+    })
+}
+
+fn read_hint_location(
+    SeedInfo { world_graph, .. }: &SeedInfo, check_map: &CheckMap, hint: &SpoilerHint,
+) -> Result<(crate::filler::check::Check, Randomizable)> {
+    let check_name = hint.location.as_deref().unwrap_or_default();
+    match (world_graph.get_check(check_name), check_map.get(check_name)) {
+        (Some(check), Some(Some(item))) => Ok((check.clone(), *item)),
+        _ => Err(Error::game(format!("Spoiler Log hints an unknown check: \"{}\"", check_name))),
+    }
+}
+
+fn read_hint_region(SeedInfo { world_graph, .. }: &SeedInfo, name: &str) -> Result<&'static Subregion> {
+    world_graph
+        .values()
+        .flat_map(|node| node.get_checks().iter().flatten())
+        .filter_map(|check| check.get_location_info())
+        .find(|location_info| location_info.region() == name)
+        .map(|location_info| location_info.subregion())
+        .ok_or_else(|| Error::game(format!("Spoiler Log hints an unknown region: \"{}\"", name)))
+}
+
+fn read_ghosts(names: &[String]) -> Result<Vec<HintGhost>> {
+    names
+        .iter()
+        .map(|name| {
+            HintGhost::iter()
+                .find(|ghost| hint_ghost_name(ghost) == name)
+                .ok_or_else(|| Error::game(format!("Spoiler Log has unknown Hint Ghost: \"{}\"", name)))
+        })
+        .collect()
+}
+
+fn crack_from_name(name: &str) -> Result<Crack> {
+    Crack::iter()
+        .find(|crack| crack.as_str() == name)
+        .ok_or_else(|| Error::game(format!("Spoiler Log has unknown Crack: \"{}\"", name)))
+}
+
+fn vane_from_name(name: &str) -> Result<Vane> {
+    Vane::iter()
+        .find(|vane| vane.as_str() == name)
+        .ok_or_else(|| Error::game(format!("Spoiler Log has unknown Weather Vane: \"{}\"", name)))
+}
+
+fn read_entrances(entrances: &BTreeMap<String, String>) -> Result<BTreeMap<LoadingZoneId, LoadingZoneId>> {
+    entrances.iter().map(|(from, to)| Ok((entrance_from_name(from)?, entrance_from_name(to)?))).collect()
+}
+
+fn entrance_from_name(name: &str) -> Result<LoadingZoneId> {
+    LoadingZoneId::iter()
+        .find(|entrance| entrance.as_str() == name)
+        .ok_or_else(|| Error::game(format!("Spoiler Log has unknown Entrance: \"{}\"", name)))
+}
+
+fn goal_from_name(name: &str) -> Result<Goal> {
+    Goal::iter()
+        .find(|goal| goal.as_str() == name)
+        .ok_or_else(|| Error::game(format!("Spoiler Log has unknown Goal: \"{}\"", name)))
+}
+
+/// Several items share a display name (e.g. each Progressive Sword), so hand out a different one each time the name
+/// comes up. Names used more often than there are items (i.e. junk) reuse the first item.
+fn item_from_name(name: &str, used_items: &mut HashSet<Item>) -> Result<Item> {
+    let mut items = Item::iter().filter(|item| item.as_str() == name).peekable();
+    let first = *items.peek().ok_or_else(|| Error::game(format!("Spoiler Log has unknown Item: \"{}\"", name)))?;
+    let item = items.find(|item| !used_items.contains(item)).unwrap_or(first);
+    used_items.insert(item);
+    Ok(item)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{filler, generate_seed_info, SeedInfo};
+    use modinfo::Settings;
+
+    #[test]
+    fn it_reads_back_the_spoiler_log() {
+        let settings = serde_json::from_str::<Settings>("{}").unwrap();
+        let mut seed_info = generate_seed_info(1, settings).unwrap();
+        let spoiler = seed_info.spoiler().unwrap();
+
+        let mut read_back = serde_json::from_str::<SeedInfo>(&spoiler).unwrap();
+        assert_eq!(read_back.placements, seed_info.placements);
+        assert_eq!(filler::read_layout(&mut read_back), filler::read_layout(&mut seed_info));
+        assert_eq!(read_back.crack_map, seed_info.crack_map);
+        assert_eq!(read_back.vane_map, seed_info.vane_map);
+        assert_eq!(read_back.hash.text_hash, seed_info.hash.text_hash);
+        assert_eq!(read_back.spoiler().unwrap(), spoiler);
+    }
+}
@@ -0,0 +1,193 @@
+//! Batch Seed generation, to see how a set of Settings plays out over many Seeds.
+
+use crate::{filler, generate_seed_info, SeedInfo};
+use log::info;
+use modinfo::Settings;
+use serde::Serialize;
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+/// Statistics gathered from generating many Seeds with the same Settings.
+#[derive(Debug, Default, Serialize)]
+pub struct Stats {
+    /// Number of Seeds attempted.
+    pub seeds: usize,
+
+    /// Number of Seeds that failed to generate.
+    pub failures: usize,
+
+    /// Number of Seeds for each Playthrough length, in spheres.
+    pub spheres: BTreeMap<usize, usize>,
+
+    /// How often each major item was placed at each check.
+    pub item_locations: BTreeMap<String, BTreeMap<String, usize>>,
+
+    /// How often each check was chosen for an Always, Maiamai, or Sometimes Hint.
+    pub location_hints: BTreeMap<String, usize>,
+
+    /// How often each region was hinted as being on the path to each goal.
+    pub path_hints: BTreeMap<String, BTreeMap<String, usize>>,
+
+    /// The error each failed Seed gave.
+    pub errors: BTreeMap<u32, String>,
+}
+
+impl Stats {
+    /// Fraction of Seeds that failed to generate.
+    pub fn failure_rate(&self) -> f64 {
+        if self.seeds == 0 {
+            0.0
+        } else {
+            self.failures as f64 / self.seeds as f64
+        }
+    }
+
+    /// Average Playthrough length of the successful Seeds, in spheres.
+    pub fn average_spheres(&self) -> f64 {
+        let successes = self.seeds - self.failures;
+        if successes == 0 {
+            0.0
+        } else {
+            self.spheres.iter().map(|(spheres, count)| spheres * count).sum::<usize>() as f64 / successes as f64
+        }
+    }
+
+    /// Flattens the statistics into a CSV table, with one row per counted value.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("stat,name,value,count\n");
+        let mut row = |stat: &str, name: &str, value: &str, count: usize| {
+            writeln!(csv, "{},{},{},{}", csv_field(stat), csv_field(name), csv_field(value), count).unwrap();
+        };
+
+        row("seeds", "", "", self.seeds);
+        row("failures", "", "", self.failures);
+        for (spheres, &count) in &self.spheres {
+            row("spheres", "", &spheres.to_string(), count);
+        }
+        for (item, checks) in &self.item_locations {
+            for (check, &count) in checks {
+                row("item_location", item, check, count);
+            }
+        }
+        for (check, &count) in &self.location_hints {
+            row("location_hint", check, "", count);
+        }
+        for (goal, regions) in &self.path_hints {
+            for (region, &count) in regions {
+                row("path_hint", goal, region, count);
+            }
+        }
+        for (seed, error) in &self.errors {
+            row("error", &seed.to_string(), error, 1);
+        }
+
+        csv
+    }
+
+    fn add_seed(&mut self, mut seed_info: SeedInfo) {
+        self.seeds += 1;
+        *self.spheres.entry(seed_info.metrics.spheres()).or_default() += 1;
+
+        for (check_name, item) in filler::read_layout(&mut seed_info) {
+            if let Some(item) = item.filter(|item| item.is_major_item()) {
+                *self.item_locations.entry(item.as_str().to_owned()).or_default().entry(check_name).or_default() += 1;
+            }
+        }
+
+        let hints = &seed_info.hints;
+        for hint in hints.always_hints.iter().chain(&hints.maiamai_hints).chain(&hints.sometimes_hints) {
+            *self.location_hints.entry(hint.check.get_name().to_owned()).or_default() += 1;
+        }
+        for hint in &hints.path_hints {
+            let region = hint.check.get_location_info().map_or("", |loc_info| loc_info.region());
+            let regions = self.path_hints.entry(hint.goal.as_str().to_owned()).or_default();
+            *regions.entry(region.to_owned()).or_default() += 1;
+        }
+    }
+
+    fn add_failure(&mut self, seed: u32, error: String) {
+        self.seeds += 1;
+        self.failures += 1;
+        self.errors.insert(seed, error);
+    }
+
+    fn merge(&mut self, other: Stats) {
+        self.seeds += other.seeds;
+        self.failures += other.failures;
+        merge_counts(&mut self.spheres, other.spheres);
+        for (item, checks) in other.item_locations {
+            merge_counts(self.item_locations.entry(item).or_default(), checks);
+        }
+        merge_counts(&mut self.location_hints, other.location_hints);
+        for (goal, regions) in other.path_hints {
+            merge_counts(self.path_hints.entry(goal).or_default(), regions);
+        }
+        self.errors.extend(other.errors);
+    }
+}
+
+/// Generates every one of the `seeds` with the given `settings`, spread across `threads`, and gathers [`Stats`] on
+/// them. Nothing is patched and no Spoiler Logs are written.
+///
+/// Seeds that fail to generate, whether by returning an error or by panicking, are counted as failures rather than
+/// retried.
+pub fn generate_stats(settings: &Settings, seeds: &[u32], threads: usize) -> Stats {
+    let next = AtomicUsize::new(0);
+    let stats = Mutex::new(Stats::default());
+
+    thread::scope(|scope| {
+        for _ in 0..threads.max(1) {
+            scope.spawn(|| {
+                let mut thread_stats = Stats::default();
+
+                loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(&seed) = seeds.get(index) else {
+                        break;
+                    };
+
+                    match panic::catch_unwind(AssertUnwindSafe(|| generate_seed_info(seed, settings.clone()))) {
+                        Ok(Ok(seed_info)) => thread_stats.add_seed(seed_info),
+                        Ok(Err(err)) => thread_stats.add_failure(seed, err.to_string()),
+                        Err(panic) => thread_stats.add_failure(seed, panic_message(panic)),
+                    }
+
+                    if (index + 1).is_multiple_of(100) {
+                        info!("Generated {}/{} Seeds...", index + 1, seeds.len());
+                    }
+                }
+
+                stats.lock().unwrap().merge(thread_stats);
+            });
+        }
+    });
+
+    stats.into_inner().unwrap()
+}
+
+fn merge_counts<K: Ord>(counts: &mut BTreeMap<K, usize>, other: BTreeMap<K, usize>) {
+    for (key, count) in other {
+        *counts.entry(key).or_default() += count;
+    }
+}
+
+fn panic_message(panic: Box<dyn Any + Send>) -> String {
+    match panic.downcast::<String>() {
+        Ok(message) => *message,
+        Err(panic) => panic.downcast::<&str>().map(|message| message.to_string()).unwrap_or_default(),
+    }
+}
+
+/// Quotes a CSV field if needed.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
@@ -3,14 +3,18 @@ use std::{
     fmt::{self, Display, Formatter},
     fs, io,
     path::{Path, PathBuf},
+    str::FromStr,
 };
 
 use json_comments::StripComments;
 use log::info;
-use macros::fail;
+use pyo3::pyclass;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
 
-use crate::constants::CONFIG_FILE_NAME;
+use crate::constants::{CONFIG_FILE_NAME, VERSION};
+use crate::migration;
+use crate::plando::Plando;
 use crate::SeedInfo;
 
 pub type Result<T, E = Error> = std::result::Result<T, E>;
@@ -51,20 +55,51 @@ pub struct System;
 
 impl System {
     pub fn load_preset(name: &str) -> Result<SeedInfo> {
-        let file = PathBuf::from("presets").join(format!("{}.json", name));
+        Self::load_preset_file(&PathBuf::from("presets").join(format!("{}.json", name)))
+    }
+
+    /// Loads a preset, first upgrading it if it was written for an older version of the randomizer.
+    pub fn load_preset_file(file: &Path) -> Result<SeedInfo> {
         info!("Loading preset from:            {}\n", file.display());
-        Self::load_json(file)
+        Self::load_migrated_json(file.to_path_buf(), "Preset")
+    }
+
+    /// Loads a Plando, upgrading its settings the same way as a Preset's if it's from another version.
+    pub fn load_plando(file: &Path) -> Result<Plando> {
+        info!("Loading plando from:            {}\n", file.display());
+        Self::load_migrated_json(file.to_path_buf(), "Plando")
+    }
+
+    pub fn load_spoiler(file: &Path) -> Result<SeedInfo> {
+        info!("Loading spoiler log from:       {}\n", file.display());
+        Self::load_json(file.to_path_buf())
     }
 
     pub fn load_config<T: DeserializeOwned>() -> Result<T> {
-        let file = PathBuf::from(CONFIG_FILE_NAME);
+        Self::load_config_file(Path::new(CONFIG_FILE_NAME))
+    }
+
+    pub fn load_config_file<T: DeserializeOwned>(file: &Path) -> Result<T> {
         if file.exists() {
-            Self::load_json(file)
+            Self::load_json(file.to_path_buf())
         } else {
-            fail!("No config file found at {}", file.display());
+            Err(Error::new(format!("No config file found at {}", file.display())))
         }
     }
 
+    fn load_migrated_json<T: DeserializeOwned>(file: PathBuf, kind: &str) -> Result<T> {
+        let mut json: Value = Self::load_json(file)?;
+        let version = json.get("version").and_then(Value::as_str).unwrap_or_default().to_owned();
+        migration::migrate_preset(&mut json).map_err(Error::new)?;
+        serde_json::from_value(json).map_err(|err| {
+            if version == VERSION {
+                Error::new(err)
+            } else {
+                Error::new(format!("{} from \"{}\" is not compatible with this version: {}", kind, version, err))
+            }
+        })
+    }
+
     fn load_json<T: DeserializeOwned>(file: PathBuf) -> Result<T> {
         let file = fs::read_to_string(file)?;
         let stripped = StripComments::new(file.as_bytes());
@@ -73,17 +108,19 @@ impl System {
     }
 }
 
-/// Paths to the game ROM and output directories.
+/// Paths to the game ROM and output directories, and the form the patch is written in.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct UserConfig {
     rom: PathBuf,
     output: PathBuf,
+    #[serde(default)]
+    output_mode: OutputMode,
 }
 
 impl UserConfig {
     /// Generates new paths with the specified ROM and output directory.
-    pub fn new(rom: PathBuf, output: PathBuf) -> Self {
-        Self { rom, output }
+    pub fn new(rom: PathBuf, output: PathBuf, output_mode: OutputMode) -> Self {
+        Self { rom, output, output_mode }
     }
 
     /// Gets the path of the ROM file.
@@ -95,4 +132,64 @@ impl UserConfig {
     pub fn output(&self) -> &Path {
         &self.output
     }
+
+    /// Gets the form the patch is written in.
+    pub fn output_mode(&self) -> OutputMode {
+        self.output_mode
+    }
+
+    /// Overrides the path of the ROM file, e.g. from a command line flag.
+    pub fn set_rom(&mut self, rom: PathBuf) {
+        self.rom = rom;
+    }
+
+    /// Overrides the output directory, e.g. from a command line flag.
+    pub fn set_output(&mut self, output: PathBuf) {
+        self.output = output;
+    }
+
+    /// Overrides the form the patch is written in, e.g. from a command line flag.
+    pub fn set_output_mode(&mut self, output_mode: OutputMode) {
+        self.output_mode = output_mode;
+    }
+}
+
+/// The form a seed's patch is written to the output directory in.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Deserialize, Serialize)]
+#[pyclass]
+pub enum OutputMode {
+    /// A `<Title ID>/` folder of loose files, to be copied into Luma's or Citra's mod folder.
+    #[default]
+    LayeredFs,
+    /// The same folder packed into a single zip file, to be extracted into the mod folder.
+    Zip,
+    /// A complete .3ds ROM with the seed already applied, for emulators without mod support.
+    Image,
+    /// A BPS patch that turns the clean ROM into the same ROM as `Image`, applied with a standard patching tool.
+    Bps,
+}
+
+impl FromStr for OutputMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "layeredfs" => Ok(Self::LayeredFs),
+            "zip" => Ok(Self::Zip),
+            "image" => Ok(Self::Image),
+            "bps" => Ok(Self::Bps),
+            _ => Err(format!("Invalid output mode: '{}'. Expected one of: layeredfs, zip, image, bps", s)),
+        }
+    }
+}
+
+impl Display for OutputMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::LayeredFs => "LayeredFS",
+            Self::Zip => "Zip",
+            Self::Image => "ROM Image",
+            Self::Bps => "BPS",
+        })
+    }
 }
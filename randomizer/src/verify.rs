@@ -0,0 +1,179 @@
+use crate::filler::check::Check;
+use crate::filler::filler_item::{Goal, Item, Randomizable};
+use crate::filler::progress::Progress;
+use crate::plando::{self, Plando};
+use crate::{filler, validate_settings, CheckMap, ErrorKind, Result, SeedHash, SeedInfo};
+use log::{error, info, warn};
+use rand::{rngs::StdRng, SeedableRng};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// The results of verifying a Seed's item layout.
+#[derive(Debug, Default, Serialize)]
+pub struct Verification {
+    /// Whether the Triforce can be obtained under the Seed's Logic Mode.
+    pub beatable: bool,
+
+    /// In-logic item locations that can never be reached, even after collecting everything else.
+    pub unreachable_checks: Vec<String>,
+
+    /// Items that are only reachable if the player already has them, mapped from the check holding them.
+    pub self_locked_items: BTreeMap<String, Item>,
+}
+
+impl Verification {
+    /// Logs whether the Seed is beatable, followed by any problems found with its layout.
+    pub fn log(&self) {
+        println!();
+        if self.beatable {
+            info!("The seed is beatable.");
+        } else {
+            error!("The seed is NOT beatable.");
+        }
+
+        if !self.unreachable_checks.is_empty() {
+            println!();
+            warn!("Unreachable checks ({}):", self.unreachable_checks.len());
+            for check_name in &self.unreachable_checks {
+                warn!("  {}", check_name);
+            }
+        }
+
+        if !self.self_locked_items.is_empty() {
+            println!();
+            warn!("Items locked behind themselves ({}):", self.self_locked_items.len());
+            for (check_name, item) in &self.self_locked_items {
+                warn!("  {:<40} {}", check_name, item.as_str());
+            }
+        }
+    }
+}
+
+/// Verifies the layout of a Plando. Checks without a placement are filled the same way as when generating the Plando.
+///
+/// A Plando that can't be filled is never beatable. It's then verified with only its own placements, to find the items
+/// locked behind themselves that keep it from being filled.
+pub fn verify_plando(plando: &Plando) -> Result<Verification> {
+    validate_settings(&plando.settings)?;
+
+    let hash = SeedHash::new(plando.seed, &plando.settings);
+    let rng = &mut StdRng::seed_from_u64(plando.seed as u64);
+    match plando::fill_seed_info(plando, hash.clone(), rng) {
+        Ok((seed_info, check_map)) => Ok(verify(&seed_info, &check_map)),
+        Err(err) if matches!(err.kind(), ErrorKind::Game) => {
+            warn!("The plando could not be filled, verifying only its placements: {}", err);
+            let rng = &mut StdRng::seed_from_u64(plando.seed as u64);
+            let mut seed_info = plando::build_seed_info(plando, hash, rng)?;
+
+            let check_map = &mut filler::prefill_check_map(&mut seed_info.world_graph);
+            filler::place_cracks(&seed_info, check_map);
+            filler::place_weather_vanes(&seed_info, check_map);
+
+            for (check_name, &item) in &plando.placements {
+                filler::validate_plando_check(&seed_info.world_graph, check_map, check_name)?;
+                check_map.insert(check_name.to_owned(), Some(item.into()));
+            }
+
+            Ok(Verification { beatable: false, ..verify(&seed_info, check_map) })
+        },
+        Err(err) => Err(err),
+    }
+}
+
+/// Verifies the layout of a Seed loaded from a spoiler log.
+pub fn verify_spoiler(seed_info: &mut SeedInfo) -> Verification {
+    let check_map = filler::read_layout(seed_info);
+    verify(seed_info, &check_map)
+}
+
+/// Sweeps the `check_map` to determine whether the Seed can be beaten, and which of its checks are out of reach.
+pub fn verify(seed_info: &SeedInfo, check_map: &CheckMap) -> Verification {
+    info!("Verifying Seed...");
+
+    let (progress, reachable_checks) = sweep(seed_info, check_map, Progress::new(seed_info));
+    let reachable = reachable_checks.iter().map(Check::get_name).collect::<HashSet<_>>();
+
+    let mut verification = Verification { beatable: progress.has(Goal::Triforce), ..Default::default() };
+    let mut reachable_with_item: HashMap<Item, HashSet<&str>> = HashMap::new();
+
+    for check in seed_info.world_graph.values().flat_map(|node| node.get_checks().iter().flatten()) {
+        if check.get_location_info().is_none() || reachable.contains(check.get_name()) || check.get_logic().is_empty() {
+            continue;
+        }
+
+        verification.unreachable_checks.push(check.get_name().to_owned());
+
+        // Retry the sweep as if the player already had the item at this check
+        if let Some(Some(Randomizable::Item(item))) = check_map.get(check.get_name()) {
+            let reachable_with_item = reachable_with_item.entry(*item).or_insert_with(|| {
+                let mut progress = Progress::new(seed_info);
+                progress.add_item(*item);
+                sweep(seed_info, check_map, progress).1.iter().map(Check::get_name).collect()
+            });
+
+            if reachable_with_item.contains(check.get_name()) {
+                verification.self_locked_items.insert(check.get_name().to_owned(), *item);
+            }
+        }
+    }
+
+    verification.unreachable_checks.sort();
+    verification
+}
+
+/// Collects everything reachable from the starting `progress`, until nothing new can be found.
+fn sweep<'s>(seed_info: &'s SeedInfo, check_map: &CheckMap, mut progress: Progress<'s>) -> (Progress<'s>, Vec<Check>) {
+    loop {
+        let reachable_checks = filler::find_reachable_checks(seed_info, &progress);
+        let reachable_items = filler::get_items_from_reachable_checks(seed_info, &reachable_checks, check_map);
+        let new_items = reachable_items.difference(&progress);
+
+        if new_items.is_empty() {
+            return (progress, reachable_checks);
+        }
+
+        for new_item in new_items {
+            progress.add_item(new_item);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::verify_plando;
+    use crate::filler::filler_item::Item;
+    use crate::system::System;
+    use serde_json::json;
+    use std::path::Path;
+
+    #[test]
+    fn it_verifies_the_example_plando() {
+        let plando = System::load_plando(Path::new("../presets/plando/Example.json")).unwrap();
+        let verification = verify_plando(&plando).unwrap();
+
+        assert!(verification.beatable);
+        assert!(verification.unreachable_checks.is_empty(), "{:?}", verification.unreachable_checks);
+        assert!(verification.self_locked_items.is_empty());
+    }
+
+    #[test]
+    fn it_finds_self_locked_items() {
+        let plando = serde_json::from_value(json!({
+            "settings": {},
+            "placements": { "Sanctuary Pegs": "Hammer01", "Ravio's Gift": "Bow01" }
+        }))
+        .unwrap();
+        let verification = verify_plando(&plando).unwrap();
+
+        assert!(!verification.beatable);
+        assert!(verification.unreachable_checks.contains(&"Sanctuary Pegs".to_owned()));
+        assert_eq!(verification.self_locked_items.get("Sanctuary Pegs"), Some(&Item::Hammer01));
+        assert_eq!(verification.self_locked_items.len(), 1);
+    }
+
+    #[test]
+    fn it_rejects_invalid_placements() {
+        let plando = serde_json::from_value(json!({ "settings": {}, "placements": { "Nowhere": "Bow01" } })).unwrap();
+        assert!(verify_plando(&plando).is_err());
+    }
+}
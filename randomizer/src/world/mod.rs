@@ -1,29 +1,42 @@
-use crate::regions::Subregion;
+use crate::filler::cracks::Crack;
+use crate::filler::entrances::EntranceMap;
+use crate::filler::filler_item::{Goal, Item};
+use crate::filler::loading_zone_pair::LoadingZoneId;
+use crate::filler::path::Path;
+use crate::filler::requirement::Requirement;
 use crate::{
     filler::filler_item::Randomizable,
-    filler::{check::Check, location::Location, location_node::LocationNode, logic::Logic, progress::Progress},
+    filler::{check::Check, location::Location, location_node::LocationNode, logic::Logic},
     hints::hint_ghost_name,
-    CrackMap, DashMap, LocationInfo,
+    regions, CrackMap, DashMap, Error, LocationInfo, Result,
 };
 use game::ghosts::HintGhost;
+use json_comments::StripComments;
+use lazy_static::lazy_static;
 use log::info;
-use serde::Serialize;
+use modinfo::Settings;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::io::Read;
 use std::ops::{Deref, DerefMut};
-
-mod dark;
-mod desert;
-mod eastern;
-mod gales;
-mod hera;
-mod hyrule;
-mod hyrule_castle;
-mod ice;
-mod lorule;
-mod lorule_castle;
-mod skull;
-mod swamp;
-mod thieves;
-mod turtle;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// The built-in logic, used unless a preset selects its own `logic_file`.
+const DEFAULT_LOGIC: &str = include_str!("../../../logic/Default.json");
+
+lazy_static! {
+    /// The built-in logic without its comments, which the names of Checks in the World Graph are borrowed from.
+    static ref DEFAULT_SOURCE: String = strip_comments(DEFAULT_LOGIC).expect("The built-in logic should be readable");
+
+    /// The names of every Check in the built-in logic. Custom logic can only use these names, as the patcher and
+    /// hints don't know of any others.
+    static ref CHECK_NAMES: HashSet<&'static str> = {
+        let logic_file: BTreeMap<&'static str, NamedNode> =
+            serde_json::from_str(&DEFAULT_SOURCE).expect("The built-in logic should be valid");
+        logic_file.into_values().flat_map(|node| node.checks).flat_map(|entry| entry.check.or(entry.goal)).collect()
+    };
+}
 
 #[derive(Default, Debug, Serialize)]
 pub struct WorldGraph {
@@ -66,211 +79,228 @@ impl DerefMut for WorldGraph {
     }
 }
 
-/// Build the World Graph
-/// FIXME shouldn't take crack_map as argument, map should be independent of that randomization
-pub fn build_world_graph(crack_map: &CrackMap) -> WorldGraph {
+/// Build the World Graph from the logic file selected by the settings, or the built-in logic if there isn't one.
+/// FIXME shouldn't take crack_map or entrance_map as arguments, map should be independent of that randomization
+pub fn build_world_graph(settings: &Settings, crack_map: &CrackMap, entrance_map: &EntranceMap) -> Result<WorldGraph> {
     info!("Building World Graph...");
-    let mut world = WorldGraph::new();
 
-    world.extend(hyrule::graph(crack_map));
-    world.extend(lorule::graph(crack_map));
-
-    world.extend(eastern::graph());
-    world.extend(gales::graph());
-    world.extend(hera::graph());
+    let contents;
+    let source = match &settings.logic_file {
+        None => DEFAULT_SOURCE.as_str(),
+        Some(file) => {
+            info!("Loading logic from:             {}", file);
+            let source = std::fs::read_to_string(file)
+                .map_err(|err| Error::io(format!("Couldn't read logic file \"{}\": {}", file, err)))?;
+            contents = strip_comments(&source)?;
+            &contents
+        },
+    };
 
-    world.extend(hyrule_castle::graph(crack_map));
+    let logic_file: BTreeMap<String, LogicNode> =
+        serde_json::from_str(source).map_err(|err| Error::game(format!("Invalid logic file: {}", err)))?;
 
-    world.extend(dark::graph());
-    world.extend(swamp::graph());
-    world.extend(skull::graph());
-    world.extend(thieves::graph());
-    world.extend(ice::graph());
-    world.extend(desert::graph(crack_map));
-    world.extend(turtle::graph());
+    let mut world = WorldGraph::new();
+    for (location, node) in logic_file {
+        let location = Location::from_str(&location).map_err(|_| invalid(&location, "is not a Location"))?;
+        let checks = node.checks.into_iter().map(LogicEntry::into_check).collect::<Result<Vec<_>>>()?;
+        let paths = node
+            .paths
+            .into_iter()
+            .map(|path| path.into_path(crack_map, entrance_map))
+            .collect::<Result<Vec<_>>>()?;
+        world.insert(location, LocationNode::new(&node.name, checks, paths));
+    }
 
-    world.extend(lorule_castle::graph(crack_map));
+    for (location, node) in world.iter() {
+        for path in node.get_paths().iter().flatten() {
+            if !world.contains_key(&path.get_destination()) {
+                return Err(Error::game(format!(
+                    "Invalid logic file: {} has a path to {}, which isn't defined",
+                    location,
+                    path.get_destination()
+                )));
+            }
+        }
+    }
 
     world.compute_check_map();
 
-    world
+    Ok(world)
 }
 
-/// Check convenience macros
-macro_rules! check {
-    ($loc_name:expr, $loc_region:expr) => {
-        Check::new($loc_name, Logic::free(), None, Some(LocationInfo::new($loc_name, $loc_region)))
-    };
-    ($loc_name:expr, $loc_region:expr, $normal:expr) => {
-        Check::new($loc_name, *Logic::new()
-            .normal($normal), None, Some(LocationInfo::new($loc_name, $loc_region)))
-    };
-    ($loc_name:expr, $loc_region:expr => {
-        $(normal: $normal:expr,)?
-        $(hard: $hard:expr,)?
-        $(glitched: $glitched:expr,)?
-        $(adv_glitched: $adv_glitched:expr,)?
-        $(hell: $hell:expr,)?
-    }) => (
-        Check::new($loc_name, *Logic::new()
-            $(.normal($normal))?
-            $(.hard($hard))?
-            $(.glitched($glitched))?
-            $(.adv_glitched($adv_glitched))?
-            $(.hell($hell))?, None, Some(LocationInfo::new($loc_name, $loc_region)))
-    );
+fn strip_comments(source: &str) -> Result<String> {
+    let mut stripped = String::new();
+    StripComments::new(source.as_bytes()).read_to_string(&mut stripped)?;
+    Ok(stripped)
 }
 
-/// Weather Vane convenience macros
-// macro_rules! vane {
-//     ($loc_name:expr, $vane:expr) => {
-//         Check::new($loc_name, Logic::free(), Some($vane.into()), None)
-//     };
-//     ($loc_name:expr, $vane:expr, $normal:expr) => {
-//         Check::new($loc_name, *Logic::new()
-//             .normal($normal), Some($vane.into()), None)
-//     };
-//     ($loc_name:expr, $vane:expr => {
-//         $(normal: $normal:expr,)?
-//         $(hard: $hard:expr,)?
-//         $(glitched: $glitched:expr,)?
-//         $(adv_glitched: $adv_glitched:expr,)?
-//         $(hell: $hell:expr,)?
-//     }) => (
-//         Check::new($loc_name, *Logic::new()
-//             $(.normal($normal))?
-//             $(.hard($hard))?
-//             $(.glitched($glitched))?
-//             $(.adv_glitched($adv_glitched))?
-//             $(.hell($hell))?, Some($vane.into()), None)
-//     );
-// }
-
-/// Goal convenience macros
-macro_rules! goal {
-    ($loc_name:expr, $goal:expr) => {
-        Check::new($loc_name, Logic::free(), Some($goal.into()), None)
-    };
-    ($loc_name:expr, $goal:expr, $normal:expr) => {
-        Check::new($loc_name, *Logic::new()
-            .normal($normal), Some($goal.into()), None)
-    };
-    ($loc_name:expr, $goal:expr => {
-        $(normal: $normal:expr,)?
-        $(hard: $hard:expr,)?
-        $(glitched: $glitched:expr,)?
-        $(adv_glitched: $adv_glitched:expr,)?
-        $(hell: $hell:expr,)?
-    }) => (
-        Check::new($loc_name, *Logic::new()
-            $(.normal($normal))?
-            $(.hard($hard))?
-            $(.glitched($glitched))?
-            $(.adv_glitched($adv_glitched))?
-            $(.hell($hell))?, Some($goal.into()), None)
-    );
+/// Gets the built-in logic's copy of a Check name, which lives for the whole program.
+fn check_name(name: &str) -> Result<&'static str> {
+    CHECK_NAMES.get(name).copied().ok_or_else(|| invalid(name, "is not the name of a check in the built-in logic"))
 }
 
-/// Path convenience macros
-/// Rust won't let me call it a Path so I'm using the dumb math name >:(
-macro_rules! edge {
-    ($dest:ident) => (
-        Path::new(Location::$dest, Logic::free())
-    );
-    ($dest:ident, $normal:expr) => (
-        Path::new(Location::$dest, *Logic::new()
-            .normal($normal))
-    );
-    ($dest:ident => {
-        $(normal: $normal:expr,)?
-        $(hard: $hard:expr,)?
-        $(glitched: $glitched:expr,)?
-        $(adv_glitched: $adv_glitched:expr,)?
-        $(hell: $hell:expr,)?
-    }) => (
-        Path::new(Location::$dest,
-            *Logic::new()
-            $(.normal($normal))?
-            $(.hard($hard))?
-            $(.glitched($glitched))?
-            $(.adv_glitched($adv_glitched))?
-            $(.hell($hell))?
-        )
-    );
+fn invalid(value: &str, problem: &str) -> Error {
+    Error::game(format!("Invalid logic file: \"{}\" {}", value, problem))
 }
 
-use crate::filler::cracks::Crack;
-use crate::filler::path::Path;
-pub(crate) use check;
-pub(crate) use edge;
-pub(crate) use goal;
-
-fn location<C, P>(name: &'static str, checks: C, paths: P) -> LocationNode
-where
-    C: Into<Option<Vec<Check>>>,
-    P: Into<Option<Vec<Path>>>,
-{
-    LocationNode::new(name, checks.into(), paths.into())
+/// A Location in the logic file, with the Checks found there and the Paths leading out of it.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct LogicNode {
+    name: String,
+    #[serde(default)]
+    checks: Vec<LogicEntry>,
+    #[serde(default)]
+    paths: Vec<LogicEntry>,
 }
 
-// TODO REMOVE
-// #[deprecated]
-fn old_check(
-    location_info: LocationInfo, normal: Option<fn(&Progress) -> bool>, hard: Option<fn(&Progress) -> bool>,
-    glitched: Option<fn(&Progress) -> bool>, adv_glitched: Option<fn(&Progress) -> bool>,
-    hell: Option<fn(&Progress) -> bool>,
-) -> Check {
-    Check::new(location_info.name, Logic::config(normal, hard, glitched, adv_glitched, hell), None, Some(location_info))
+/// A Check or a Path in the logic file.
+///
+/// Checks are one of:
+/// - `check`, an item location in the given `region`, e.g. `"hyrule::lake::hylia"`.
+/// - `goal`, an event granting the `quest` Goal, or the quest `item`.
+/// - `ghost`, a Hint Ghost.
+///
+/// Paths are one of:
+/// - `to`, a Location.
+/// - `crack`, through the Crack from its `Left` or `Right` `side`, to wherever the Crack Map leads.
+/// - `entrance` or `exit`, through a door from its outside or inside, to wherever the Entrance Map leads.
+///
+/// Each has the requirements to use it under each Logic Mode, from `normal` through `hell`. Entries with none are
+/// always accessible, unless they're `out_of_logic`.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct LogicEntry {
+    check: Option<String>,
+    region: Option<String>,
+    goal: Option<String>,
+    quest: Option<String>,
+    item: Option<String>,
+    ghost: Option<String>,
+
+    to: Option<String>,
+    crack: Option<String>,
+    side: Option<CrackSide>,
+    entrance: Option<String>,
+    exit: Option<String>,
+
+    normal: Option<String>,
+    hard: Option<String>,
+    glitched: Option<String>,
+    adv_glitched: Option<String>,
+    hell: Option<String>,
+    #[serde(default)]
+    out_of_logic: bool,
 }
 
-// todo REMOVE
-// #[deprecated]
-fn old_path(
-    default: Location, normal: Option<fn(&Progress) -> bool>, hard: Option<fn(&Progress) -> bool>,
-    glitched: Option<fn(&Progress) -> bool>, adv_glitched: Option<fn(&Progress) -> bool>,
-    hell: Option<fn(&Progress) -> bool>,
-) -> Path {
-    Path::new(default, Logic::config(normal, hard, glitched, adv_glitched, hell))
+/// Just the names of the Checks in a Location, to collect the built-in logic's [`CHECK_NAMES`].
+#[derive(Deserialize)]
+#[serde(bound(deserialize = "'de: 'static"))]
+struct NamedNode {
+    #[serde(default)]
+    checks: Vec<NamedEntry>,
 }
 
-/// Used for checks that the Randomizer should be aware of existing, but are not considered part of any logic.
-/// Most things that use this are typically not in logic *yet*
-fn out_of_logic(name: &'static str, subregion: &'static Subregion) -> Check {
-    Check::new(
-        name,
-        Logic { normal: None, hard: None, glitched: None, adv_glitched: None, hell: None },
-        None,
-        Some(LocationInfo::new(name, subregion)),
-    )
+#[derive(Deserialize)]
+struct NamedEntry {
+    check: Option<&'static str>,
+    goal: Option<&'static str>,
 }
 
-fn crack_left(crack: Crack, crack_map: &CrackMap, is_hc: bool) -> Path {
-    let dest_crack = crack_map.get(&crack).unwrap_or_else(|| panic!("CrackMap missing Crack: {:?}", crack));
-    let (_left, right) = dest_crack.get_left_right_locations();
-
-    let logic: fn(&Progress) -> bool = if is_hc { |p| p.can_merge() } else { |p| p.are_cracks_open() && p.can_merge() };
-
-    Path::new(right, *Logic::new().normal(logic))
+#[derive(Deserialize)]
+enum CrackSide {
+    Left,
+    Right,
 }
 
-fn crack_right(crack: Crack, crack_map: &CrackMap, is_hc: bool) -> Path {
-    let dest_crack = crack_map.get(&crack).expect("CrackMap should have all Cracks mapped");
-    let (left, _right) = dest_crack.get_left_right_locations();
-
-    let logic: fn(&Progress) -> bool = if is_hc { |p| p.can_merge() } else { |p| p.are_cracks_open() && p.can_merge() };
-
-    Path::new(left, *Logic::new().normal(logic))
-}
+impl LogicEntry {
+    fn into_check(self) -> Result<Check> {
+        let logic = self.logic()?;
+        match self {
+            Self { check: Some(name), region: Some(region), goal: None, ghost: None, .. } => {
+                let name = check_name(&name)?;
+                let subregion = regions::subregion(&region).ok_or_else(|| invalid(&region, "is not a region"))?;
+                Ok(Check::new(name, logic, None, Some(LocationInfo::new(name, subregion))))
+            },
+            Self { goal: Some(name), quest: Some(goal), item: None, check: None, ghost: None, .. } => {
+                let goal = Goal::from_str(&goal).map_err(|_| invalid(&goal, "is not a Goal"))?;
+                Ok(Check::new(check_name(&name)?, logic, Some(goal.into()), None))
+            },
+            Self { goal: Some(name), item: Some(item), quest: None, check: None, ghost: None, .. } => {
+                let item = Item::from_str(&item).map_err(|_| invalid(&item, "is not an Item"))?;
+                Ok(Check::new(check_name(&name)?, logic, Some(item.into()), None))
+            },
+            Self { ghost: Some(ghost), check: None, goal: None, .. } => {
+                let ghost = HintGhost::from_str(&ghost).map_err(|_| invalid(&ghost, "is not a Hint Ghost"))?;
+                Ok(Check::new(hint_ghost_name(&ghost), logic, Some(Randomizable::HintGhost(ghost)), None))
+            },
+            Self { check, goal, ghost, .. } => Err(invalid(
+                &check.or(goal).or(ghost).unwrap_or_default(),
+                "needs exactly one of a check with a region, a goal with a quest or item, or a ghost",
+            )),
+        }
+    }
 
-fn fast_travel_hyrule() -> Path {
-    edge!(HyruleBellTravel, |p| p.has_bell() && p.are_hyrule_vanes_active())
-}
+    fn into_path(self, crack_map: &CrackMap, entrance_map: &EntranceMap) -> Result<Path> {
+        let logic = self.logic()?;
+        let destination = match self {
+            Self { to: Some(to), crack: None, entrance: None, exit: None, .. } => {
+                Location::from_str(&to).map_err(|_| invalid(&to, "is not a Location"))?
+            },
+            Self { crack: Some(crack), side: Some(side), to: None, entrance: None, exit: None, .. } => {
+                let crack = Crack::from_str(&crack).map_err(|_| invalid(&crack, "is not a Crack"))?;
+                let destination = crack_map.get(&crack).ok_or_else(|| invalid(crack.as_str(), "is not mapped"))?;
+                let (left, right) = destination.get_left_right_locations();
+                match side {
+                    CrackSide::Left => right,
+                    CrackSide::Right => left,
+                }
+            },
+            Self { entrance: Some(door), to: None, crack: None, exit: None, .. } => {
+                let door = LoadingZoneId::from_str(&door).map_err(|_| invalid(&door, "is not a door"))?;
+                entrance_map.get_entrance(door).get_exit().get_location()
+            },
+            Self { exit: Some(door), to: None, crack: None, entrance: None, .. } => {
+                let door = LoadingZoneId::from_str(&door).map_err(|_| invalid(&door, "is not a door"))?;
+                entrance_map.get_exit(door).get_entry().get_location()
+            },
+            Self { to, crack, entrance, exit, .. } => {
+                return Err(invalid(
+                    &to.or(crack).or(entrance).or(exit).unwrap_or_default(),
+                    "needs exactly one of to, a crack with a side, an entrance, or an exit",
+                ));
+            },
+        };
+
+        Ok(Path::new(destination, logic))
+    }
 
-fn fast_travel_lorule() -> Path {
-    edge!(LoruleBellTravel, |p| p.has_bell() && p.are_lorule_vanes_active())
-}
+    fn logic(&self) -> Result<Logic> {
+        let requirements = [&self.normal, &self.hard, &self.glitched, &self.adv_glitched, &self.hell];
+        let requirements = requirements.map(Option::as_deref);
+        if self.out_of_logic {
+            return match requirements.iter().flatten().next() {
+                None => Ok(Logic::new()),
+                Some(requirement) => Err(invalid(requirement, "is given for an out_of_logic entry")),
+            };
+        } else if requirements.iter().all(Option::is_none) {
+            return Ok(Logic::free());
+        }
 
-/// Hint Ghost checks
-fn ghost(ghost: HintGhost) -> Check {
-    Check::new(hint_ghost_name(&ghost), Logic::free(), Some(Randomizable::HintGhost(ghost)), None)
+        let parse = |requirement: &Option<String>| {
+            requirement
+                .as_deref()
+                .map(|requirement| Requirement::from_str(requirement).map(Arc::new))
+                .transpose()
+                .map_err(|err| Error::game(format!("Invalid logic file: {}", err)))
+        };
+
+        Ok(Logic {
+            normal: parse(&self.normal)?,
+            hard: parse(&self.hard)?,
+            glitched: parse(&self.glitched)?,
+            adv_glitched: parse(&self.adv_glitched)?,
+            hell: parse(&self.hell)?,
+        })
+    }
 }
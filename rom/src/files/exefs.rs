@@ -0,0 +1,143 @@
+use {
+    crate::{Error, Result},
+    bytey::*,
+    sha2::{Digest, Sha256},
+    std::io::prelude::*,
+};
+
+/// The game's executable filesystem, holding its code and icon.
+#[derive(Debug)]
+pub(crate) struct ExeFs {
+    files: Vec<(String, Vec<u8>)>,
+}
+
+impl ExeFs {
+    #[cfg(test)]
+    pub(crate) fn new(files: Vec<(String, Vec<u8>)>) -> Self {
+        Self { files }
+    }
+
+    /// Reads the ExeFS image starting at the given offset of a file.
+    pub(crate) fn read<R>(mut file: R, offset: u64) -> Result<Self>
+    where
+        R: Read + Seek,
+    {
+        let header = read_slice_from_offset(&mut file, offset, HEADER_LEN)?;
+        let mut files = vec![];
+        for entry in header[..FILE_COUNT * 0x10].chunks_exact(0x10) {
+            let name = String::from_utf8_lossy(&entry[..8]).trim_end_matches('\0').to_owned();
+            let (file_offset, len) =
+                unsafe { (u32::from_slice_unchecked(&entry[8..]), u32::from_slice_unchecked(&entry[0xC..])) };
+            if !name.is_empty() && len > 0 {
+                let data =
+                    read_slice_from_offset(&mut file, offset + HEADER_LEN as u64 + file_offset as u64, len as usize)?;
+                files.push((name, data.into()));
+            }
+        }
+        Ok(Self { files })
+    }
+
+    /// Gets the contents of a file, by its name.
+    pub(crate) fn get_mut(&mut self, name: &str) -> Result<&mut Vec<u8>> {
+        self.files
+            .iter_mut()
+            .find_map(|(file, data)| (file == name).then_some(data))
+            .ok_or_else(|| Error::new(format!("ExeFS file not found: '{}'.", name)))
+    }
+
+    /// Writes the ExeFS image, returning the header that the NCCH header hashes to verify its files.
+    pub(crate) fn write<W>(self, mut out: W) -> Result<Box<[u8]>>
+    where
+        W: Write,
+    {
+        let mut header = [0; HEADER_LEN];
+        let mut offset = 0u32;
+        for (i, (name, data)) in self.files.iter().enumerate() {
+            let entry = &mut header[i * 0x10..];
+            entry[..name.len()].copy_from_slice(name.as_bytes());
+            entry[8..0xC].copy_from_slice(&offset.to_le_bytes());
+            entry[0xC..0x10].copy_from_slice(&(data.len() as u32).to_le_bytes());
+            // Hashes are stored in reverse order
+            header[HEADER_LEN - (i + 1) * 0x20..][..0x20].copy_from_slice(&Sha256::digest(data));
+            offset = super::align::<{ HEADER_LEN as u32 }>(offset + data.len() as u32);
+        }
+        out.write_all(&header)?;
+        for (_, data) in self.files {
+            out.write_all(&data)?;
+            out.write_all(&vec![0; super::align::<{ HEADER_LEN as u32 }>(data.len() as u32) as usize - data.len()])?;
+        }
+        Ok(header.into())
+    }
+}
+
+/// Decompresses the game's code, which is compressed with a variant of LZSS that's read from the end backwards.
+pub(crate) fn decompress_code(compressed: &[u8]) -> Result<Vec<u8>> {
+    let invalid = || Error::new("Invalid compressed code.");
+    let len = compressed.len();
+    if len < 8 {
+        return Err(invalid());
+    }
+    let (bounds, extra) = unsafe {
+        (u32::from_slice_unchecked(&compressed[len - 8..]), u32::from_slice_unchecked(&compressed[len - 4..]))
+    };
+    let mut index = len.checked_sub((bounds >> 24) as usize).ok_or_else(invalid)?;
+    let stop = len.checked_sub((bounds & 0xFFFFFF) as usize).ok_or_else(invalid)?;
+    let mut out = compressed.to_vec();
+    out.resize(len + extra as usize, 0);
+    let mut out_index = out.len();
+    while index > stop {
+        index -= 1;
+        let mut control = compressed[index];
+        for _ in 0..8 {
+            if index <= stop {
+                break;
+            }
+            if control & 0x80 != 0 {
+                index = index.checked_sub(2).ok_or_else(invalid)?;
+                let segment = u16::from_le_bytes([compressed[index], compressed[index + 1]]) as usize;
+                let size = (segment >> 12) + 3;
+                let offset = (segment & 0xFFF) + 2;
+                for _ in 0..size {
+                    let data = *out.get(out_index + offset).ok_or_else(invalid)?;
+                    out_index = out_index.checked_sub(1).ok_or_else(invalid)?;
+                    out[out_index] = data;
+                }
+            } else {
+                index -= 1;
+                out_index = out_index.checked_sub(1).ok_or_else(invalid)?;
+                out[out_index] = compressed[index];
+            }
+            control <<= 1;
+        }
+    }
+    Ok(out)
+}
+
+const HEADER_LEN: usize = 0x200;
+const FILE_COUNT: usize = 10;
+
+#[cfg(test)]
+mod tests {
+    use {super::*, std::io::Cursor};
+
+    #[test]
+    fn it_decompresses_code() -> Result<()> {
+        // Three literals, then a copy of 15 bytes from 3 bytes ahead
+        let mut compressed = vec![0x00, 0xC0, b'a', b'b', b'c', 0b0001_0000];
+        compressed.extend((8u32 << 24 | 14).to_le_bytes());
+        compressed.extend(4u32.to_le_bytes());
+        assert_eq!(decompress_code(&compressed)?, b"abcabcabcabcabcabc");
+        Ok(())
+    }
+
+    #[test]
+    fn it_rewrites_exefs() -> Result<()> {
+        let exefs = ExeFs { files: vec![(".code".into(), vec![1; 0x234]), ("icon".into(), vec![2; 0x10])] };
+        let mut buf = vec![];
+        exefs.write(&mut buf)?;
+        let mut exefs = ExeFs::read(Cursor::new(buf), 0)?;
+        assert_eq!(exefs.get_mut(".code")?, &vec![1; 0x234]);
+        assert_eq!(exefs.get_mut("icon")?, &vec![2; 0x10]);
+        Ok(())
+    }
+}
@@ -5,6 +5,20 @@ use bytey::*;
 pub struct ExHeader([u8; LEN]);
 
 impl ExHeader {
+    /// Gets the 64-bit title ID from the ARM11 system capabilities.
+    pub fn get_program_id(&self) -> u64 {
+        unsafe { u64::from_slice_unchecked(&self.0[0x200..]) }
+    }
+
+    /// Whether the ExeFS `.code` is compressed.
+    pub fn is_code_compressed(&self) -> bool {
+        self.0[0xD] & 1 != 0
+    }
+
+    pub fn set_code_compressed(&mut self, compressed: bool) {
+        self.0[0xD] = self.0[0xD] & !1 | compressed as u8;
+    }
+
     pub fn get_text_address(&self) -> u32 {
         unsafe { u32::from_slice_unchecked(&self.0[0x10..]) }
     }
@@ -65,6 +79,22 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn it_gets_program_id() {
+        let mut buf = [0u8; LEN];
+        buf[0x200..0x208].copy_from_slice(&0x00040000000EC300u64.to_le_bytes());
+        assert_eq!(ExHeader::from_bytes(&buf).get_program_id(), 0x00040000000EC300);
+    }
+
+    #[test]
+    fn it_sets_code_compressed() {
+        let mut exheader = new();
+        exheader.0[0xD] = 0b11;
+        exheader.set_code_compressed(false);
+        assert!(!exheader.is_code_compressed());
+        assert_eq!(exheader.0[0xD], 0b10);
+    }
+
     #[test]
     fn it_sets_rodata_size() -> Result<()> {
         let mut exheader = new();
@@ -0,0 +1,53 @@
+use crate::{Error, Result};
+
+/// Applies an IPS patch, growing the data if the patch writes past its end.
+pub(crate) fn apply(data: &mut Vec<u8>, patch: &[u8]) -> Result<()> {
+    let invalid = || Error::new("Invalid IPS patch.");
+    let mut patch = patch.strip_prefix(b"PATCH").ok_or_else(invalid)?;
+    loop {
+        let mut take = |len: usize| -> Result<&[u8]> {
+            let (taken, rest) = patch.split_at_checked(len).ok_or_else(invalid)?;
+            patch = rest;
+            Ok(taken)
+        };
+        let offset = take(3)?;
+        if offset == b"EOF" {
+            return Ok(());
+        }
+        let offset = u32::from_be_bytes([0, offset[0], offset[1], offset[2]]) as usize;
+        let len = u16::from_be_bytes(take(2)?.try_into().unwrap()) as usize;
+        let record = if len == 0 {
+            // Run-length encoded record
+            let len = u16::from_be_bytes(take(2)?.try_into().unwrap()) as usize;
+            vec![take(1)?[0]; len]
+        } else {
+            take(len)?.to_vec()
+        };
+        if data.len() < offset + record.len() {
+            data.resize(offset + record.len(), 0);
+        }
+        data[offset..offset + record.len()].copy_from_slice(&record);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_applies_records() -> Result<()> {
+        let mut data = vec![0; 4];
+        let mut patch = b"PATCH".to_vec();
+        patch.extend([0, 0, 2, 0, 2, 0xAA, 0xBB]);
+        patch.extend([0, 0, 6, 0, 0, 0, 3, 0xCC]);
+        patch.extend(b"EOF");
+        apply(&mut data, &patch)?;
+        assert_eq!(data, [0, 0, 0xAA, 0xBB, 0, 0, 0xCC, 0xCC, 0xCC]);
+        Ok(())
+    }
+
+    #[test]
+    fn it_rejects_truncated_patches() {
+        assert!(apply(&mut vec![], b"PATCH\0\0\x01\0\x04\xAA").is_err());
+    }
+}
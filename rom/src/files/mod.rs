@@ -5,9 +5,14 @@ use {
     serde::Serialize,
     std::{fs, io::prelude::*, path::Path},
 };
+pub(crate) use ncsd::rebuild;
+
 pub mod byaml;
+mod exefs;
 pub mod exheader;
+mod ips;
 pub mod msgbn;
+mod ncsd;
 pub mod romfs;
 pub mod sarc;
 
@@ -37,6 +42,7 @@ where
 }
 
 impl Cxi<fs::File> {
+    /// Opens the game's CXI from a decrypted NCSD (.3ds) image or a decrypted CIA.
     pub fn open<P>(path: P) -> Result<Self>
     where
         P: AsRef<Path>,
@@ -46,25 +52,48 @@ impl Cxi<fs::File> {
 
         //validate_rom(&file);
 
-        typedef! { struct NCSD: TryFromBytes<'_> [HEADER_LEN] {
-            #b"NCSD",
-            [8] id: u64,
-            [0x20] offset: u32,
-        }}
-        let header = NCSD::try_read_from_offset(&mut file, SIGNATURE_LEN)?;
-        let offset = from_media_units(header.offset);
+        let (offset, id) = if u32::read_from_offset(&mut file, 0u64)? == CIA_HEADER_LEN {
+            (find_cia_content(&mut file)?, None)
+        } else {
+            typedef! { struct NCSD: TryFromBytes<'_> [HEADER_LEN] {
+                #b"NCSD",
+                [8] id: u64,
+                [0x20] offset: u32,
+            }}
+            let header = NCSD::try_read_from_offset(&mut file, SIGNATURE_LEN)?;
+            (from_media_units(header.offset), Some(header.id))
+        };
         typedef! { struct NCCH: TryFromBytes<'_> [HEADER_LEN] {
             #b"NCCH",
             [8] id: u64,
             [0x18] program_id: u64,
         }}
         let ncch = NCCH::try_read_from_offset(&mut file, offset + SIGNATURE_LEN as u32)?;
-        cmp_id(ncch.id, header.id)?;
-        cmp_id(ncch.program_id, header.id)?;
-        Ok(Self { file, id: header.id, offset })
+        let id = id.unwrap_or(ncch.id);
+        cmp_id(ncch.id, id)?;
+        cmp_id(ncch.program_id, id)?;
+        Ok(Self { file, id, offset })
     }
 }
 
+/// Finds the offset of a CIA's first content, the game's CXI. Each section of a CIA is aligned to 64 bytes.
+fn find_cia_content<R>(file: R) -> Result<u32>
+where
+    R: Read + Seek,
+{
+    typedef! { struct CIA: FromBytes<'_> [0x20] {
+        [0] header_len: u32,
+        [8] cert_chain_len: u32,
+        [0xC] ticket_len: u32,
+        [0x10] tmd_len: u32,
+    }}
+    let header = CIA::read_from_offset(file, 0u64)?;
+    Ok(align::<0x40>(header.header_len)
+        + align::<0x40>(header.cert_chain_len)
+        + align::<0x40>(header.ticket_len)
+        + align::<0x40>(header.tmd_len))
+}
+
 #[derive(Clone, Debug)]
 pub struct File<T> {
     path: String,
@@ -204,5 +233,22 @@ pub(crate) const fn align<const ALIGN: u32>(value: u32) -> u32 {
 }
 
 const SIGNATURE_LEN: u64 = 0x100;
+const CIA_HEADER_LEN: u32 = 0x2020;
 const HEADER_LEN: usize = 0x100;
 const MEDIA_UNIT_SHIFT: u8 = 9;
+
+#[cfg(test)]
+mod tests {
+    use {super::*, std::io::Cursor};
+
+    #[test]
+    fn it_finds_cia_content() -> Result<()> {
+        let mut header = vec![0u8; 0x20];
+        header[0..4].copy_from_slice(&CIA_HEADER_LEN.to_le_bytes());
+        header[8..0xC].copy_from_slice(&0xA00u32.to_le_bytes());
+        header[0xC..0x10].copy_from_slice(&0x350u32.to_le_bytes());
+        header[0x10..0x14].copy_from_slice(&0xB34u32.to_le_bytes());
+        assert_eq!(find_cia_content(Cursor::new(header))?, 0x2040 + 0xA00 + 0x380 + 0xB40);
+        Ok(())
+    }
+}
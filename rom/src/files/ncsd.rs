@@ -0,0 +1,286 @@
+use {
+    super::{
+        exefs::{self, ExeFs},
+        exheader::{self, ExHeader},
+        ips,
+        romfs::{Content, RomFs, RomFsBuilder},
+        File, MEDIA_UNIT_SHIFT, SIGNATURE_LEN,
+    },
+    crate::{Error, Result},
+    bytey::*,
+    sha2::{Digest, Sha256},
+    std::{
+        fs,
+        io::{self, prelude::*, SeekFrom},
+        mem,
+        path::Path,
+    },
+};
+
+/// Writes a complete NCSD (.3ds) image of a ROM, with its game's NCCH rebuilt from the given changes.
+///
+/// The IPS patch is applied to the decompressed ExeFS `.code`, which is stored uncompressed. Files replace those
+/// at the same paths in the RomFS. The ROM's other partitions and card info are copied as they are.
+pub(crate) fn rebuild<P, W>(
+    path: P, exheader: &ExHeader, code_patch: &[u8], files: Vec<File<Box<[u8]>>>, mut out: W,
+) -> Result<()>
+where
+    P: AsRef<Path>,
+    W: Write + Seek,
+{
+    let path = path.as_ref();
+    let mut source = fs::File::open(path)?;
+    let mut header = read_slice_from_offset(&mut source, 0u64, HEADER_LEN)?;
+    if &header[0x100..0x104] != b"NCSD" {
+        return Err(Error::new("Only .3ds ROMs can be rebuilt into a patched image."));
+    }
+    let mut partitions = [(0, 0); PARTITION_COUNT];
+    for (i, partition) in partitions.iter_mut().enumerate() {
+        *partition = unsafe {
+            (
+                u32::from_slice_unchecked(&header[PARTITIONS + i * 8..]),
+                u32::from_slice_unchecked(&header[PARTITIONS + i * 8 + 4..]),
+            )
+        };
+    }
+
+    // Everything before the game's partition, including the card info, is kept as is
+    let start = out.stream_position()?;
+    let game_offset = to_bytes(partitions[0].0);
+    source.seek(SeekFrom::Start(0))?;
+    copy(&mut source, &mut out, game_offset)?;
+    let game_len = rebuild_ncch(path, game_offset, exheader, code_patch, files, &mut out)?;
+    partitions[0].1 = to_media_units(game_len);
+
+    let mut end = game_offset + game_len;
+    for partition in partitions.iter_mut().skip(1).filter(|(_, len)| *len > 0) {
+        source.seek(SeekFrom::Start(to_bytes(partition.0)))?;
+        copy(&mut source, &mut out, to_bytes(partition.1))?;
+        partition.0 = to_media_units(end);
+        end += to_bytes(partition.1);
+    }
+
+    for (i, (offset, len)) in partitions.into_iter().enumerate() {
+        header[PARTITIONS + i * 8..][..4].copy_from_slice(&offset.to_le_bytes());
+        header[PARTITIONS + i * 8 + 4..][..4].copy_from_slice(&len.to_le_bytes());
+    }
+    let image_len = unsafe { u32::from_slice_unchecked(&header[0x104..]) }.max(to_media_units(end));
+    header[0x104..0x108].copy_from_slice(&image_len.to_le_bytes());
+    // The card info's filled size
+    if game_offset >= CARD_INFO_END {
+        out.seek(SeekFrom::Start(start + FILLED_SIZE))?;
+        out.write_all(&(end as u32).to_le_bytes())?;
+    }
+    out.seek(SeekFrom::Start(start))?;
+    out.write_all(&header)?;
+    out.seek(SeekFrom::Start(start + end))?;
+    Ok(())
+}
+
+/// Rebuilds the game's NCCH, returning its length.
+fn rebuild_ncch<W>(
+    path: &Path, offset: u64, exheader: &ExHeader, code_patch: &[u8], mut files: Vec<File<Box<[u8]>>>, mut out: W,
+) -> Result<u64>
+where
+    W: Write + Seek,
+{
+    let mut source = fs::File::open(path)?;
+    let mut header = read_slice_from_offset(&mut source, offset, HEADER_LEN)?;
+    if &header[0x100..0x104] != b"NCCH" {
+        return Err(Error::new("The ROM's game partition is not an NCCH."));
+    }
+    if header[FLAGS + 7] & NO_CRYPTO == 0 {
+        return Err(Error::new("The ROM must be decrypted to be rebuilt."));
+    }
+    let original = ExHeader::read_from_offset(&mut source, offset + HEADER_LEN as u64)?;
+    let access_desc = read_slice_from_offset(&mut source, offset + (HEADER_LEN + exheader::LEN) as u64, exheader::LEN)?;
+
+    // Lay the regions out in the order they were in, starting right after the extended header
+    let start = out.stream_position()?;
+    out.write_all(&vec![0; HEADER_LEN + 2 * exheader::LEN])?;
+    let mut regions = [PLAIN, LOGO, EXEFS, ROMFS]
+        .into_iter()
+        .map(|region| unsafe {
+            (
+                region,
+                to_bytes(u32::from_slice_unchecked(&header[region..])),
+                to_bytes(u32::from_slice_unchecked(&header[region + 4..])),
+            )
+        })
+        .filter(|(_, _, len)| *len > 0)
+        .collect::<Vec<_>>();
+    regions.sort_by_key(|(_, region_offset, _)| *region_offset);
+    for (region, region_offset, len) in regions {
+        let pos = out.stream_position()? - start;
+        let region_start = pos.next_multiple_of(if region == ROMFS { ROMFS_ALIGN } else { MEDIA_UNIT });
+        out.write_all(&vec![0; (region_start - pos) as usize])?;
+        match region {
+            EXEFS => {
+                let mut exefs = ExeFs::read(&mut source, offset + region_offset)?;
+                let code = exefs.get_mut(".code")?;
+                if original.is_code_compressed() {
+                    *code = exefs::decompress_code(code)?;
+                }
+                ips::apply(code, code_patch)?;
+                let superblock = exefs.write(&mut out)?;
+                header[0x1A8..0x1AC].copy_from_slice(&to_media_units(superblock.len() as u64).to_le_bytes());
+                header[0x1C0..0x1E0].copy_from_slice(&Sha256::digest(&superblock));
+            },
+            ROMFS => {
+                let mut romfs = RomFs::load(fs::File::open(path)?, (offset + region_offset) as u32)?;
+                let mut builder = RomFsBuilder::from_image(&mut romfs)?;
+                for File { path, inner } in mem::take(&mut files) {
+                    builder.insert(&path, Content::Data(inner));
+                }
+                let (_, superblock) = builder.write_from(&mut out, Some(&mut source))?;
+                header[0x1B8..0x1BC].copy_from_slice(&to_media_units(superblock.len() as u64).to_le_bytes());
+                header[0x1E0..0x200].copy_from_slice(&Sha256::digest(&superblock));
+            },
+            _ => {
+                source.seek(SeekFrom::Start(offset + region_offset))?;
+                copy(&mut source, &mut out, len)?;
+            },
+        }
+        let pos = out.stream_position()? - start;
+        let region_end = pos.next_multiple_of(MEDIA_UNIT);
+        out.write_all(&vec![0; (region_end - pos) as usize])?;
+        header[region..region + 4].copy_from_slice(&to_media_units(region_start).to_le_bytes());
+        header[region + 4..region + 8].copy_from_slice(&to_media_units(region_end - region_start).to_le_bytes());
+    }
+    let len = out.stream_position()? - start;
+
+    // The code is stored decompressed, with the segment sizes grown by the patch
+    let mut exheader = exheader.clone();
+    exheader.set_code_compressed(false);
+    header[0x104..0x108].copy_from_slice(&to_media_units(len).to_le_bytes());
+    header[0x160..0x180].copy_from_slice(&Sha256::digest(exheader.as_ref()));
+    out.seek(SeekFrom::Start(start))?;
+    out.write_all(&header)?;
+    out.write_all(exheader.as_ref())?;
+    out.write_all(&access_desc)?;
+    out.seek(SeekFrom::Start(start + len))?;
+    Ok(len)
+}
+
+fn copy<R, W>(source: R, out: W, len: u64) -> Result<()>
+where
+    R: Read,
+    W: Write,
+{
+    let mut out = out;
+    if io::copy(&mut source.take(len), &mut out)? == len { Ok(()) } else { Err(Error::new("Unexpected end of ROM.")) }
+}
+
+fn to_bytes(media_units: u32) -> u64 {
+    (media_units as u64) << MEDIA_UNIT_SHIFT
+}
+
+fn to_media_units(len: u64) -> u32 {
+    len.div_ceil(MEDIA_UNIT) as u32
+}
+
+const HEADER_LEN: usize = SIGNATURE_LEN as usize + 0x100;
+const PARTITIONS: usize = 0x120;
+const PARTITION_COUNT: usize = 8;
+const CARD_INFO_END: u64 = 0x1000;
+const FILLED_SIZE: u64 = 0x300;
+const FLAGS: usize = 0x188;
+const NO_CRYPTO: u8 = 0x4;
+const PLAIN: usize = 0x190;
+const LOGO: usize = 0x198;
+const EXEFS: usize = 0x1A0;
+const ROMFS: usize = 0x1B0;
+const MEDIA_UNIT: u64 = 1 << MEDIA_UNIT_SHIFT;
+const ROMFS_ALIGN: u64 = 0x1000;
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{super::Cxi, *},
+        std::io::Cursor,
+    };
+
+    const ID: u64 = 0x00040000000EC300;
+
+    /// Builds a small decrypted NCSD image, with an NCCH holding an uncompressed `.code` and a RomFS.
+    fn image() -> Result<Vec<u8>> {
+        let mut ncch = Cursor::new(vec![0; 0x1000]);
+        ncch.seek(SeekFrom::End(0))?;
+        let exefs_offset = ncch.position();
+        ExeFs::new(vec![(".code".into(), vec![0; 0x10])]).write(&mut ncch)?;
+        let exefs_len = ncch.position() - exefs_offset;
+        let romfs_offset = ncch.position().next_multiple_of(ROMFS_ALIGN);
+        ncch.seek(SeekFrom::Start(romfs_offset))?;
+        let romfs_len = [
+            File::new("US/RegionBoot.szs".into(), vec![1; 0x40].into()),
+            File::new("World/Byaml/FlowChart.byaml".into(), vec![2; 0x20].into()),
+        ]
+        .into_iter()
+        .collect::<RomFsBuilder>()
+        .write(&mut ncch)?;
+        let mut ncch = ncch.into_inner();
+        ncch.resize(ncch.len().next_multiple_of(MEDIA_UNIT as usize), 0);
+        let len = ncch.len() as u64;
+
+        ncch[0x100..0x104].copy_from_slice(b"NCCH");
+        ncch[0x104..0x108].copy_from_slice(&to_media_units(len).to_le_bytes());
+        ncch[0x108..0x110].copy_from_slice(&ID.to_le_bytes());
+        ncch[0x118..0x120].copy_from_slice(&ID.to_le_bytes());
+        ncch[FLAGS + 7] = NO_CRYPTO;
+        ncch[EXEFS..EXEFS + 4].copy_from_slice(&to_media_units(exefs_offset).to_le_bytes());
+        ncch[EXEFS + 4..EXEFS + 8].copy_from_slice(&to_media_units(exefs_len).to_le_bytes());
+        ncch[ROMFS..ROMFS + 4].copy_from_slice(&to_media_units(romfs_offset).to_le_bytes());
+        ncch[ROMFS + 4..ROMFS + 8].copy_from_slice(&to_media_units(romfs_len).to_le_bytes());
+        ncch[HEADER_LEN + 0xD] = 1;
+
+        let mut image = vec![0; 0x4000];
+        image[0x100..0x104].copy_from_slice(b"NCSD");
+        image[0x108..0x110].copy_from_slice(&ID.to_le_bytes());
+        image[PARTITIONS..PARTITIONS + 4].copy_from_slice(&to_media_units(0x4000).to_le_bytes());
+        image[PARTITIONS + 4..PARTITIONS + 8].copy_from_slice(&to_media_units(len).to_le_bytes());
+        image.extend(ncch);
+        // A manual partition, which should be carried over after the rebuilt game
+        let manual = image.len() as u64;
+        image[PARTITIONS + 8..PARTITIONS + 0xC].copy_from_slice(&to_media_units(manual).to_le_bytes());
+        image[PARTITIONS + 0xC..PARTITIONS + 0x10].copy_from_slice(&1u32.to_le_bytes());
+        image.extend([3; MEDIA_UNIT as usize]);
+        Ok(image)
+    }
+
+    #[test]
+    fn it_rebuilds_images() -> Result<()> {
+        let dir = std::env::temp_dir().join(format!("rom-ncsd-test-{}", std::process::id()));
+        fs::create_dir_all(&dir)?;
+        let path = dir.join("image.3ds");
+        fs::write(&path, image()?)?;
+
+        let mut exheader = ExHeader::read_from_offset(fs::File::open(&path)?, 0x4200u64)?;
+        exheader.set_code_compressed(false);
+        let mut patch = b"PATCH".to_vec();
+        patch.extend([0, 0, 4, 0, 2, 0xAA, 0xBB]);
+        patch.extend(b"EOF");
+        let files = vec![File::new("US/RegionBoot.szs".into(), vec![4; 0x1234].into())];
+        let mut out = Cursor::new(vec![]);
+        rebuild(&path, &exheader, &patch, files, &mut out)?;
+        let out = out.into_inner();
+        let rebuilt = dir.join("rebuilt.3ds");
+        fs::write(&rebuilt, &out)?;
+
+        let mut cxi = Cxi::open(&rebuilt)?;
+        assert_eq!(cxi.id(), ID);
+        assert!(!cxi.exheader()?.is_code_compressed());
+        let ncch = &out[0x4000..];
+        let exefs = to_bytes(unsafe { u32::from_slice_unchecked(&ncch[EXEFS..]) });
+        let mut exefs = ExeFs::read(Cursor::new(ncch), exefs)?;
+        assert_eq!(exefs.get_mut(".code")?, &[0, 0, 0, 0, 0xAA, 0xBB, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(&ncch[0x160..0x180], &Sha256::digest(&ncch[HEADER_LEN..HEADER_LEN + exheader::LEN])[..]);
+
+        let manual = to_bytes(unsafe { u32::from_slice_unchecked(&out[PARTITIONS + 8..]) }) as usize;
+        assert_eq!(&out[manual..], &[3; MEDIA_UNIT as usize]);
+        let mut romfs = cxi.try_into_romfs()?;
+        assert_eq!(romfs.read("US/RegionBoot.szs")?.get(), &vec![4; 0x1234].into_boxed_slice());
+        assert_eq!(romfs.read("World/Byaml/FlowChart.byaml")?.get(), &vec![2; 0x20].into_boxed_slice());
+        fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+}
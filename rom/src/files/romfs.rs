@@ -1,23 +1,97 @@
 use {
-    super::File,
+    super::{align, File},
     crate::{Error, Result},
     bytey::*,
-    std::io::prelude::*,
+    sha2::{Digest, Sha256},
+    std::{
+        collections::{BTreeMap, VecDeque},
+        fs,
+        io::{self, prelude::*, SeekFrom},
+        mem,
+        path::PathBuf,
+    },
 };
 
+/// The game's read-only filesystem, either embedded in a ROM image or extracted to a directory.
 #[derive(Debug)]
 pub struct RomFs<R> {
+    source: Source<R>,
+}
+
+#[derive(Debug)]
+enum Source<R> {
+    Image(Image<R>),
+    Directory(PathBuf),
+}
+
+impl<R> RomFs<R>
+where
+    R: Read + Seek,
+{
+    /// Loads the RomFS image starting at the given offset of a file.
+    pub fn load(file: R, offset: u32) -> Result<Self> {
+        Ok(Self { source: Source::Image(Image::load(file, offset)?) })
+    }
+
+    pub fn read<P>(&mut self, path: P) -> Result<File<Box<[u8]>>>
+    where
+        P: Into<String>,
+    {
+        let path = path.into();
+        if path.ends_with('/') {
+            panic!("Attempt to open a directory.")
+        }
+        match &mut self.source {
+            Source::Image(image) => image.read(path),
+            Source::Directory(root) => match fs::read(root.join(path.strip_prefix('/').unwrap_or(&path))) {
+                Ok(data) => Ok(File::new(path, data.into())),
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                    Err(Error::new(format!("File not found: '{}'.", path)))
+                },
+                Err(err) => Err(err.into()),
+            },
+        }
+    }
+
+    /// Checks whether a directory exists at the given path.
+    pub fn contains_dir(&mut self, path: &str) -> bool {
+        let path = path.trim_matches('/');
+        match &mut self.source {
+            Source::Image(image) => image.find_dir(Some(path), 0).is_ok(),
+            Source::Directory(root) => root.join(path).is_dir(),
+        }
+    }
+}
+
+impl RomFs<fs::File> {
+    /// Uses a directory the RomFS has already been extracted to.
+    pub fn from_dir<P>(path: P) -> Result<Self>
+    where
+        P: Into<PathBuf>,
+    {
+        let path = path.into();
+        if path.is_dir() {
+            Ok(Self { source: Source::Directory(path) })
+        } else {
+            Err(Error::new(format!("RomFS directory not found: '{}'.", path.display())))
+        }
+    }
+}
+
+/// A RomFS image, as stored in a ROM.
+#[derive(Debug)]
+struct Image<R> {
     file: R,
     directories: Section,
     files: Section,
     file_data: u32,
 }
 
-impl<R> RomFs<R>
+impl<R> Image<R>
 where
     R: Read + Seek,
 {
-    pub fn load(mut file: R, offset: u32) -> Result<Self> {
+    fn load(mut file: R, offset: u32) -> Result<Self> {
         typedef! { struct IVFC: TryFromBytes<'_> [HEADER_LEN] {
             #b"IVFC",
             [0x4C] block_size: u32,
@@ -36,27 +110,53 @@ where
         Ok(Self { file, directories, files, file_data: l3 + header.file_data })
     }
 
-    pub fn read<P>(&mut self, path: P) -> Result<File<Box<[u8]>>>
-    where
-        P: Into<String>,
-    {
-        let path = path.into();
-        if path.ends_with('/') {
-            panic!("Attempt to open a directory.")
-        } else {
-            let (parent, file) = {
-                let path = path.strip_prefix('/').unwrap_or(&path);
-                let mut split = path.rsplitn(2, '/');
-                let file = split.next().expect("Attempt to read empty file name.");
-                (self.find_dir(split.next(), 0)?, file)
-            };
-            let (offset, length) =
-                self.find_file(parent, file)?.ok_or_else(|| Error::new(format!("File not found: '{}'.", path)))?;
-            Ok(File::new(
-                path,
-                read_slice_from_offset(&mut self.file, self.file_data + offset as u32, length as usize)?,
-            ))
+    fn read(&mut self, path: String) -> Result<File<Box<[u8]>>> {
+        let (parent, file) = {
+            let path = path.strip_prefix('/').unwrap_or(&path);
+            let mut split = path.rsplitn(2, '/');
+            let file = split.next().expect("Attempt to read empty file name.");
+            (self.find_dir(split.next(), 0)?, file)
+        };
+        let (offset, length) =
+            self.find_file(parent, file)?.ok_or_else(|| Error::new(format!("File not found: '{}'.", path)))?;
+        Ok(File::new(path, read_slice_from_offset(&mut self.file, self.file_data + offset as u32, length as usize)?))
+    }
+
+    /// Lists every file in the image, along with where its data is found in the underlying file.
+    fn entries(&mut self) -> Result<Vec<(String, Content)>> {
+        typedef! { struct DirMetadata: FromBytes<'_> [0x18] {
+            [4] next: u32,
+            [8] child: u32,
+            [0xC] file: u32,
+            [0x14] name_len: u32,
+        }}
+        typedef! { struct FileMetadata: FromBytes<'_> [0x20] {
+            [4] next: u32,
+            [8] offset: u64,
+            [0x10] length: u64,
+            [0x1C] name_len: u32,
+        }}
+        let mut entries = vec![];
+        let mut dirs = vec![(0, String::new())];
+        while let Some((offset, path)) = dirs.pop() {
+            let dir = DirMetadata::read_from_offset(&mut self.file, self.directories.metadata + offset)?;
+            let mut child = dir.child;
+            while child != EMPTY {
+                let metadata = DirMetadata::read_from_offset(&mut self.file, self.directories.metadata + child)?;
+                let name = read_name(&mut self.file, metadata.name_len)?;
+                dirs.push((child, format!("{}{}/", path, name)));
+                child = metadata.next;
+            }
+            let mut file = dir.file;
+            while file != EMPTY {
+                let metadata = FileMetadata::read_from_offset(&mut self.file, self.files.metadata + file)?;
+                let name = read_name(&mut self.file, metadata.name_len)?;
+                let offset = self.file_data as u64 + metadata.offset;
+                entries.push((format!("{}{}", path, name), Content::Image { offset, len: metadata.length }));
+                file = metadata.next;
+            }
         }
+        Ok(entries)
     }
 
     fn find_dir(&mut self, name: Option<&str>, parent: u32) -> Result<u32> {
@@ -66,7 +166,7 @@ where
             let child = split.next();
             let i = (hash(dirname, parent) % self.directories.count) as usize;
             let mut offset = unsafe { u32::from_slice_unchecked(&self.directories.hashtable[i * 4..]) };
-            while offset != 0xFFFFFFFF {
+            while offset != EMPTY {
                 typedef! { struct Metadata: FromBytes<'_> [0x18] {
                     [0] parent: u32,
                     [0x10] next: u32,
@@ -89,7 +189,7 @@ where
     fn find_file(&mut self, parent: u32, filename: &str) -> Result<Option<(u64, u64)>> {
         let i = (hash(filename, parent) % self.files.count) as usize;
         let mut offset = unsafe { u32::from_slice_unchecked(&self.files.hashtable[i * 4..]) };
-        while offset != 0xFFFFFFFF {
+        while offset != EMPTY {
             typedef! { struct Metadata: FromBytes<'_> [0x20] {
                 [0] parent: u32,
                 [8] offset: u64,
@@ -137,6 +237,390 @@ impl Section {
     }
 }
 
+/// Lays out and writes a new RomFS image from a tree of files.
+#[derive(Debug, Default)]
+pub struct RomFsBuilder {
+    root: Dir,
+}
+
+#[derive(Debug, Default)]
+struct Dir {
+    dirs: BTreeMap<String, Dir>,
+    files: BTreeMap<String, Content>,
+}
+
+/// Where the data of a file being written to a RomFS image comes from.
+#[derive(Debug)]
+pub(crate) enum Content {
+    Data(Box<[u8]>),
+    /// A range of the file that an existing image is read from.
+    Image {
+        offset: u64,
+        len: u64,
+    },
+}
+
+impl Content {
+    fn len(&self) -> u64 {
+        match self {
+            Self::Data(data) => data.len() as u64,
+            Self::Image { len, .. } => *len,
+        }
+    }
+}
+
+impl RomFsBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a file to the image at its path, replacing any file already there.
+    pub fn add(&mut self, file: File<Box<[u8]>>) {
+        self.insert(&file.path, Content::Data(file.inner));
+    }
+
+    /// Writes the image at the writer's current position, returning its length.
+    pub fn write<W>(self, out: W) -> Result<u64>
+    where
+        W: Write + Seek,
+    {
+        Ok(self.write_from(out, None::<io::Empty>)?.0)
+    }
+
+    /// Starts a new image with all the files of an existing one, whose data is copied from `source` when written.
+    pub(crate) fn from_image<R>(romfs: &mut RomFs<R>) -> Result<Self>
+    where
+        R: Read + Seek,
+    {
+        let mut builder = Self::default();
+        match &mut romfs.source {
+            Source::Image(image) => {
+                for (path, content) in image.entries()? {
+                    builder.insert(&path, content);
+                }
+            },
+            Source::Directory(_) => {
+                return Err(Error::new("Cannot rebuild a RomFS that was extracted to a directory."));
+            },
+        }
+        Ok(builder)
+    }
+
+    /// Adds a file to the image, replacing any file already at its path.
+    pub(crate) fn insert(&mut self, path: &str, content: Content) {
+        let path = path.strip_prefix('/').unwrap_or(path);
+        let mut split = path.rsplitn(2, '/');
+        let name = split.next().expect("Attempt to insert empty file name.");
+        let dir = split
+            .next()
+            .into_iter()
+            .flat_map(|dirs| dirs.split('/'))
+            .fold(&mut self.root, |dir, name| dir.dirs.entry(name.to_owned()).or_default());
+        dir.files.insert(name.to_owned(), content);
+    }
+
+    /// Writes the image at the writer's current position, copying the data of `Content::Image` files from `source`.
+    ///
+    /// Returns the length of the image and its superblock, the IVFC header and master hash that the NCCH header
+    /// hashes to verify the rest of the hash tree.
+    pub(crate) fn write_from<W, R>(self, mut out: W, mut source: Option<R>) -> Result<(u64, Box<[u8]>)>
+    where
+        W: Write + Seek,
+        R: Read + Seek,
+    {
+        let level3 = Level3::new(self.root);
+        let l3_len = level3.len();
+        let l2_len = hash_len(l3_len);
+        let l1_len = hash_len(l2_len);
+        let master_len = hash_len(l1_len);
+
+        // The physical order of the levels is the master hash, level 3, level 1, then level 2
+        let start = out.stream_position()?;
+        out.write_all(&vec![0; align_u64(MASTER_HASH_OFFSET + master_len, BLOCK_LEN) as usize])?;
+        let mut writer = HashWriter::new(&mut out);
+        level3.write(&mut writer, &mut source)?;
+        let l2 = writer.finish()?;
+        let l1 = hash_blocks(&l2);
+        let master = hash_blocks(&l1);
+        for level in [l1, l2] {
+            out.write_all(&level)?;
+            out.write_all(&vec![0; (align_u64(level.len() as u64, BLOCK_LEN) - level.len() as u64) as usize])?;
+        }
+        let end = out.stream_position()?;
+
+        let mut superblock = vec![0; align_u64(MASTER_HASH_OFFSET + master_len, MEDIA_UNIT_LEN) as usize];
+        superblock[..4].copy_from_slice(b"IVFC");
+        superblock[4..8].copy_from_slice(&IVFC_ID.to_le_bytes());
+        superblock[8..0xC].copy_from_slice(&(master_len as u32).to_le_bytes());
+        let l2_offset = align_u64(l1_len, BLOCK_LEN);
+        let l3_offset = align_u64(l2_offset + l2_len, BLOCK_LEN);
+        for (i, (offset, len)) in [(0, l1_len), (l2_offset, l2_len), (l3_offset, l3_len)].into_iter().enumerate() {
+            let level = &mut superblock[0xC + i * 0x18..];
+            level[..8].copy_from_slice(&offset.to_le_bytes());
+            level[8..0x10].copy_from_slice(&len.to_le_bytes());
+            level[0x10..0x14].copy_from_slice(&BLOCK_LEN_LOG2.to_le_bytes());
+        }
+        superblock[0x54..0x58].copy_from_slice(&(HEADER_LEN as u32).to_le_bytes());
+        superblock[MASTER_HASH_OFFSET as usize..][..master.len()].copy_from_slice(&master);
+
+        out.seek(SeekFrom::Start(start))?;
+        out.write_all(&superblock)?;
+        out.seek(SeekFrom::Start(end))?;
+        Ok((end - start, superblock.into()))
+    }
+}
+
+impl FromIterator<File<Box<[u8]>>> for RomFsBuilder {
+    fn from_iter<I>(files: I) -> Self
+    where
+        I: IntoIterator<Item = File<Box<[u8]>>>,
+    {
+        let mut builder = Self::new();
+        for file in files {
+            builder.add(file);
+        }
+        builder
+    }
+}
+
+/// The directory and file tables of a RomFS image, laid out ahead of writing it.
+struct Level3 {
+    header: [u8; L3_HEADER_LEN],
+    tables: Vec<u8>,
+    files: Vec<Content>,
+}
+
+impl Level3 {
+    fn new(root: Dir) -> Self {
+        // Flatten the tree breadth first, so that each directory's children are next to each other
+        let mut dirs = vec![];
+        let mut queue = VecDeque::from([(String::new(), 0, root)]);
+        while let Some((name, parent, dir)) = queue.pop_front() {
+            let index = dirs.len();
+            queue.extend(dir.dirs.into_iter().map(|(name, dir)| (name, index, dir)));
+            dirs.push((name, parent, dir.files.into_iter().collect::<Vec<_>>()));
+        }
+        let mut children = vec![vec![]; dirs.len()];
+        for (index, (_, parent, _)) in dirs.iter().enumerate().skip(1) {
+            children[*parent].push(index);
+        }
+
+        let dir_offsets = offsets(dirs.iter().map(|(name, ..)| DIR_ENTRY_LEN + name_len(name)));
+        let file_offsets =
+            offsets(dirs.iter().flat_map(|(.., files)| files).map(|(name, _)| FILE_ENTRY_LEN + name_len(name)));
+        let mut dir_table = Table::new(dirs.len());
+        let mut file_table = Table::new(file_offsets.len());
+        let mut files = vec![];
+        let mut data_len = 0;
+        for (index, (name, parent, dir_files)) in dirs.into_iter().enumerate() {
+            let next = children[parent].iter().skip_while(|&&sibling| sibling != index).nth(1);
+            let fields = [
+                next.map_or(EMPTY, |&next| dir_offsets[next]),
+                children[index].first().map_or(EMPTY, |&child| dir_offsets[child]),
+                if dir_files.is_empty() { EMPTY } else { file_offsets[files.len()] },
+            ];
+            dir_table.push(&name, dir_offsets[parent], &fields.map(u32::to_le_bytes).concat());
+
+            let count = dir_files.len();
+            for (i, (name, content)) in dir_files.into_iter().enumerate() {
+                let next = if i + 1 < count { file_offsets[files.len() + 1] } else { EMPTY };
+                data_len = align_u64(data_len, FILE_ALIGN);
+                let fields = [&next.to_le_bytes()[..], &data_len.to_le_bytes(), &content.len().to_le_bytes()];
+                file_table.push(&name, dir_offsets[index], &fields.concat());
+                data_len += content.len();
+                files.push(content);
+            }
+        }
+
+        let mut header = [0; L3_HEADER_LEN];
+        let mut tables = vec![];
+        let mut offset = L3_HEADER_LEN as u32;
+        let sections = [dir_table.buckets(), dir_table.entries, file_table.buckets(), file_table.entries];
+        for (i, section) in sections.into_iter().enumerate() {
+            header[4 + i * 8..][..4].copy_from_slice(&offset.to_le_bytes());
+            header[8 + i * 8..][..4].copy_from_slice(&(section.len() as u32).to_le_bytes());
+            offset += section.len() as u32;
+            tables.extend(section);
+        }
+        let file_data = align::<{ FILE_ALIGN as u32 }>(offset);
+        tables.resize(file_data as usize - L3_HEADER_LEN, 0);
+        header[..4].copy_from_slice(&(L3_HEADER_LEN as u32).to_le_bytes());
+        header[0x24..].copy_from_slice(&file_data.to_le_bytes());
+        Self { header, tables, files }
+    }
+
+    fn len(&self) -> u64 {
+        let data_len = self.files.iter().fold(0, |len, file| align_u64(len, FILE_ALIGN) + file.len());
+        (L3_HEADER_LEN + self.tables.len()) as u64 + data_len
+    }
+
+    fn write<W, R>(self, mut out: W, source: &mut Option<R>) -> Result<()>
+    where
+        W: Write,
+        R: Read + Seek,
+    {
+        out.write_all(&self.header)?;
+        out.write_all(&self.tables)?;
+        let mut written = 0;
+        for file in self.files {
+            let pad = align_u64(written, FILE_ALIGN) - written;
+            out.write_all(&[0; FILE_ALIGN as usize][..pad as usize])?;
+            written += pad + file.len();
+            match file {
+                Content::Data(data) => out.write_all(&data)?,
+                Content::Image { offset, len } => {
+                    let source = source.as_mut().ok_or_else(|| Error::new("No image to copy file data from."))?;
+                    source.seek(SeekFrom::Start(offset))?;
+                    if io::copy(&mut source.take(len), &mut out)? != len {
+                        return Err(Error::new("Unexpected end of RomFS file data."));
+                    }
+                },
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The entries of one of the directory or file tables, and the hash table used to look them up.
+struct Table {
+    buckets: Vec<u32>,
+    entries: Vec<u8>,
+}
+
+impl Table {
+    fn new(count: usize) -> Self {
+        Self { buckets: vec![EMPTY; hash_table_len(count as u32) as usize], entries: vec![] }
+    }
+
+    /// Appends an entry: its parent's offset, its own `fields`, the next entry in its hash bucket, then its name.
+    fn push(&mut self, name: &str, parent: u32, fields: &[u8]) {
+        let offset = self.entries.len() as u32;
+        let bucket = (hash(name, parent) % self.buckets.len() as u32) as usize;
+        let name = name.encode_utf16().flat_map(u16::to_le_bytes).collect::<Vec<_>>();
+        self.entries.extend(parent.to_le_bytes());
+        self.entries.extend(fields);
+        self.entries.extend(mem::replace(&mut self.buckets[bucket], offset).to_le_bytes());
+        self.entries.extend((name.len() as u32).to_le_bytes());
+        self.entries.extend(name);
+        self.entries.resize(align::<4>(self.entries.len() as u32) as usize, 0);
+    }
+
+    fn buckets(&self) -> Vec<u8> {
+        self.buckets.iter().flat_map(|bucket| bucket.to_le_bytes()).collect()
+    }
+}
+
+/// Passes data through to a writer, hashing each block of it for the level of the hash tree above.
+struct HashWriter<W> {
+    inner: W,
+    hasher: Sha256,
+    block: u64,
+    hashes: Vec<u8>,
+}
+
+impl<W> HashWriter<W>
+where
+    W: Write,
+{
+    fn new(inner: W) -> Self {
+        Self { inner, hasher: Sha256::new(), block: 0, hashes: vec![] }
+    }
+
+    /// Pads the last block with zeros, then returns the hashes of every block written.
+    fn finish(mut self) -> io::Result<Vec<u8>> {
+        if self.block > 0 {
+            self.write_all(&vec![0; (BLOCK_LEN - self.block) as usize])?;
+        }
+        Ok(self.hashes)
+    }
+}
+
+impl<W> Write for HashWriter<W>
+where
+    W: Write,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let len = buf.len().min((BLOCK_LEN - self.block) as usize);
+        let len = self.inner.write(&buf[..len])?;
+        self.hasher.update(&buf[..len]);
+        self.block += len as u64;
+        if self.block == BLOCK_LEN {
+            self.hashes.extend(self.hasher.finalize_reset());
+            self.block = 0;
+        }
+        Ok(len)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Hashes each block of a level of the hash tree, padding the last one with zeros.
+fn hash_blocks(level: &[u8]) -> Vec<u8> {
+    level
+        .chunks(BLOCK_LEN as usize)
+        .flat_map(|block| {
+            let mut hasher = Sha256::new();
+            hasher.update(block);
+            hasher.update(vec![0; BLOCK_LEN as usize - block.len()]);
+            hasher.finalize()
+        })
+        .collect()
+}
+
+/// Gets the length of the level of the hash tree holding the hashes of a level with the given length.
+fn hash_len(len: u64) -> u64 {
+    len.div_ceil(BLOCK_LEN) * HASH_LEN
+}
+
+/// Gets the number of buckets to use in a hash table with the given number of entries.
+fn hash_table_len(count: u32) -> u32 {
+    if count < 3 {
+        3
+    } else if count < 19 {
+        count | 1
+    } else {
+        let mut count = count;
+        while [2, 3, 5, 7, 11, 13, 17].iter().any(|prime| count.is_multiple_of(*prime)) {
+            count += 1;
+        }
+        count
+    }
+}
+
+/// Gets the offset of each entry of a table, given the length of each.
+fn offsets<I>(lens: I) -> Vec<u32>
+where
+    I: IntoIterator<Item = u32>,
+{
+    lens.into_iter()
+        .scan(0, |offset, len| {
+            let this = *offset;
+            *offset += len;
+            Some(this)
+        })
+        .collect()
+}
+
+/// Gets the length of a name in a table entry, padded to 4 bytes.
+fn name_len(name: &str) -> u32 {
+    align::<4>(name.encode_utf16().count() as u32 * 2)
+}
+
+fn read_name<R>(read: R, len: u32) -> Result<String>
+where
+    R: Read,
+{
+    let name = read_slice(read, len as usize)?;
+    let name = name.chunks_exact(2).map(|ch| unsafe { u16::from_slice_unchecked(ch) }).collect::<Vec<_>>();
+    String::from_utf16(&name).map_err(Error::new)
+}
+
+fn align_u64(value: u64, align: u64) -> u64 {
+    value.div_ceil(align) * align
+}
+
 fn hash(name: &str, seed: u32) -> u32 {
     name.encode_utf16().fold(seed ^ 123456789, |hash, ch| (hash.wrapping_shr(5) | hash.wrapping_shl(27)) ^ (ch as u32))
 }
@@ -147,3 +631,85 @@ fn path_eq(this: &[u8], other: &str) -> bool {
 
 const HEADER_LEN: usize = 0x5C;
 const L3_HEADER_LEN: usize = 0x28;
+const IVFC_ID: u32 = 0x10000;
+const MASTER_HASH_OFFSET: u64 = 0x60;
+const BLOCK_LEN_LOG2: u32 = 12;
+const BLOCK_LEN: u64 = 1 << BLOCK_LEN_LOG2;
+const HASH_LEN: u64 = 0x20;
+const MEDIA_UNIT_LEN: u64 = 0x200;
+const DIR_ENTRY_LEN: u32 = 0x18;
+const FILE_ENTRY_LEN: u32 = 0x20;
+const FILE_ALIGN: u64 = 0x10;
+const EMPTY: u32 = 0xFFFFFFFF;
+
+#[cfg(test)]
+mod tests {
+    use {super::*, std::io::Cursor};
+
+    #[test]
+    fn it_sizes_hash_tables() {
+        for (count, len) in [(0, 3), (2, 3), (5, 5), (6, 7), (18, 19), (19, 19), (20, 23), (100, 101)] {
+            assert_eq!(hash_table_len(count), len);
+        }
+    }
+
+    #[test]
+    fn it_hashes_each_level() -> Result<()> {
+        let builder = (0..0x40)
+            .map(|i| File::new(format!("Dir{}/File{}.bin", i % 4, i), vec![i as u8; 0x300].into()))
+            .collect::<RomFsBuilder>();
+        let mut image = Cursor::new(vec![]);
+        let (len, superblock) = builder.write_from(&mut image, None::<io::Empty>)?;
+        let image = image.into_inner();
+        assert_eq!(len, image.len() as u64);
+        assert_eq!(&image[..superblock.len()], &*superblock);
+
+        let level = |i: usize| unsafe {
+            let level = &superblock[0xC + i * 0x18..];
+            (u64::from_slice_unchecked(level), u64::from_slice_unchecked(&level[8..]))
+        };
+        let master_len = unsafe { u32::from_slice_unchecked(&superblock[8..]) } as usize;
+        let body = align_u64(MASTER_HASH_OFFSET + master_len as u64, BLOCK_LEN) as usize;
+        let [(l1_offset, l1_len), (l2_offset, l2_len), (l3_offset, l3_len)] = [0, 1, 2].map(level);
+        let slice = |offset: u64, len: u64| {
+            // Level 3 comes first in the image, followed by levels 1 and 2
+            let physical = if offset == l3_offset { 0 } else { offset + align_u64(l3_len, BLOCK_LEN) };
+            &image[body + physical as usize..][..len as usize]
+        };
+        assert_eq!(hash_blocks(slice(l3_offset, l3_len)), slice(l2_offset, l2_len));
+        assert_eq!(hash_blocks(slice(l2_offset, l2_len)), slice(l1_offset, l1_len));
+        assert_eq!(hash_blocks(slice(l1_offset, l1_len)), &superblock[MASTER_HASH_OFFSET as usize..][..master_len]);
+        Ok(())
+    }
+
+    #[test]
+    fn it_finds_directories() -> Result<()> {
+        let builder = ["EU_English/Boot.szs", "EU_German/Boot.szs", "EU/RegionBoot.szs"]
+            .iter()
+            .map(|path| File::new(path.to_string(), vec![0; 4].into()))
+            .collect::<RomFsBuilder>();
+        let mut image = Cursor::new(vec![]);
+        builder.write(&mut image)?;
+        let mut romfs = RomFs::load(image, 0)?;
+        assert!(romfs.contains_dir("EU_English"));
+        assert!(romfs.contains_dir("EU_German"));
+        assert!(!romfs.contains_dir("EU_French"));
+        assert!(!romfs.contains_dir("US_English"));
+        Ok(())
+    }
+
+    #[test]
+    fn it_buckets_entries_by_hash() -> Result<()> {
+        let names = ["a.bin", "b.bin", "c.bin", "d.bin", "e.bin", "f.bin"];
+        let builder = names.iter().map(|name| File::new(name.to_string(), vec![0; 4].into())).collect::<RomFsBuilder>();
+        let mut image = Cursor::new(vec![]);
+        builder.write(&mut image)?;
+        let image = Image::load(&mut image, 0)?;
+        assert_eq!(image.files.count, hash_table_len(names.len() as u32));
+        for name in names {
+            let bucket = (hash(name, 0) % image.files.count) as usize;
+            assert_ne!(unsafe { u32::from_slice_unchecked(&image.files.hashtable[bucket * 4..]) }, EMPTY);
+        }
+        Ok(())
+    }
+}
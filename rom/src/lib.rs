@@ -4,10 +4,12 @@ use std::{
     cell::RefCell,
     error::Error as StdError,
     fmt::{self, Display, Formatter},
-    fs, io,
-    path::Path,
+    fs,
+    io::{self, Seek, Write},
+    path::{Path, PathBuf},
 };
 
+use bytey::FromBytesOwned;
 use log::info;
 use path_absolutize::*;
 
@@ -20,9 +22,16 @@ pub use {
     actors::{Actor, Actors},
     course::Course,
     demo::Demo,
-    files::{byaml, exheader::ExHeader, romfs::RomFs, sarc::Sarc, Cxi, File, IntoBytes},
+    files::{
+        byaml,
+        exheader::ExHeader,
+        romfs::{RomFs, RomFsBuilder},
+        sarc::Sarc,
+        Cxi, File, IntoBytes,
+    },
     item::GetItem,
     language::Language,
+    region::Region,
     scene::{Scene, Stage},
 };
 
@@ -37,6 +46,7 @@ pub mod flag;
 pub mod flow;
 pub mod item;
 pub mod language;
+pub mod region;
 pub mod scene;
 
 pub type Result<T, E = Error> = ::std::result::Result<T, E>;
@@ -100,7 +110,10 @@ pub enum ErrorKind {
 #[derive(Debug)]
 #[allow(dead_code)]
 pub struct Rom {
+    path: PathBuf,
     id: u64,
+    region: Region,
+    language_dir: &'static str,
     exheader: ExHeader,
     romfs: RefCell<RomFs<fs::File>>,
     flow_chart: File<FlowChart>,
@@ -111,6 +124,9 @@ pub struct Rom {
 impl Rom {
     /// Loads the game from a ROM.
     ///
+    /// The ROM may be a decrypted NCSD (.3ds) image, a decrypted CIA, or a directory holding an extracted `romfs`
+    /// directory and `exheader.bin`.
+    ///
     /// Fails if the ROM is invalid for any reason, including general
     /// corruption, mismatched IDs, invalid region, etc.
     pub fn load<P>(path: P) -> Result<Self>
@@ -119,22 +135,49 @@ impl Rom {
     {
         let path = path.as_ref().to_path_buf();
         info!("Loading ROM from:               {}", &path.absolutize()?.display());
-        let mut cxi = Cxi::open(&path)?;
-        if cxi.id() == US_ID {
-            let id = cxi.id();
-            let exheader = cxi.exheader()?;
-            let mut romfs = cxi.try_into_romfs()?;
-            let region_boot = romfs.read("US/RegionBoot.szs")?.map(Sarc::from);
-            let flow_chart =
-                region_boot.get().read("World/Byaml/FlowChart.byaml")?.try_map(|data| byaml::from_bytes(&data))?;
-            let get_item =
-                region_boot.get().read("World/Byaml/GetItem.byaml")?.try_map(|data| byaml::from_bytes(&data))?;
-            let message =
-                region_boot.get().read("World/Byaml/Message.byaml")?.try_map(|data| byaml::from_bytes(&data))?;
-            Ok(Self { id, exheader, romfs: RefCell::new(romfs), flow_chart, get_item, message })
+        let (id, exheader, mut romfs) = if path.is_dir() {
+            let exheader = ExHeader::read(fs::File::open(path.join("exheader.bin"))?)?;
+            (exheader.get_program_id(), exheader, RomFs::from_dir(path.join("romfs"))?)
         } else {
-            Err(Error::new("Invalid ROM ID."))
-        }
+            let mut cxi = Cxi::open(&path)?;
+            (cxi.id(), cxi.exheader()?, cxi.try_into_romfs()?)
+        };
+        let region = Region::from_id(id).ok_or_else(|| Error::new("Invalid ROM ID."))?;
+        info!("ROM Region:                     {}", region);
+        let language_dir = region
+            .language_dirs()
+            .iter()
+            .copied()
+            .find(|dir| romfs.contains_dir(dir))
+            .ok_or_else(|| Error::new(format!("No language archives found for the {} region.", region)))?;
+        info!("ROM Language:                   {}", language_dir);
+        let region_boot = romfs.read(format!("{}/RegionBoot.szs", region.boot_dir()))?.map(Sarc::from);
+        let flow_chart =
+            region_boot.get().read("World/Byaml/FlowChart.byaml")?.try_map(|data| byaml::from_bytes(&data))?;
+        let get_item = region_boot.get().read("World/Byaml/GetItem.byaml")?.try_map(|data| byaml::from_bytes(&data))?;
+        let message = region_boot.get().read("World/Byaml/Message.byaml")?.try_map(|data| byaml::from_bytes(&data))?;
+        Ok(Self { path, id, region, language_dir, exheader, romfs: RefCell::new(romfs), flow_chart, get_item, message })
+    }
+
+    /// Reads just the region of a ROM from its title ID, without loading the rest of the game.
+    ///
+    /// Accepts the same kinds of ROM as [`Rom::load`].
+    pub fn read_region<P>(path: P) -> Result<Region>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let id = if path.is_dir() {
+            ExHeader::read(fs::File::open(path.join("exheader.bin"))?)?.get_program_id()
+        } else {
+            Cxi::open(path)?.id()
+        };
+        Region::from_id(id).ok_or_else(|| Error::new("Invalid ROM ID."))
+    }
+
+    /// Gets the path the ROM was loaded from.
+    pub fn path(&self) -> &Path {
+        &self.path
     }
 
     /// Gets the 64-bit title ID.
@@ -142,11 +185,32 @@ impl Rom {
         self.id
     }
 
+    /// Gets the region, as detected from the title ID.
+    pub fn region(&self) -> Region {
+        self.region
+    }
+
+    /// Gets the RomFS directory of the language archives being patched, the first of the region's present in the ROM.
+    pub fn language_dir(&self) -> &'static str {
+        self.language_dir
+    }
+
     /// Gets the ROM's extended header.
     pub fn exheader(&self) -> &ExHeader {
         &self.exheader
     }
 
+    /// Writes a complete, patched NCSD (.3ds) image of the game.
+    ///
+    /// The extended header replaces the ROM's, the IPS patch is applied to the game's code, and the files replace
+    /// those at the same paths in the RomFS. Only ROMs loaded from a .3ds file can be rebuilt.
+    pub fn rebuild<W>(&self, exheader: &ExHeader, code_patch: &[u8], files: Vec<File<Box<[u8]>>>, out: W) -> Result<()>
+    where
+        W: Write + Seek,
+    {
+        files::rebuild(&self.path, exheader, code_patch, files, out)
+    }
+
     pub fn get_get_item(&mut self, item: Item) -> GetItem {
         self.get_item.get()[item as usize].clone()
     }
@@ -178,7 +242,8 @@ impl Rom {
 
     pub fn boot(&self) -> Result<Language> {
         let flow = self.flow_chart.get().load().boot()?.iter().cloned();
-        let archive = self.romfs.borrow_mut().read("US/RegionBoot.szs")?.map(Sarc::from);
+        let path = format!("{}/RegionBoot.szs", self.region.boot_dir());
+        let archive = self.romfs.borrow_mut().read(path)?.map(Sarc::from);
         Ok(Language::new(flow, archive))
     }
 
@@ -196,7 +261,8 @@ impl Rom {
 
     pub fn language(&self, course: CourseId) -> Result<Language> {
         let flow = self.flow_chart.get().load().course(course).unwrap_or_default().iter().cloned();
-        let archive = self.romfs.borrow_mut().read(format!("US_English/{}.szs", course.as_str()))?.map(Sarc::from);
+        let path = format!("{}/{}.szs", self.language_dir, course.as_str());
+        let archive = self.romfs.borrow_mut().read(path)?.map(Sarc::from);
         Ok(Language::new(flow, archive))
     }
 
@@ -236,8 +302,6 @@ impl Rom {
     }
 }
 
-const US_ID: u64 = 0x00040000000EC300;
-
 #[macro_export]
 macro_rules! string_constants {
     (
@@ -0,0 +1,79 @@
+use std::fmt::{self, Display, Formatter};
+
+/// The region of a ROM, which decides where its region-specific files are found in the RomFS.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Region {
+    US,
+    EU,
+    JP,
+}
+
+impl Region {
+    /// Gets the region of the ROM with the given title ID, if it's a version of the game.
+    pub fn from_id(id: u64) -> Option<Self> {
+        match id {
+            US_ID => Some(Self::US),
+            EU_ID => Some(Self::EU),
+            JP_ID => Some(Self::JP),
+            _ => None,
+        }
+    }
+
+    /// Gets the 64-bit title ID of this region's version of the game.
+    pub fn id(self) -> u64 {
+        match self {
+            Self::US => US_ID,
+            Self::EU => EU_ID,
+            Self::JP => JP_ID,
+        }
+    }
+
+    /// The RomFS directory holding this region's `RegionBoot.szs`.
+    pub fn boot_dir(self) -> &'static str {
+        match self {
+            Self::US => "US",
+            Self::EU => "EU",
+            Self::JP => "JP",
+        }
+    }
+
+    /// The RomFS directories this region may hold language archives in, in order of preference for the randomizer's
+    /// English text. A ROM only ships the languages it was sold with, so the first one present is used.
+    pub fn language_dirs(self) -> &'static [&'static str] {
+        match self {
+            Self::US => &["US_English", "US_French", "US_Spanish"],
+            Self::EU => &["EU_English", "EU_French", "EU_German", "EU_Italian", "EU_Spanish"],
+            Self::JP => &["JP_Japanese"],
+        }
+    }
+}
+
+impl Display for Region {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.boot_dir())
+    }
+}
+
+const US_ID: u64 = 0x00040000000EC300;
+const EU_ID: u64 = 0x00040000000EC400;
+const JP_ID: u64 = 0x00040000000EC200;
+
+#[cfg(test)]
+mod tests {
+    use super::Region;
+
+    #[test]
+    fn it_detects_regions() {
+        for region in [Region::US, Region::EU, Region::JP] {
+            assert_eq!(Region::from_id(region.id()), Some(region));
+        }
+        assert_eq!(Region::from_id(0x0004000000000000), None);
+    }
+
+    #[test]
+    fn it_prefers_english_text() {
+        assert_eq!(Region::US.language_dirs()[0], "US_English");
+        assert_eq!(Region::EU.language_dirs()[0], "EU_English");
+        assert_eq!(Region::JP.language_dirs(), ["JP_Japanese"]);
+    }
+}
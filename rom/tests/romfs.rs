@@ -0,0 +1,78 @@
+use std::io::{Cursor, Write};
+
+use rom::{File, Result, RomFs, RomFsBuilder};
+
+fn file(path: &str, data: &[u8]) -> File<Box<[u8]>> {
+    File::new(path.into(), data.into())
+}
+
+fn round_trip(files: &[File<Box<[u8]>>]) -> Result<RomFs<Cursor<Vec<u8>>>> {
+    let mut image = Cursor::new(vec![]);
+    let len = files.iter().cloned().collect::<RomFsBuilder>().write(&mut image)?;
+    assert_eq!(len, image.get_ref().len() as u64);
+    let mut romfs = RomFs::load(image, 0)?;
+    for file in files {
+        assert_eq!(romfs.read(file.path())?.get(), file.get(), "{}", file.path());
+    }
+    Ok(romfs)
+}
+
+#[test]
+fn it_reads_back_a_tree() -> Result<()> {
+    round_trip(&[
+        file("RegionBoot.szs", b"boot"),
+        file("World/Byaml/FlowChart.byaml", &[1; 0x1234]),
+        file("World/Byaml/Empty.byaml", b""),
+        file("World/Demo/Demo1.bch", &[2; 3]),
+        file("US/RegionBoot.szs", &[3; 0x2000]),
+        file("Sound/Stream/日本語.bcstm", b"unicode"),
+    ])?;
+    Ok(())
+}
+
+#[test]
+fn it_reads_back_many_files() -> Result<()> {
+    let files = (0..500)
+        .map(|i| file(&format!("Dir{}/Sub{}/File{}.bin", i % 7, i % 3, i), &(i as u32).to_le_bytes().repeat(i % 50)))
+        .collect::<Vec<_>>();
+    round_trip(&files)?;
+    Ok(())
+}
+
+#[test]
+fn it_reads_back_an_empty_image() -> Result<()> {
+    let mut romfs = round_trip(&[])?;
+    assert!(romfs.read("Missing.bin").is_err());
+    Ok(())
+}
+
+#[test]
+fn it_rejects_missing_files() -> Result<()> {
+    let mut romfs = round_trip(&[file("World/Byaml/FlowChart.byaml", b"flow")])?;
+    assert!(romfs.read("World/Byaml/Missing.byaml").is_err());
+    assert!(romfs.read("World/Missing/FlowChart.byaml").is_err());
+    assert!(romfs.read("FlowChart.byaml").is_err());
+    Ok(())
+}
+
+#[test]
+fn it_replaces_files_at_the_same_path() -> Result<()> {
+    let mut builder = RomFsBuilder::new();
+    builder.add(file("World/Byaml/FlowChart.byaml", b"old"));
+    builder.add(file("World/Byaml/FlowChart.byaml", b"new"));
+    let mut image = Cursor::new(vec![]);
+    builder.write(&mut image)?;
+    let mut romfs = RomFs::load(image, 0)?;
+    assert_eq!(romfs.read("World/Byaml/FlowChart.byaml")?.get().as_ref(), b"new");
+    Ok(())
+}
+
+#[test]
+fn it_writes_at_an_offset() -> Result<()> {
+    let mut image = Cursor::new(vec![]);
+    image.write_all(&[0xFF; 0x3000])?;
+    [file("US/RegionBoot.szs", &[3; 0x100])].into_iter().collect::<RomFsBuilder>().write(&mut image)?;
+    let mut romfs = RomFs::load(image, 0x3000)?;
+    assert_eq!(romfs.read("US/RegionBoot.szs")?.get().as_ref(), [3; 0x100]);
+    Ok(())
+}
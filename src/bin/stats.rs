@@ -0,0 +1,77 @@
+use log::{info, LevelFilter};
+use macros::fail;
+use randomizer::{stats, system::System};
+use simplelog::{ConfigBuilder, SimpleLogger};
+use std::{fs, path::PathBuf, thread, time::Instant};
+use structopt::StructOpt;
+
+#[derive(Debug, StructOpt)]
+struct Opt {
+    /// Name of the preset to generate Seeds with
+    #[structopt(long)]
+    preset: String,
+
+    /// Number of Seeds to generate
+    #[structopt(long, default_value = "100")]
+    seeds: usize,
+
+    /// First Seed to generate, the rest follow in order. Seeds are random if not given.
+    #[structopt(long)]
+    seed: Option<u32>,
+
+    /// Number of Seeds to generate at once, defaults to the number of available CPUs
+    #[structopt(long)]
+    threads: Option<usize>,
+
+    /// Where to write the results, as CSV if the file name ends in `.csv` and as JSON otherwise
+    #[structopt(long, parse(from_os_str), default_value = "stats.json")]
+    output: PathBuf,
+}
+
+/**
+ * STATISTICS
+ *
+ * Generates many Seeds with the same preset, without patching, and gathers statistics on how they turned out.
+ */
+fn main() {
+    let opt = Opt::from_args();
+
+    // Only log our own progress, not that of every Seed
+    let log_config =
+        ConfigBuilder::new().add_filter_allow_str("stats").add_filter_allow_str("randomizer::stats").build();
+    SimpleLogger::init(LevelFilter::Info, log_config).expect("Failed to init logger.");
+
+    info!("Initializing ALBW Statistics...\n");
+
+    let preset = System::load_preset(&opt.preset).unwrap_or_else(|err| {
+        fail!("Failed to load preset: {}\nError: {}", opt.preset, err);
+    });
+
+    let settings = preset.settings;
+
+    let seeds = match opt.seed {
+        Some(first) => (0..opt.seeds).map(|i| first.wrapping_add(i as u32)).collect::<Vec<_>>(),
+        None => (0..opt.seeds).map(|_| rand::random()).collect(),
+    };
+    let threads = opt.threads.unwrap_or_else(|| thread::available_parallelism().map_or(1, |threads| threads.get()));
+
+    info!("Generating {} Seeds on {} threads...", seeds.len(), threads);
+    let stopwatch = Instant::now();
+    let stats = stats::generate_stats(&settings, &seeds, threads);
+
+    println!();
+    info!("Generated {} Seeds in {} seconds.", stats.seeds, stopwatch.elapsed().as_secs());
+    info!("Failures:                       {} ({:.1}%)", stats.failures, stats.failure_rate() * 100.0);
+    info!("Average Spheres:                {:.2}", stats.average_spheres());
+
+    let output = if opt.output.extension().is_some_and(|extension| extension == "csv") {
+        stats.to_csv()
+    } else {
+        serde_json::to_string_pretty(&stats).expect("Could not serialize statistics.")
+    };
+    fs::write(&opt.output, output).unwrap_or_else(|err| {
+        fail!("Failed to write statistics to: {}\nError: {}", opt.output.display(), err);
+    });
+
+    info!("Wrote statistics to:            {}", opt.output.display());
+}
@@ -2,6 +2,10 @@ use pyo3::prelude::*;
 use modinfo::settings::{
     cracks::Cracks,
     cracksanity::Cracksanity,
+    entrance_shuffle::EntranceShuffle,
+    goal::GoalSetting,
+    hint_distribution::HintDistribution,
+    keysanity::Keysanity,
     keysy::Keysy,
     logic::LogicMode,
     nice_items::NiceItems,
@@ -11,7 +15,13 @@ use modinfo::settings::{
     weather_vanes::WeatherVanes,
 };
 use modinfo::Settings;
-use randomizer::{ArchipelagoInfo, SeedInfo, randomize_pre_fill};
+use modinfo::schema::settings_schema;
+use randomizer::{
+    ArchipelagoInfo, SeedInfo, generate_seed_info, randomize_pre_fill,
+    encode_settings_string, decode_settings_string,
+    RandomizerError, InternalError, GameError, IoError, RomError, SettingsError,
+};
+use randomizer::system::OutputMode;
 use randomizer::filler::filler_item::{
     Item, Goal, Vane, Crack, PyRandomizable,
     new_item, new_goal, new_vane, new_crack
@@ -24,9 +34,13 @@ pub fn logging_on() {
 }
 
 #[pymodule]
-fn albwrandomizer(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
+fn albwrandomizer(py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<Cracks>()?;
     m.add_class::<Cracksanity>()?;
+    m.add_class::<EntranceShuffle>()?;
+    m.add_class::<GoalSetting>()?;
+    m.add_class::<HintDistribution>()?;
+    m.add_class::<Keysanity>()?;
     m.add_class::<Keysy>()?;
     m.add_class::<LogicMode>()?;
     m.add_class::<NiceItems>()?;
@@ -37,14 +51,26 @@ fn albwrandomizer(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_class::<Settings>()?;
     m.add_class::<ArchipelagoInfo>()?;
     m.add_class::<SeedInfo>()?;
+    m.add_class::<OutputMode>()?;
     m.add_class::<Item>()?;
     m.add_class::<Goal>()?;
     m.add_class::<Vane>()?;
     m.add_class::<Crack>()?;
     m.add_class::<PyRandomizable>()?;
 
+    m.add("RandomizerError", py.get_type::<RandomizerError>())?;
+    m.add("InternalError", py.get_type::<InternalError>())?;
+    m.add("GameError", py.get_type::<GameError>())?;
+    m.add("IoError", py.get_type::<IoError>())?;
+    m.add("SettingsError", py.get_type::<SettingsError>())?;
+    m.add("RomError", py.get_type::<RomError>())?;
+
     m.add_function(wrap_pyfunction!(logging_on, m)?)?;
     m.add_function(wrap_pyfunction!(randomize_pre_fill, m)?)?;
+    m.add_function(wrap_pyfunction!(generate_seed_info, m)?)?;
+    m.add_function(wrap_pyfunction!(encode_settings_string, m)?)?;
+    m.add_function(wrap_pyfunction!(decode_settings_string, m)?)?;
+    m.add_function(wrap_pyfunction!(settings_schema, m)?)?;
     m.add_function(wrap_pyfunction!(new_item, m)?)?;
     m.add_function(wrap_pyfunction!(new_goal, m)?)?;
     m.add_function(wrap_pyfunction!(new_vane, m)?)?;
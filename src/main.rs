@@ -1,49 +1,181 @@
 use log::warn;
-use randomizer::SeedInfo;
+use randomizer::{ErrorKind, SeedInfo};
+use serde::Serialize;
+use serde_json::Value;
+use std::io::{stdin, IsTerminal};
+use std::path::{Path, PathBuf};
 use {
     log::{error, info},
     macros::fail,
+    modinfo::{schema, Settings},
     randomizer::{
-        constants::VERSION,
-        system::{System, UserConfig},
+        constants::{CONFIG_FILE_NAME, VERSION},
+        system::{OutputMode, System, UserConfig},
     },
     simplelog::{LevelFilter, SimpleLogger},
-    structopt::StructOpt,
+    structopt::{clap, StructOpt},
 };
 
-#[derive(Debug, StructOpt, Clone)]
+/// THE LEGEND OF ZELDA: A LINK BETWEEN WORLDS RANDOMIZER
+#[derive(Debug, StructOpt)]
 struct Opt {
-    #[structopt(long)]
-    seed: Option<u32>,
+    #[structopt(flatten)]
+    global: GlobalOpts,
 
-    #[structopt(long)]
-    preset: Option<String>,
+    // Used when no subcommand is given, so that `albw-randomizer --preset <name>` keeps working
+    #[structopt(flatten)]
+    generate: GenerateOpts,
+
+    #[structopt(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Debug, StructOpt)]
+struct GlobalOpts {
+    /// Path to the config file holding the ROM path, output directory, and output mode [default: config.json]
+    #[structopt(long, parse(from_os_str), global = true)]
+    config: Option<PathBuf>,
+
+    /// Overrides the ROM path from the config file
+    #[structopt(long, parse(from_os_str), global = true)]
+    rom: Option<PathBuf>,
+
+    /// Overrides the output directory from the config file
+    #[structopt(long, parse(from_os_str), global = true)]
+    output: Option<PathBuf>,
+
+    /// Overrides the output mode from the config file: layeredfs, zip, image, or bps
+    #[structopt(long, global = true)]
+    output_mode: Option<OutputMode>,
+
+    /// Exits without waiting for Enter. This is always the case when stdout isn't a terminal.
+    #[structopt(long, global = true)]
+    no_pause: bool,
+}
+
+#[derive(Debug, StructOpt)]
+enum Command {
+    /// Generates a seed. This is the default when no subcommand is given.
+    Generate(GenerateOpts),
+
+    /// Patches the ROM with the seed from an existing spoiler log
+    Patch {
+        /// Path to the spoiler log of the seed to patch
+        #[structopt(long, parse(from_os_str))]
+        spoiler: PathBuf,
+    },
+
+    /// Checks whether the layout of a spoiler log or Plando can be beaten
+    Verify {
+        /// Path to a spoiler log to verify
+        #[structopt(long, parse(from_os_str), required_unless = "plando", conflicts_with = "plando")]
+        spoiler: Option<PathBuf>,
+
+        /// Path to a Plando file to verify
+        #[structopt(long, parse(from_os_str))]
+        plando: Option<PathBuf>,
+    },
+
+    /// Prints the settings a seed would be generated with, in the format of a preset file
+    Settings(SettingsOpts),
+
+    /// Prints a JSON Schema describing every setting in a preset file
+    Schema,
+}
+
+#[derive(Debug, StructOpt)]
+struct GenerateOpts {
+    #[structopt(flatten)]
+    settings: SettingsOpts,
 
+    /// Skips patching the ROM, e.g. to only get the spoiler log
     #[structopt(long)]
     no_patch: bool,
 
+    /// Skips writing the spoiler log
     #[structopt(long)]
     no_spoiler: bool,
 }
 
-/// THE LEGEND OF ZELDA: A LINK BETWEEN WORLDS RANDOMIZER
+impl GenerateOpts {
+    /// Whether none of these options were given.
+    fn is_empty(&self) -> bool {
+        self.settings.is_empty() && !self.no_patch && !self.no_spoiler
+    }
+}
+
+#[derive(Debug, StructOpt)]
+struct SettingsOpts {
+    /// Seed number to generate, instead of the preset's or a random one
+    #[structopt(long)]
+    seed: Option<u32>,
+
+    /// Name of a preset in the presets folder
+    #[structopt(long, conflicts_with_all = &["preset-file", "settings-string"])]
+    preset: Option<String>,
+
+    /// Path to a preset file
+    #[structopt(long, parse(from_os_str), conflicts_with = "settings-string")]
+    preset_file: Option<PathBuf>,
+
+    /// Settings String holding the seed and settings to generate, as printed alongside the hash of a generated seed
+    #[structopt(long)]
+    settings_string: Option<String>,
+
+    /// Overrides one setting, e.g. `--set logic_mode=Hard` or `--set hint_distribution.path=3`. May be repeated.
+    #[structopt(long = "set", value_name = "name=value", number_of_values = 1, parse(try_from_str = parse_override))]
+    overrides: Vec<(String, Value)>,
+}
+
+impl SettingsOpts {
+    /// Whether none of these options were given.
+    fn is_empty(&self) -> bool {
+        self.seed.is_none()
+            && self.preset.is_none()
+            && self.preset_file.is_none()
+            && self.settings_string.is_none()
+            && self.overrides.is_empty()
+    }
+}
+
 fn main() {
-    let opt = Opt::from_args();
+    let Opt { global, generate: generate_opts, command } = Opt::from_args();
+    let command = match command {
+        None => Command::Generate(generate_opts),
+        // These would otherwise be silently ignored, e.g. `--preset <name> generate --no-patch` not using the preset
+        Some(_) if !generate_opts.is_empty() => clap::Error::with_description(
+            "Generation options must come after the `generate` subcommand, and can't be used with other subcommands",
+            clap::ErrorKind::ArgumentConflict,
+        )
+        .exit(),
+        Some(command) => command,
+    };
 
-    SimpleLogger::init(LevelFilter::Info, Default::default()).expect("Could not initialize logger.");
+    if global.no_pause {
+        cli::disable_pause();
+    }
 
-    info!("Initializing ALBW Randomizer...");
+    // Keep stdout clean for the JSON printed by the `settings` and `schema` subcommands
+    let level =
+        if matches!(command, Command::Settings(_) | Command::Schema) { LevelFilter::Warn } else { LevelFilter::Info };
+    SimpleLogger::init(level, Default::default()).expect("Could not initialize logger.");
+
+    match command {
+        Command::Generate(opts) => generate(&global, &opts),
+        Command::Patch { spoiler } => patch(&global, &spoiler),
+        Command::Verify { spoiler, plando } => verify(spoiler, plando),
+        Command::Settings(opts) => print_settings(&opts),
+        Command::Schema => print_schema(),
+    }
+}
 
-    let (preset_name, seeded, SeedInfo { mut seed, mut settings, .. }) = determine_settings(opt.preset, opt.seed);
+/// Generates a seed, retrying with new seed numbers if one fails and no seed number was given.
+fn generate(global: &GlobalOpts, opts: &GenerateOpts) {
+    info!("Initializing ALBW Randomizer...");
 
-    settings.yuganon_requirement = settings.lc_requirement; // FIXME Temporary: Force Yuganon Requirement to be equal to LC Requirement
+    let (preset_name, seeded, SeedInfo { mut seed, settings, .. }) = determine_settings(&opts.settings);
 
-    // Load User Config
-    let user_config: UserConfig = System::load_config().unwrap_or_else(|error| {
-        fail!("Failed to parse configuration file: config.json\n\
-                Commonly Fixed By: Replace any single backslash characters '\\' with a forward slash '/' or double backslash '\\\\'.\n\
-                Full Error: {}\n", error);
-    });
+    let user_config = load_user_config(global);
 
     // Generate Seed in a retryable manner
     const MAX_RETRIES: u16 = 100;
@@ -57,7 +189,7 @@ fn main() {
         info!("Seed:                           {:0>10}", seed);
 
         let stopwatch = std::time::Instant::now();
-        match randomizer::generate_seed(seed, settings.clone(), &user_config, opt.no_patch, opt.no_spoiler) {
+        match randomizer::generate_seed(seed, settings.clone(), &user_config, opts.no_patch, opts.no_spoiler) {
             Ok(_) => {
                 println!();
                 info!("Successfully Generated ALBWR Seed {} in {} seconds! :D", seed, stopwatch.elapsed().as_secs());
@@ -67,6 +199,10 @@ fn main() {
                 info!("Visit us on Discord: https://discord.gg/dmAJh2uY7M");
                 break;
             },
+            // Another seed number won't fix these
+            Err(err) if matches!(err.kind(), ErrorKind::Settings | ErrorKind::Rom | ErrorKind::Io) => {
+                fail!("{}", err);
+            },
             Err(err) => {
                 error!("{:?}", err);
                 if x < MAX_RETRIES {
@@ -87,26 +223,141 @@ fn main() {
     cli::pause();
 }
 
-/// Get Settings, either from a preset or the CLI
-fn determine_settings(opt_preset: Option<String>, opt_seed: Option<u32>) -> (Option<String>, bool, SeedInfo) {
-    if let Some(preset_name) = opt_preset {
-        let preset_name = preset_name.clone();
-        let mut seed_info = System::load_preset(&(preset_name.clone())).unwrap_or_else(|err| {
-            fail!("Failed to load preset: {}\nError: {}", preset_name, err);
+/// Patches the ROM with a seed read back from its spoiler log, without generating anything.
+fn patch(global: &GlobalOpts, spoiler: &Path) {
+    info!("Initializing ALBW Randomizer...");
+
+    let seed_info = System::load_spoiler(spoiler).unwrap_or_else(|err| {
+        fail!("Failed to load spoiler log: {}\nError: {}", spoiler.display(), err);
+    });
+
+    if seed_info.version != VERSION {
+        warn!("Patching a seed from \"{}\" with the patcher from \"{}\"", seed_info.version, VERSION);
+    }
+
+    let user_config = load_user_config(global);
+
+    info!("Seed:                           {:0>10}", seed_info.seed);
+    if let Err(err) = randomizer::patch_seed(&seed_info, &user_config, false, true) {
+        fail!("Couldn't patch Seed: \"{}\"\n{}", seed_info.seed, err);
+    }
+
+    println!();
+    info!("Successfully Patched ALBWR Seed {}! :D", seed_info.seed);
+    println!();
+    cli::pause();
+}
+
+/// Verifies a spoiler log or Plando, exiting with an error code if its layout can't be beaten.
+fn verify(spoiler: Option<PathBuf>, plando: Option<PathBuf>) {
+    info!("Initializing ALBW Verifier...\n");
+
+    let (version, result) = if let Some(path) = spoiler {
+        let mut seed_info = System::load_spoiler(&path).unwrap_or_else(|err| {
+            fail!("Failed to load spoiler log: {}\nError: {}", path.display(), err);
         });
+        (seed_info.version.clone(), Ok(randomizer::verify::verify_spoiler(&mut seed_info)))
+    } else if let Some(path) = plando {
+        let plando = System::load_plando(&path).unwrap_or_else(|err| {
+            fail!("Failed to load plando: {}\nError: {}", path.display(), err);
+        });
+        (plando.version.clone(), randomizer::verify::verify_plando(&plando))
+    } else {
+        unreachable!("structopt requires either a spoiler log or plando");
+    };
 
-        if seed_info.version != VERSION {
-            fail!(
-                "There is a Version mismatch between the Randomizer and the Preset file.\n\
-                Randomizer Version: \"{}\"\n\
-                Preset Version:     \"{}\"",
-                VERSION,
-                seed_info.version
-            );
-        }
+    if version != VERSION {
+        warn!("Verifying a layout from \"{}\" with the logic from \"{}\"", version, VERSION);
+    }
+
+    let verification = result.unwrap_or_else(|err| {
+        fail!("Verification failed:\n{}", err.into_inner());
+    });
+    verification.log();
+
+    println!();
+    cli::pause();
+
+    if !verification.beatable {
+        std::process::exit(1);
+    }
+}
+
+/// Prints the settings a seed would be generated with as a preset, to be saved and shared.
+fn print_settings(opts: &SettingsOpts) {
+    let (_, seeded, SeedInfo { seed, version, settings, .. }) = determine_settings(opts);
+
+    #[derive(Serialize)]
+    struct Preset {
+        seed: u32,
+        version: String,
+        settings: Settings,
+    }
+
+    // A seed of 0 is randomized when the preset is used
+    let preset = Preset { seed: if seeded { seed } else { 0 }, version, settings };
+
+    println!("{}", serde_json::to_string_pretty(&preset).expect("Settings could not be serialized."));
+}
+
+/// Prints the JSON Schema of a preset, for GUIs and preset validators to read every setting's details from.
+fn print_schema() {
+    println!("{}", serde_json::to_string_pretty(&schema::json_schema()).expect("Schema could not be serialized."));
+}
+
+/// Loads the config file, then overrides its values with any given on the command line.
+fn load_user_config(global: &GlobalOpts) -> UserConfig {
+    let file = global.config.clone().unwrap_or_else(|| PathBuf::from(CONFIG_FILE_NAME));
+
+    let mut user_config = match (&global.rom, &global.output) {
+        // Nothing is needed from the default config file if both of its paths were given
+        (Some(rom), Some(output)) if global.config.is_none() && !file.exists() => {
+            UserConfig::new(rom.clone(), output.clone(), Default::default())
+        },
+        _ => System::load_config_file(&file).unwrap_or_else(|error| {
+            fail!("Failed to parse configuration file: {}\n\
+                Commonly Fixed By: Replace any single backslash characters '\\' with a forward slash '/' or double backslash '\\\\'.\n\
+                Full Error: {}\n", file.display(), error);
+        }),
+    };
+
+    if let Some(rom) = &global.rom {
+        user_config.set_rom(rom.clone());
+    }
+    if let Some(output) = &global.output {
+        user_config.set_output(output.clone());
+    }
+    if let Some(output_mode) = global.output_mode {
+        user_config.set_output_mode(output_mode);
+    }
+
+    user_config
+}
+
+/// Get Settings, either from a preset, the command line, or the interactive prompts
+fn determine_settings(opts: &SettingsOpts) -> (Option<String>, bool, SeedInfo) {
+    let preset = match (&opts.preset, &opts.preset_file, &opts.settings_string) {
+        (Some(preset_name), _, _) => Some((preset_name.clone(), System::load_preset(preset_name).map_err(Into::into))),
+        (None, Some(file), _) => Some((file.display().to_string(), System::load_preset_file(file).map_err(Into::into))),
+        (None, None, Some(settings_string)) => Some((
+            settings_string.clone(),
+            randomizer::decode_settings_string(settings_string).map(|(seed, settings)| SeedInfo {
+                seed,
+                version: VERSION.to_owned(),
+                settings,
+                ..Default::default()
+            }),
+        )),
+        (None, None, None) => None,
+    };
+
+    if let Some((preset_name, preset)) = preset {
+        let mut seed_info = preset.unwrap_or_else(|err| {
+            fail!("Failed to load preset: {}\nError: {}", preset_name, err);
+        });
 
         let mut seeded = false;
-        if let Some(seed) = opt_seed {
+        if let Some(seed) = opts.seed {
             if seed_info.seed != 0 {
                 println!();
                 warn!("Two seed numbers provided! Defaulting to the command line argument.\n");
@@ -120,22 +371,55 @@ fn determine_settings(opt_preset: Option<String>, opt_seed: Option<u32>) -> (Opt
             seed_info.seed = rand::random();
         }
 
+        if !opts.overrides.is_empty() {
+            let settings = serde_json::to_value(&seed_info.settings).expect("Settings could not be serialized.");
+            seed_info.settings = apply_overrides(settings, &opts.overrides);
+        }
+
         (Some(preset_name), seeded, seed_info)
     } else {
         let (seeded, seed): (bool, u32) =
-            if let Some(seed) = opt_seed { (true, seed) } else { (false, rand::random()) };
+            if let Some(seed) = opts.seed { (true, seed) } else { (false, rand::random()) };
 
-        (
-            None,
-            seeded,
-            SeedInfo {
-                seed,
-                version: VERSION.to_owned(),
-                settings: cli::get_seed_settings().unwrap_or_else(|err| {
-                    fail!("Failed to create Settings: {}", err);
-                }),
-                ..Default::default()
-            },
-        )
+        // Only prompt for settings when nothing was given and someone is there to answer
+        let settings = if opts.overrides.is_empty() && stdin().is_terminal() {
+            cli::get_seed_settings().unwrap_or_else(|err| {
+                fail!("Failed to create Settings: {}", err);
+            })
+        } else {
+            apply_overrides(Value::Object(Default::default()), &opts.overrides)
+        };
+
+        (None, seeded, SeedInfo { seed, version: VERSION.to_owned(), settings, ..Default::default() })
     }
 }
+
+/// Parses a `--set` flag of the form `name=value`. The value is read as JSON if it can be, and as a string otherwise.
+fn parse_override(s: &str) -> Result<(String, Value), String> {
+    let (name, value) = s.split_once('=').ok_or_else(|| format!("Expected <name>=<value>, found: \"{}\"", s))?;
+    let value = serde_json::from_str(value).unwrap_or_else(|_| Value::String(value.to_owned()));
+    Ok((name.trim().to_owned(), value))
+}
+
+/// Sets each overridden setting in the JSON form of `settings`, so that they're named and validated the same way as
+/// in a preset. Settings inside of a group, like the Hint Distribution, are named with a `.` between each level.
+fn apply_overrides(mut settings: Value, overrides: &[(String, Value)]) -> Settings {
+    for (name, value) in overrides {
+        let mut group = &mut settings;
+        let mut path = name.split('.').peekable();
+        while let Some(key) = path.next() {
+            let Some(object) = group.as_object_mut() else {
+                fail!("Invalid setting name: \"{}\"", name);
+            };
+            if path.peek().is_none() {
+                object.insert(key.to_owned(), value.clone());
+                break;
+            }
+            group = object.entry(key).or_insert_with(|| Value::Object(Default::default()));
+        }
+    }
+
+    serde_json::from_value(settings).unwrap_or_else(|err| {
+        fail!("Invalid setting: {}", err);
+    })
+}